@@ -0,0 +1,15 @@
+use std::env;
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(lume_backend)");
+
+    // Every module binds values and builds SQL for exactly one backend; the
+    // other combinations are reported by `src/features.rs` instead
+    let enabled = ["MYSQL", "POSTGRES", "SQLITE"]
+        .iter()
+        .filter(|feature| env::var_os(format!("CARGO_FEATURE_{feature}")).is_some())
+        .count();
+    if enabled == 1 {
+        println!("cargo::rustc-cfg=lume_backend");
+    }
+}
@@ -3,4 +3,8 @@ set -e
 
 cargo test --all --no-default-features --features mysql
 cargo test --all --no-default-features --features postgres
-cargo test --all --no-default-features --features sqlite
\ No newline at end of file
+cargo test --all --no-default-features --features sqlite
+
+# Building with no backend, or with several, must fail with a single feature error
+cargo test --manifest-path tests/features/Cargo.toml --no-default-features
+cargo test --manifest-path tests/features/Cargo.toml --features mysql,postgres
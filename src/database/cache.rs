@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    row::Row,
+    schema::{Schema, Value},
+};
+
+/// A cached query result and the moment it stops being valid.
+struct CachedResult {
+    expires_at: Instant,
+    /// The column data of each cached row
+    rows: Vec<HashMap<String, Value>>,
+}
+
+/// An in-memory cache of query results with a per-entry time to live.
+///
+/// Queries opt in with [`Query::cached`], and results are keyed by the schema
+/// type, the generated SQL and its bound parameters. Entries are only dropped
+/// when they expire or the cache is [cleared](Self::clear), so writes made in
+/// the meantime aren't visible to cached queries.
+///
+/// [`Query::cached`]: crate::operations::query::Query::cached
+pub struct ResultCache {
+    entries: Mutex<HashMap<String, CachedResult>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Debug for ResultCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResultCache")
+            .field("len", &self.len())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+impl ResultCache {
+    /// Creates an empty result cache.
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Builds the cache key for a query on `T` with the given SQL and parameters.
+    pub(crate) fn key<T: Schema + Debug>(sql: &str, params: &[Value]) -> String {
+        format!("{}\0{}\0{:?}", std::any::type_name::<T>(), sql, params)
+    }
+
+    /// Returns a copy of the rows cached under `key`, if present and not expired.
+    pub(crate) fn get<T: Schema + Debug>(&self, key: &str) -> Option<Vec<Row<T>>> {
+        let mut entries = self.entries.lock().unwrap();
+
+        let rows = match entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(
+                entry
+                    .rows
+                    .iter()
+                    .map(|data| Row::from_data(data.clone()))
+                    .collect(),
+            ),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        };
+
+        match rows {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+
+        rows
+    }
+
+    /// Stores a copy of `rows` under `key` for `ttl`.
+    pub(crate) fn insert<T: Schema + Debug>(&self, key: String, rows: &[Row<T>], ttl: Duration) {
+        self.entries.lock().unwrap().insert(
+            key,
+            CachedResult {
+                expires_at: Instant::now() + ttl,
+                rows: rows.iter().map(|row| row.data().clone()).collect(),
+            },
+        );
+    }
+
+    /// Removes every cached result.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Returns the number of cached results, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns how many cached queries were answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns how many cached queries had to go to the database.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use crate::schema::ValidationError;
+
 /// Represents errors that can occur during database operations in Lume.
 ///
 /// This type wraps underlying SQLx errors as well as Lume-specific error cases,
@@ -9,6 +13,11 @@
 /// - [`ConnectionError(sqlx::Error)`]: An error occurred while establishing a database connection.
 /// - [`QueryError(String)`]: An error occurred during query preparation or execution.
 /// - [`ExecutionError(String)`]: An error occurred while executing a database operation.
+/// - [`ValidationFailed(ValidationError)`]: A value was rejected by one of its column's validators.
+/// - [`UniqueViolation(ValidationError)`]: A value checked with `unique_check()` already exists.
+/// - [`ValueOutOfRange(String)`]: A value doesn't fit the column type it's bound as.
+/// - [`StaleData(String)`]: A versioned update found its row changed by another writer.
+/// - [`Timeout(Duration)`]: A statement ran longer than the `timeout()` set on its operation.
 ///
 /// # Examples
 ///
@@ -44,6 +53,21 @@ pub enum DatabaseError {
     QueryError(String),
     /// An error in the execution of a database operation
     ExecutionError(String),
+    /// A value failed one of its column's validators
+    ValidationFailed(ValidationError),
+    /// A value checked with `unique_check()` already exists in the table
+    UniqueViolation(ValidationError),
+    /// A value doesn't fit the database type it's bound as, e.g. a `u64` above
+    /// `i64::MAX` on Postgres or SQLite
+    ValueOutOfRange(String),
+    /// An update guarded by a `version()` column matched no row, because
+    /// another writer changed (or deleted) it since it was read
+    StaleData(String),
+    /// A statement didn't finish within the `timeout()` set on its query,
+    /// update or delete, which it carries. Raised by the client-side timer, or
+    /// on Postgres when the server cancels the statement for exceeding its
+    /// matching `statement_timeout`. The connection is closed, not reused
+    Timeout(Duration),
 }
 
 impl DatabaseError {
@@ -54,13 +78,54 @@ impl DatabaseError {
             DatabaseError::ConnectionError(e) => e.to_string(),
             DatabaseError::QueryError(e) => e.clone(),
             DatabaseError::ExecutionError(e) => e.clone(),
+            DatabaseError::ValidationFailed(e) => e.to_string(),
+            DatabaseError::UniqueViolation(e) => e.to_string(),
+            DatabaseError::ValueOutOfRange(reason) => reason.clone(),
+            DatabaseError::StaleData(reason) => reason.clone(),
+            DatabaseError::Timeout(timeout) => {
+                format!("Statement timed out after {:?}", timeout)
+            }
         }
     }
 }
 
+/// Writes the kind of error followed by its [`reason`](DatabaseError::reason),
+/// e.g. `Query error: no such table: users`.
 impl std::fmt::Display for DatabaseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.reason())
+        let kind = match self {
+            DatabaseError::InvalidValue(_) => "Invalid value",
+            DatabaseError::ConnectionError(_) => "Connection error",
+            DatabaseError::QueryError(_) => "Query error",
+            DatabaseError::ExecutionError(_) => "Execution error",
+            DatabaseError::ValidationFailed(_) => "Validation failed",
+            DatabaseError::UniqueViolation(_) => "Unique violation",
+            DatabaseError::ValueOutOfRange(_) => "Value out of range",
+            DatabaseError::StaleData(_) => "Stale data",
+            DatabaseError::Timeout(_) => "Timeout",
+        };
+        write!(f, "{}: {}", kind, self.reason())
+    }
+}
+
+/// Converts a driver error, so `?` works on sqlx calls in functions returning
+/// [`DatabaseError`].
+///
+/// Failures to reach or keep a connection (I/O, TLS, configuration and pool
+/// errors) become [`DatabaseError::ConnectionError`]; everything else, such as
+/// a database error or a row that couldn't be decoded, becomes
+/// [`DatabaseError::QueryError`].
+impl From<sqlx::Error> for DatabaseError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::Configuration(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed => DatabaseError::ConnectionError(error),
+            error => DatabaseError::QueryError(error.to_string()),
+        }
     }
 }
 
@@ -68,6 +133,8 @@ impl std::error::Error for DatabaseError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             DatabaseError::ConnectionError(e) => Some(e),
+            DatabaseError::ValidationFailed(e) => Some(e),
+            DatabaseError::UniqueViolation(e) => Some(e),
             _ => None,
         }
     }
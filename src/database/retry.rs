@@ -0,0 +1,120 @@
+use std::{future::Future, time::Duration};
+
+use crate::database::error::DatabaseError;
+
+/// How often, and how patiently, to retry an operation that failed with a
+/// transient connection error.
+///
+/// Each retry waits twice as long as the one before, starting at
+/// `initial_backoff` and never exceeding `max_backoff`.
+///
+/// Only errors that say nothing about the statement itself are retried: I/O
+/// errors such as a reset connection, pool timeouts and a crashed connection
+/// worker. Database errors, like constraint violations, fail right away.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lume::database::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(3)
+///     .initial_backoff(Duration::from_millis(50))
+///     .max_backoff(Duration::from_secs(1));
+///
+/// assert_eq!(policy.backoff(0), Duration::from_millis(50));
+/// assert_eq!(policy.backoff(1), Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that runs an operation at most `max_attempts` times,
+    /// waiting 100ms before the first retry and at most 5s between retries.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets how long to wait before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the longest wait between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Returns the maximum number of attempts, including the first.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns how long to wait after the given failed attempt (0-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Runs `operation`, retrying it while it fails with a transient
+    /// [`DatabaseError::ConnectionError`] and attempts remain.
+    ///
+    /// Returns the first success, or the last error.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T, DatabaseError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, DatabaseError>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            match operation().await {
+                Err(e) if attempt + 1 < self.max_attempts && is_transient(&e) => {
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether `error` is worth retrying: the connection failed, not the statement.
+fn is_transient(error: &DatabaseError) -> bool {
+    matches!(error, DatabaseError::ConnectionError(e) if is_transient_connection_error(e))
+}
+
+fn is_transient_connection_error(error: &sqlx::Error) -> bool {
+    matches!(
+        error,
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut | sqlx::Error::WorkerCrashed
+    )
+}
+
+/// Maps an error from running a statement, keeping transient connection
+/// failures as [`DatabaseError::ConnectionError`] so they can be retried.
+pub(crate) fn statement_error(error: sqlx::Error) -> DatabaseError {
+    if is_transient_connection_error(&error) {
+        DatabaseError::ConnectionError(error)
+    } else {
+        DatabaseError::QueryError(error.to_string())
+    }
+}
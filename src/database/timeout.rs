@@ -0,0 +1,100 @@
+use std::{future::Future, time::Duration};
+
+#[cfg(feature = "mysql")]
+use sqlx::MySql;
+#[cfg(feature = "postgres")]
+use sqlx::Postgres;
+#[cfg(feature = "sqlite")]
+use sqlx::Sqlite;
+use sqlx::pool::PoolConnection;
+
+use crate::database::error::DatabaseError;
+use crate::dialects::get_dialect;
+
+#[cfg(feature = "mysql")]
+type Connection = PoolConnection<MySql>;
+#[cfg(feature = "postgres")]
+type Connection = PoolConnection<Postgres>;
+#[cfg(feature = "sqlite")]
+type Connection = PoolConnection<Sqlite>;
+
+/// Sets the server-side statement timeout on `conn`, where the backend has one.
+///
+/// Must be paired with [`finish`], which undoes it before the connection goes
+/// back to the pool.
+pub(crate) async fn start(
+    conn: &mut Connection,
+    timeout: Option<Duration>,
+) -> Result<(), DatabaseError> {
+    if timeout.is_none() {
+        return Ok(());
+    }
+
+    if let Some(sql) = get_dialect().statement_timeout_sql(timeout) {
+        sqlx::query(&sql)
+            .execute(&mut **conn)
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Awaits `statement`, failing with [`DatabaseError::Timeout`] if it takes
+/// longer than `timeout` or the server cancels it for running past the
+/// timeout set by [`start`]. Any other error goes through `map_err`.
+pub(crate) async fn run<T>(
+    timeout: Option<Duration>,
+    statement: impl Future<Output = Result<T, sqlx::Error>>,
+    map_err: impl FnOnce(sqlx::Error) -> DatabaseError,
+) -> Result<T, DatabaseError> {
+    let Some(timeout) = timeout else {
+        return statement.await.map_err(map_err);
+    };
+
+    match tokio::time::timeout(timeout, statement).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(error)) if is_statement_timeout(&error) => Err(DatabaseError::Timeout(timeout)),
+        Ok(Err(error)) => Err(map_err(error)),
+        Err(_) => Err(DatabaseError::Timeout(timeout)),
+    }
+}
+
+/// Whether the server cancelled the statement, which with a timeout set
+/// means its `statement_timeout` expired (Postgres `query_canceled`, 57014).
+#[cfg(feature = "postgres")]
+fn is_statement_timeout(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Database(db) if db.code().as_deref() == Some("57014"))
+}
+
+#[cfg(not(feature = "postgres"))]
+fn is_statement_timeout(_error: &sqlx::Error) -> bool {
+    false
+}
+
+/// Undoes [`start`] once the statement finished.
+///
+/// A timed out statement may still be running, so its connection is closed
+/// instead of being returned to the pool.
+pub(crate) async fn finish<T>(
+    conn: &mut Connection,
+    timeout: Option<Duration>,
+    result: Result<T, DatabaseError>,
+) -> Result<T, DatabaseError> {
+    if timeout.is_none() {
+        return result;
+    }
+
+    if matches!(result, Err(DatabaseError::Timeout(_))) {
+        conn.close_on_drop();
+        return result;
+    }
+
+    if let Some(sql) = get_dialect().statement_timeout_sql(None)
+        && sqlx::query(&sql).execute(&mut **conn).await.is_err()
+    {
+        conn.close_on_drop();
+    }
+
+    result
+}
@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+
+#[cfg(feature = "mysql")]
+use sqlx::MySql;
+#[cfg(feature = "postgres")]
+use sqlx::Postgres;
+#[cfg(feature = "sqlite")]
+use sqlx::Sqlite;
+
+use crate::database::error::DatabaseError;
+use crate::dialects::get_dialect;
+use crate::row::Row;
+use crate::schema::Schema;
+
+/// A database transaction, started with [`Database::begin`](crate::database::Database::begin).
+///
+/// Statements run through the transaction only become visible to other
+/// connections once it is committed. Dropping it without calling
+/// [`commit`](Self::commit) rolls it back.
+///
+/// Savepoints mark a point inside the transaction that can be rolled back to
+/// without aborting the whole transaction, e.g. to skip one failed item of a
+/// batch.
+///
+/// Queries run inside the transaction with
+/// [`Query::execute_in`](crate::operations::query::Query::execute_in). The
+/// other builders run on the connection pool, so their statements inside a
+/// transaction are written as raw SQL.
+///
+/// # Example
+///
+/// ```no_run
+/// use lume::database::Database;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+///     let db = Database::connect("mysql://...").await?;
+///     let mut tx = db.begin().await?;
+///
+///     tx.execute("INSERT INTO Jobs (id) VALUES (1)").await?;
+///
+///     tx.savepoint("job_2").await?;
+///     if tx.execute("INSERT INTO Jobs (id) VALUES (2)").await.is_err() {
+///         tx.rollback_to("job_2").await?;
+///     }
+///
+///     tx.commit().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct Transaction {
+    #[cfg(feature = "mysql")]
+    pub(crate) tx: sqlx::Transaction<'static, MySql>,
+
+    #[cfg(feature = "postgres")]
+    pub(crate) tx: sqlx::Transaction<'static, Postgres>,
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) tx: sqlx::Transaction<'static, Sqlite>,
+}
+
+impl Transaction {
+    #[cfg(feature = "mysql")]
+    pub(crate) fn new(tx: sqlx::Transaction<'static, MySql>) -> Self {
+        Self { tx }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub(crate) fn new(tx: sqlx::Transaction<'static, Postgres>) -> Self {
+        Self { tx }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn new(tx: sqlx::Transaction<'static, Sqlite>) -> Self {
+        Self { tx }
+    }
+
+    /// Executes a raw SQL statement inside the transaction.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(u64)`: The number of rows affected
+    /// - `Err(DatabaseError)`: If the statement failed
+    pub async fn execute(&mut self, sql: &str) -> Result<u64, DatabaseError> {
+        let result = sqlx::query(sql)
+            .execute(&mut *self.tx)
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Executes a raw SQL query inside the transaction, returning typed rows.
+    ///
+    /// See [`Database::sql`](crate::database::Database::sql).
+    pub async fn sql<T: Schema + Debug>(
+        &mut self,
+        sql: &str,
+    ) -> Result<Vec<Row<T>>, DatabaseError> {
+        let rows = sqlx::query(sql)
+            .fetch_all(&mut *self.tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+
+        #[cfg(feature = "mysql")]
+        let rows = Row::from_mysql_row(rows, None);
+
+        #[cfg(feature = "postgres")]
+        let rows = Row::from_postgres_row(rows, None);
+
+        #[cfg(feature = "sqlite")]
+        let rows = Row::from_sqlite_row(rows, None);
+
+        Ok(rows)
+    }
+
+    /// Creates a savepoint named `name`.
+    ///
+    /// Creating a savepoint with the name of an existing one moves the name to
+    /// the current point.
+    pub async fn savepoint(&mut self, name: &str) -> Result<(), DatabaseError> {
+        let sql = format!("SAVEPOINT {}", get_dialect().quote_identifier(name));
+        self.execute(&sql).await.map(|_| ())
+    }
+
+    /// Undoes everything run since the savepoint `name` was created.
+    ///
+    /// The transaction stays open, and the savepoint can be rolled back to again.
+    pub async fn rollback_to(&mut self, name: &str) -> Result<(), DatabaseError> {
+        let sql = format!(
+            "ROLLBACK TO SAVEPOINT {}",
+            get_dialect().quote_identifier(name)
+        );
+        self.execute(&sql).await.map(|_| ())
+    }
+
+    /// Forgets the savepoint `name`, keeping everything run since it was created.
+    pub async fn release_savepoint(&mut self, name: &str) -> Result<(), DatabaseError> {
+        let sql = format!("RELEASE SAVEPOINT {}", get_dialect().quote_identifier(name));
+        self.execute(&sql).await.map(|_| ())
+    }
+
+    /// Commits the transaction.
+    pub async fn commit(self) -> Result<(), DatabaseError> {
+        self.tx
+            .commit()
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))
+    }
+
+    /// Rolls back the whole transaction.
+    pub async fn rollback(self) -> Result<(), DatabaseError> {
+        self.tx
+            .rollback()
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))
+    }
+}
@@ -12,10 +12,13 @@ use postgres::PostgresDialect;
 #[cfg(feature = "sqlite")]
 use sqlite::SqliteDialect;
 
+use std::time::Duration;
+
 use crate::{
-    filter::FilterType,
+    database::TruncateOptions,
+    filter::{DatePart, FilterType},
     helpers::{ColumnBindingKind, SqlBindQuery},
-    schema::ColumnInfo,
+    schema::{ColumnInfo, GeneratedColumn, Value, native_enum_type},
 };
 
 /// Trait for database-specific SQL generation and binding behavior.
@@ -29,12 +32,61 @@ pub trait SqlDialect {
     /// Quote an identifier (table name, column name) according to backend rules.
     fn quote_identifier(&self, identifier: &str) -> String;
 
+    /// Quote a possibly schema-qualified name, quoting each `.`-separated part on its own.
+    ///
+    /// `analytics.events` becomes `"analytics"."events"` on Postgres and
+    /// `` `analytics`.`events` `` on MySQL. Used for table names; column names
+    /// keep going through [`quote_identifier`](Self::quote_identifier).
+    fn quote_qualified(&self, name: &str) -> String {
+        name.split('.')
+            .map(|part| self.quote_identifier(part))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Quote a `table.column` reference, such as the names a `Select` struct
+    /// returns, so reserved words like `order` can be used as column names.
+    /// A bare `*` is left as is.
+    fn quote_column_ref(&self, column: &str) -> String {
+        if column == "*" {
+            return column.to_string();
+        }
+        self.quote_qualified(column)
+    }
+
     /// Generate a placeholder for the given index (0-based).
     ///
     /// - MySQL / SQLite: always `"?"` (index is ignored)
     /// - Postgres: `"$1"`, `"$2"`, ... (1-based)
     fn placeholder(&self, index: usize) -> String;
 
+    /// Cast a bound parameter to a `native_enum()` column's type.
+    ///
+    /// - Postgres: `<placeholder>::<type_name>`, since a `TEXT` parameter is
+    ///   not converted to an enum implicitly
+    /// - MySQL / SQLite: the placeholder unchanged
+    fn enum_param_sql(&self, placeholder: String, _type_name: &str) -> String {
+        placeholder
+    }
+
+    /// Render one parenthesized `VALUES` tuple for `columns`, numbering the
+    /// placeholders from `first` and casting `native_enum()` columns with
+    /// [`enum_param_sql`](Self::enum_param_sql).
+    fn values_tuple_sql(&self, columns: &[ColumnInfo], first: usize) -> String {
+        let placeholders: Vec<String> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                let placeholder = self.placeholder(first + i);
+                match native_enum_type(col.constraints) {
+                    Some(type_name) => self.enum_param_sql(placeholder, type_name),
+                    None => placeholder,
+                }
+            })
+            .collect();
+        format!("({})", placeholders.join(", "))
+    }
+
     /// Adapt SQL syntax for backend-specific requirements (e.g., DDL rewrites).
     fn adapt_sql(&self, sql: String) -> String;
 
@@ -42,7 +94,8 @@ pub trait SqlDialect {
     ///
     /// For:
     /// - Postgres / SQLite: appends `RETURNING col1, col2, ...;`
-    /// - MySQL: usually a no-op (returns the same `sql`)
+    /// - MySQL: not available, inserted rows are read back by primary key instead
+    #[cfg(any(feature = "postgres", feature = "sqlite"))]
     fn returning_sql(&self, sql: String, returning: &Vec<&'static str>) -> String;
 
     /// Build a simple filter expression when no specialized behavior is needed.
@@ -61,6 +114,12 @@ pub trait SqlDialect {
         idx: usize,
     ) -> String;
 
+    /// The operator for equality that treats two `NULL`s as equal.
+    ///
+    /// - MySQL: `<=>`
+    /// - Postgres / SQLite: `IS NOT DISTINCT FROM`
+    fn null_safe_eq_operator(&self) -> &'static str;
+
     /// Bind a `NULL` of the appropriate Rust type for this dialect.
     fn bind_null<'q>(&self, query: SqlBindQuery<'q>, kind: ColumnBindingKind) -> SqlBindQuery<'q>;
 
@@ -74,9 +133,252 @@ pub trait SqlDialect {
     /// no longer needs any `#[cfg(feature = "...")]` logic for SQL construction.
     fn insert_sql(&self, sql: String, columns: &Vec<ColumnInfo>) -> String;
 
+    /// Append a conflict clause to an `INSERT` so that a row whose `conflict`
+    /// columns already exist has its `update` columns overwritten instead.
+    ///
+    /// - MySQL: `ON DUPLICATE KEY UPDATE col = VALUES(col), ...`
+    /// - Postgres / SQLite: `ON CONFLICT (key, ...) DO UPDATE SET col = EXCLUDED.col, ...`
+    fn upsert_sql(&self, sql: String, conflict: &[&str], update: &[&str]) -> String;
+
+    /// Render the DDL clause for a generated column, including the leading space.
+    ///
+    /// - MySQL / SQLite: ` GENERATED ALWAYS AS (<expr>) VIRTUAL|STORED`
+    /// - Postgres: ` GENERATED ALWAYS AS (<expr>) STORED` (virtual columns aren't supported)
+    fn generated_column_sql(&self, generated: &GeneratedColumn) -> String {
+        match generated {
+            GeneratedColumn::Virtual(expression) => {
+                format!(" GENERATED ALWAYS AS ({}) VIRTUAL", expression)
+            }
+            GeneratedColumn::Stored(expression) => {
+                format!(" GENERATED ALWAYS AS ({}) STORED", expression)
+            }
+        }
+    }
+
+    /// Render the DDL clause for an invisible column, including the leading space.
+    ///
+    /// - MySQL: ` INVISIBLE` (MySQL 8.0.23+)
+    /// - Postgres / SQLite: empty, since they have no invisible columns
+    fn invisible_column_sql(&self) -> &'static str;
+
+    /// Render a comparison of the JSON element at `path` (e.g. `$.address.city`)
+    /// in `column` with the bound `placeholder`.
+    ///
+    /// - MySQL / SQLite: `JSON_EXTRACT(column, '<path>') = <placeholder>`
+    /// - Postgres: `column->'address'->>'city' = CAST(<placeholder> AS TEXT)`
+    ///
+    /// Returns `None` if the path can't be rendered.
+    fn json_path_eq_sql(&self, column: &str, path: &str, placeholder: &str) -> Option<String> {
+        if !path.starts_with('$') {
+            return None;
+        }
+
+        Some(format!(
+            "JSON_EXTRACT({}, {}) = {}",
+            column,
+            self.string_literal(path),
+            placeholder
+        ))
+    }
+
+    /// Build the statement setting (or, for `None`, resetting) the server-side
+    /// limit on how long a statement may run on the current session.
+    ///
+    /// - Postgres: `SET statement_timeout = <ms>`
+    /// - MySQL / SQLite: `None`, the timeout is only enforced by the client
+    fn statement_timeout_sql(&self, _timeout: Option<Duration>) -> Option<String> {
+        None
+    }
+
+    /// Whether `DELETE` accepts a `LIMIT` clause.
+    ///
+    /// - MySQL: yes
+    /// - Postgres: no
+    /// - SQLite: only when built with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`,
+    ///   which the bundled library isn't, so no
+    fn supports_delete_limit(&self) -> bool {
+        false
+    }
+
+    /// Build a statement that removes every row from `table`.
+    ///
+    /// - MySQL / Postgres: `TRUNCATE TABLE <table>`
+    /// - SQLite: `DELETE FROM <table>` (SQLite has no `TRUNCATE`)
+    fn truncate_sql(&self, table: &str, options: &TruncateOptions) -> String;
+
+    /// A query listing the names of a live table's columns, taking the table
+    /// name as its only bind parameter.
+    ///
+    /// - MySQL / Postgres: `information_schema.columns` in the current schema
+    /// - SQLite: `pragma_table_info`
+    fn existing_columns_sql(&self) -> &'static str;
+
+    /// Whether `ALTER TABLE ... ADD COLUMN` accepts a non-constant default such
+    /// as `DEFAULT CURRENT_TIMESTAMP` or a parenthesized expression.
+    ///
+    /// - MySQL / Postgres: `true`
+    /// - SQLite: `false`, only constant defaults can be added
+    fn add_column_allows_expression_default(&self) -> bool {
+        true
+    }
+
+    /// Whether schema changes can be rolled back as part of a transaction.
+    ///
+    /// - Postgres / SQLite: `true`
+    /// - MySQL: `false`, DDL statements commit implicitly
+    fn supports_transactional_ddl(&self) -> bool {
+        true
+    }
+
+    /// Render `value` as a quoted SQL string literal.
+    ///
+    /// - MySQL: doubles `'` and `\`, since backslash is an escape character there
+    /// - Postgres / SQLite: doubles `'`
+    fn string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+
+    /// Render a membership test against a single bound array, used for `IN`
+    /// lists too long to bind one placeholder per value, or `None` if the
+    /// dialect can't bind arrays.
+    ///
+    /// - Postgres: `<column> = ANY(<placeholder>)`, or
+    ///   `<column> <> ALL(<placeholder>)` for `NOT IN`
+    /// - MySQL / SQLite: `None`
+    fn in_array_param_sql(
+        &self,
+        _column: &str,
+        _in_array: bool,
+        _placeholder: &str,
+    ) -> Option<String> {
+        None
+    }
+
+    /// Render a boolean as a SQL literal, as used in column defaults.
+    ///
+    /// - MySQL / SQLite: `1` / `0`, which SQLite before 3.23 requires
+    /// - Postgres: `TRUE` / `FALSE`, since a `BOOLEAN` column rejects integers
+    fn bool_literal(&self, value: bool) -> &'static str {
+        if value { "1" } else { "0" }
+    }
+
+    /// Render an aggregate joining `column`'s values with `separator`, pushing
+    /// the separator onto `params` where it can be bound.
+    ///
+    /// - MySQL: `GROUP_CONCAT(<column> SEPARATOR '<separator>')`, inlined as a
+    ///   string literal since `SEPARATOR` doesn't accept a parameter
+    /// - Postgres: `STRING_AGG(CAST(<column> AS TEXT), $n)`
+    /// - SQLite: `GROUP_CONCAT(<column>, ?)`
+    fn string_agg_sql(&self, column: &str, separator: &str, params: &mut Vec<Value>) -> String;
+
+    /// Render a count of the distinct combinations of `columns`, or `None`
+    /// when the dialect has no multi-column `COUNT(DISTINCT ...)`.
+    ///
+    /// - MySQL: `COUNT(DISTINCT <a>, <b>)`
+    /// - Postgres: `COUNT(DISTINCT (<a>, <b>))`
+    /// - SQLite: `None`, so the caller counts a `SELECT DISTINCT` subquery
+    fn count_distinct_sql(&self, _columns: &[String]) -> Option<String> {
+        None
+    }
+
+    /// Prefix `sql` so it returns the query plan instead of the rows.
+    ///
+    /// - MySQL: `EXPLAIN FORMAT=TREE <sql>`, or `EXPLAIN ANALYZE <sql>`
+    /// - Postgres: `EXPLAIN <sql>`, or `EXPLAIN ANALYZE <sql>`
+    /// - SQLite: `EXPLAIN QUERY PLAN <sql>`; SQLite can't analyze, so
+    ///   `analyze` is ignored
+    fn explain_sql(&self, sql: &str, analyze: bool) -> String;
+
+    /// Render `part` of the date in `column` as an integer expression.
+    ///
+    /// - MySQL: `YEAR(<column>)`, `MONTH(<column>)`, ...
+    /// - Postgres: `EXTRACT(YEAR FROM <column>)`
+    /// - SQLite: `CAST(strftime('%Y', <column>) AS INTEGER)`, since `strftime`
+    ///   returns text, which never equals a bound integer
+    fn date_part_sql(&self, column: &str, part: DatePart) -> String;
+
+    /// Render the type of a `native_enum()` column, plus anything that must
+    /// follow the type to restrict its values.
+    ///
+    /// - MySQL: `ENUM('a', 'b')`
+    /// - Postgres: the enum type's name
+    /// - SQLite: `TEXT CHECK (<column> IN ('a', 'b'))`
+    fn native_enum_column_type(&self, column: &str, type_name: &str, values: &[&str]) -> String;
+
+    /// Build the statement creating a `native_enum()` type, if the backend
+    /// declares enum types separately from the table.
+    ///
+    /// - Postgres: a `CREATE TYPE ... AS ENUM` that does nothing if the type exists
+    /// - MySQL / SQLite: `None`
+    fn create_enum_type_sql(&self, _type_name: &str, _values: &[&str]) -> Option<String> {
+        None
+    }
+
+    /// The column type to declare for a `String` column without a
+    /// [`length`](crate::schema::Column::length).
+    ///
+    /// - MySQL / SQLite: `VARCHAR(255)`
+    /// - Postgres: `TEXT`, which is stored like `VARCHAR` but has no length cap
+    fn default_string_type(&self) -> &'static str {
+        "VARCHAR(255)"
+    }
+
+    /// The column type to declare for an integer primary key.
+    ///
+    /// - MySQL / Postgres: the column's own type
+    /// - SQLite: `INTEGER`, the only type that makes the key an alias for the
+    ///   auto-assigned rowid
+    fn integer_primary_key_type<'a>(&self, data_type: &'a str) -> &'a str {
+        data_type
+    }
+
+    /// Render what an `indexed_expr()` index covers.
+    ///
+    /// - Postgres / SQLite: the expression, e.g. `LOWER(email)`
+    /// - MySQL: the plain column, since functional indexes need MySQL 8.0.13+
+    fn expression_index_target(&self, _column: &str, expression: &str) -> String {
+        expression.to_string()
+    }
+
+    /// Render one side of a `UNION`.
+    ///
+    /// - MySQL / Postgres: the parenthesized `SELECT`, so each side keeps its
+    ///   own `ORDER BY` and `LIMIT`
+    /// - SQLite: `SELECT * FROM (...)`, since it rejects parenthesized
+    ///   compound operands
+    fn union_operand_sql(&self, select: &str) -> String {
+        format!("({})", select)
+    }
+
+    /// Render the `WHERE` clause of an `indexed_where()` index.
+    ///
+    /// - Postgres / SQLite: ` WHERE <predicate>`
+    /// - MySQL: `None`, since it has no partial indexes
+    fn partial_index_where(&self, predicate: &str) -> Option<String> {
+        Some(format!(" WHERE {}", predicate))
+    }
+
+    /// The longest identifier, such as an index name, the backend accepts.
+    ///
+    /// - MySQL: 64
+    /// - Postgres: 63, since longer names are silently truncated
+    /// - SQLite: `None`, since it has no limit
+    fn max_identifier_length(&self) -> Option<usize> {
+        None
+    }
+
     // fn returning() -> String;
 }
 
+/// Renders enum values as a comma-separated list of string literals.
+pub(crate) fn enum_values_sql(dialect: &dyn SqlDialect, values: &[&str]) -> String {
+    values
+        .iter()
+        .map(|value| dialect.string_literal(value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Get the appropriate dialect for the current backend.
 ///
 /// Only one of the branches below is compiled, depending on enabled features.
@@ -93,13 +395,4 @@ pub fn get_dialect() -> Box<dyn SqlDialect> {
 
     #[cfg(all(not(feature = "mysql"), not(feature = "postgres"), feature = "sqlite"))]
     return Box::new(SqliteDialect);
-
-    #[cfg(all(
-        not(feature = "mysql"),
-        not(feature = "postgres"),
-        not(feature = "sqlite")
-    ))]
-    compile_error!(
-        "At least one database backend feature (mysql, postgres, sqlite) must be enabled"
-    );
 }
@@ -1,7 +1,9 @@
 use crate::{
-    dialects::SqlDialect,
-    filter::FilterType,
+    database::TruncateOptions,
+    dialects::{SqlDialect, enum_values_sql},
+    filter::{DatePart, FilterType},
     helpers::{ColumnBindingKind, SqlBindQuery},
+    schema::Value,
 };
 
 // MySQL Implementation
@@ -20,12 +22,8 @@ impl SqlDialect for MySqlDialect {
     }
 
     fn adapt_sql(&self, sql: String) -> String {
-        // MySQL-specific transformations (if needed)
-        sql
-    }
-
-    fn returning_sql(&self, sql: String, _returning: &Vec<&'static str>) -> String {
-        sql
+        // A bare DATETIME rounds away fractional seconds
+        sql.replace("DATETIME", "DATETIME(6)")
     }
 
     fn build_filter_expr_fallback(
@@ -34,7 +32,16 @@ impl SqlDialect for MySqlDialect {
         filter: &FilterType,
         _idx: usize,
     ) -> String {
-        format!("{}.{} {} ?", col1.0, col1.1, filter.to_sql())
+        format!(
+            "{}.{} {} ?",
+            self.quote_qualified(&col1.0),
+            self.quote_identifier(&col1.1),
+            filter.to_sql()
+        )
+    }
+
+    fn null_safe_eq_operator(&self) -> &'static str {
+        "<=>"
     }
 
     fn bind_null<'q>(&self, query: SqlBindQuery<'q>, kind: ColumnBindingKind) -> SqlBindQuery<'q> {
@@ -63,17 +70,94 @@ impl SqlDialect for MySqlDialect {
             }
             sql.push_str(&self.quote_identifier(&col.name));
         }
-        sql.push_str(") VALUES (");
+        sql.push_str(") VALUES ");
+        sql.push_str(&self.values_tuple_sql(columns, 0));
 
-        for (i, _col) in columns.iter().enumerate() {
+        sql
+    }
+
+    fn upsert_sql(&self, mut sql: String, conflict: &[&str], update: &[&str]) -> String {
+        sql.push_str(" ON DUPLICATE KEY UPDATE ");
+
+        // MySQL has no DO NOTHING; assigning a key to itself leaves the row untouched
+        if update.is_empty() {
+            let key = self.quote_identifier(conflict[0]);
+            sql.push_str(&format!("{} = {}", key, key));
+            return sql;
+        }
+
+        for (i, col) in update.iter().enumerate() {
             if i > 0 {
                 sql.push_str(", ");
             }
-            sql.push_str("?");
+            let col = self.quote_identifier(col);
+            sql.push_str(&format!("{} = VALUES({})", col, col));
         }
 
-        sql.push_str(")");
-
         sql
     }
+
+    fn expression_index_target(&self, column: &str, _expression: &str) -> String {
+        self.quote_identifier(column)
+    }
+
+    fn partial_index_where(&self, _predicate: &str) -> Option<String> {
+        None
+    }
+
+    fn max_identifier_length(&self) -> Option<usize> {
+        Some(64)
+    }
+
+    fn supports_delete_limit(&self) -> bool {
+        true
+    }
+
+    fn invisible_column_sql(&self) -> &'static str {
+        " INVISIBLE"
+    }
+
+    fn truncate_sql(&self, table: &str, _options: &TruncateOptions) -> String {
+        format!("TRUNCATE TABLE {}", self.quote_qualified(table))
+    }
+
+    fn supports_transactional_ddl(&self) -> bool {
+        false
+    }
+
+    fn existing_columns_sql(&self) -> &'static str {
+        "SELECT CAST(COLUMN_NAME AS CHAR) FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?"
+    }
+
+    fn native_enum_column_type(&self, _column: &str, _type_name: &str, values: &[&str]) -> String {
+        format!("ENUM({})", enum_values_sql(self, values))
+    }
+
+    fn string_agg_sql(&self, column: &str, separator: &str, _params: &mut Vec<Value>) -> String {
+        format!(
+            "GROUP_CONCAT({} SEPARATOR {})",
+            column,
+            self.string_literal(separator)
+        )
+    }
+
+    fn count_distinct_sql(&self, columns: &[String]) -> Option<String> {
+        Some(format!("COUNT(DISTINCT {})", columns.join(", ")))
+    }
+
+    fn date_part_sql(&self, column: &str, part: DatePart) -> String {
+        format!("{}({})", part, column)
+    }
+
+    fn explain_sql(&self, sql: &str, analyze: bool) -> String {
+        if analyze {
+            format!("EXPLAIN ANALYZE {}", sql)
+        } else {
+            format!("EXPLAIN FORMAT=TREE {}", sql)
+        }
+    }
+
+    fn string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+    }
 }
@@ -1,7 +1,11 @@
+use std::time::Duration;
+
 use crate::{
-    dialects::SqlDialect,
-    filter::FilterType,
+    database::TruncateOptions,
+    dialects::{SqlDialect, enum_values_sql},
+    filter::{DatePart, FilterType},
     helpers::{ColumnBindingKind, SqlBindQuery},
+    schema::{GeneratedColumn, Value},
 };
 
 // PostgreSQL Implementation
@@ -19,10 +23,13 @@ impl SqlDialect for PostgresDialect {
         format!("${}", index + 1)
     }
 
+    fn enum_param_sql(&self, placeholder: String, type_name: &str) -> String {
+        format!("{}::{}", placeholder, type_name)
+    }
+
     fn adapt_sql(&self, sql: String) -> String {
         sql.replace("AUTO_INCREMENT", "GENERATED ALWAYS AS IDENTITY")
             .replace("DEFAULT (UUID())", "DEFAULT gen_random_uuid()")
-            .replace("VARCHAR(255)", "TEXT")
             .replace("UNSIGNED", "")
             .replace("DATETIME", "TIMESTAMPTZ")
     }
@@ -37,7 +44,7 @@ impl SqlDialect for PostgresDialect {
             if i > 0 {
                 sql.push_str(", ");
             }
-            sql.push_str(col);
+            sql.push_str(&self.quote_column_ref(col));
         }
         sql.push_str(";");
         sql
@@ -49,7 +56,17 @@ impl SqlDialect for PostgresDialect {
         filter: &FilterType,
         idx: usize,
     ) -> String {
-        format!("{}.{} {} ${}", col1.0, col1.1, filter.to_sql(), idx)
+        format!(
+            "{}.{} {} ${}",
+            self.quote_qualified(&col1.0),
+            self.quote_identifier(&col1.1),
+            filter.to_sql(),
+            idx
+        )
+    }
+
+    fn null_safe_eq_operator(&self) -> &'static str {
+        "IS NOT DISTINCT FROM"
     }
 
     fn bind_null<'q>(&self, query: SqlBindQuery<'q>, kind: ColumnBindingKind) -> SqlBindQuery<'q> {
@@ -80,18 +97,170 @@ impl SqlDialect for PostgresDialect {
             }
             sql.push_str(&self.quote_identifier(&col.name));
         }
-        sql.push_str(") VALUES (");
+        sql.push_str(") VALUES ");
 
         // Use $1, $2, $3... for Postgres
-        for (i, _col) in columns.iter().enumerate() {
+        sql.push_str(&self.values_tuple_sql(columns, 0));
+
+        sql
+    }
+
+    fn upsert_sql(&self, mut sql: String, conflict: &[&str], update: &[&str]) -> String {
+        let conflict: Vec<String> = conflict
+            .iter()
+            .map(|col| self.quote_identifier(col))
+            .collect();
+        sql.push_str(&format!(" ON CONFLICT ({})", conflict.join(", ")));
+
+        if update.is_empty() {
+            sql.push_str(" DO NOTHING");
+            return sql;
+        }
+
+        sql.push_str(" DO UPDATE SET ");
+        for (i, col) in update.iter().enumerate() {
             if i > 0 {
                 sql.push_str(", ");
             }
-            sql.push_str(&format!("${}", i + 1));
+            let col = self.quote_identifier(col);
+            sql.push_str(&format!("{} = EXCLUDED.{}", col, col));
         }
 
-        sql.push_str(")");
+        sql
+    }
+
+    fn generated_column_sql(&self, generated: &GeneratedColumn) -> String {
+        let (GeneratedColumn::Virtual(expression) | GeneratedColumn::Stored(expression)) =
+            generated;
+        format!(" GENERATED ALWAYS AS ({}) STORED", expression)
+    }
+
+    fn invisible_column_sql(&self) -> &'static str {
+        ""
+    }
 
+    fn truncate_sql(&self, table: &str, options: &TruncateOptions) -> String {
+        let mut sql = format!("TRUNCATE TABLE {}", self.quote_qualified(table));
+        if options.restart_identity {
+            sql.push_str(" RESTART IDENTITY");
+        }
+        if options.cascade {
+            sql.push_str(" CASCADE");
+        }
         sql
     }
+
+    fn native_enum_column_type(&self, _column: &str, type_name: &str, _values: &[&str]) -> String {
+        type_name.to_string()
+    }
+
+    fn create_enum_type_sql(&self, type_name: &str, values: &[&str]) -> Option<String> {
+        // Postgres has no CREATE TYPE IF NOT EXISTS
+        Some(format!(
+            "DO $$ BEGIN CREATE TYPE {} AS ENUM ({}); EXCEPTION WHEN duplicate_object THEN NULL; END $$",
+            type_name,
+            enum_values_sql(self, values)
+        ))
+    }
+
+    fn string_agg_sql(&self, column: &str, separator: &str, params: &mut Vec<Value>) -> String {
+        let placeholder = self.placeholder(params.len());
+        params.push(Value::String(separator.to_string()));
+        format!("STRING_AGG(CAST({} AS TEXT), {})", column, placeholder)
+    }
+
+    fn in_array_param_sql(
+        &self,
+        column: &str,
+        in_array: bool,
+        placeholder: &str,
+    ) -> Option<String> {
+        Some(if in_array {
+            format!("{} = ANY({})", column, placeholder)
+        } else {
+            format!("{} <> ALL({})", column, placeholder)
+        })
+    }
+
+    fn bool_literal(&self, value: bool) -> &'static str {
+        if value { "TRUE" } else { "FALSE" }
+    }
+
+    fn default_string_type(&self) -> &'static str {
+        "TEXT"
+    }
+
+    fn count_distinct_sql(&self, columns: &[String]) -> Option<String> {
+        Some(format!("COUNT(DISTINCT ({}))", columns.join(", ")))
+    }
+
+    fn date_part_sql(&self, column: &str, part: DatePart) -> String {
+        format!("EXTRACT({} FROM {})", part, column)
+    }
+
+    fn explain_sql(&self, sql: &str, analyze: bool) -> String {
+        if analyze {
+            format!("EXPLAIN ANALYZE {}", sql)
+        } else {
+            format!("EXPLAIN {}", sql)
+        }
+    }
+
+    fn existing_columns_sql(&self) -> &'static str {
+        // Tables created with an unquoted name were folded to lowercase
+        "SELECT column_name::text FROM information_schema.columns WHERE table_schema = current_schema() AND lower(table_name) = lower($1)"
+    }
+
+    fn statement_timeout_sql(&self, timeout: Option<Duration>) -> Option<String> {
+        Some(match timeout {
+            // 0 disables the timeout, so round up to at least 1ms
+            Some(timeout) => format!("SET statement_timeout = {}", timeout.as_millis().max(1)),
+            None => "SET statement_timeout = DEFAULT".to_string(),
+        })
+    }
+
+    fn json_path_eq_sql(&self, column: &str, path: &str, placeholder: &str) -> Option<String> {
+        let steps = json_path_steps(self, path)?;
+        let (last, rest) = steps.split_last()?;
+
+        let mut sql = column.to_string();
+        for step in rest {
+            sql.push_str(&format!("->{}", step));
+        }
+        // `->>` yields text, so the bound value is compared as text too
+        sql.push_str(&format!("->>{} = CAST({} AS TEXT)", last, placeholder));
+
+        Some(sql)
+    }
+
+    fn max_identifier_length(&self) -> Option<usize> {
+        Some(63)
+    }
+}
+
+/// Splits a JSON path like `$.items[0].name` into `->` operands: quoted keys
+/// and array indexes. Returns `None` for a malformed or empty path.
+fn json_path_steps(dialect: &PostgresDialect, path: &str) -> Option<Vec<String>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut steps = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end == 0 {
+                return None;
+            }
+            steps.push(dialect.string_literal(&after[..end]));
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            let index: usize = after[..end].parse().ok()?;
+            steps.push(index.to_string());
+            rest = &after[end + 1..];
+        } else {
+            return None;
+        }
+    }
+
+    Some(steps)
 }
@@ -1,7 +1,9 @@
 use crate::{
-    dialects::SqlDialect,
-    filter::FilterType,
+    database::TruncateOptions,
+    dialects::{SqlDialect, enum_values_sql},
+    filter::{DatePart, FilterType},
     helpers::{ColumnBindingKind, SqlBindQuery},
+    schema::Value,
 };
 
 // SQLite Implementation
@@ -22,11 +24,21 @@ impl SqlDialect for SqliteDialect {
     fn adapt_sql(&self, sql: String) -> String {
         sql.replace("DEFAULT (UUID())", "DEFAULT (lower(hex(randomblob(16))))")
             .replace("DATETIME", "TEXT")
+            // NUMERIC affinity would turn integers past 64 bits into lossy REALs
+            .replace("DECIMAL(39,0)", "TEXT")
             .replace("CURRENT_TIMESTAMP", "(datetime('now'))")
             .replace(" AUTO_INCREMENT", "")
             .replace("AUTO_INCREMENT ", "")
     }
 
+    fn integer_primary_key_type<'a>(&self, _data_type: &'a str) -> &'a str {
+        "INTEGER"
+    }
+
+    fn union_operand_sql(&self, select: &str) -> String {
+        format!("SELECT * FROM ({})", select)
+    }
+
     fn returning_sql(&self, mut sql: String, returning: &Vec<&'static str>) -> String {
         if returning.is_empty() {
             return sql;
@@ -37,7 +49,7 @@ impl SqlDialect for SqliteDialect {
             if i > 0 {
                 sql.push_str(", ");
             }
-            sql.push_str(col);
+            sql.push_str(&self.quote_column_ref(col));
         }
         sql.push_str(";");
         sql
@@ -49,7 +61,16 @@ impl SqlDialect for SqliteDialect {
         filter: &FilterType,
         _idx: usize,
     ) -> String {
-        format!("{}.{} {} ?", col1.0, col1.1, filter.to_sql())
+        format!(
+            "{}.{} {} ?",
+            self.quote_qualified(&col1.0),
+            self.quote_identifier(&col1.1),
+            filter.to_sql()
+        )
+    }
+
+    fn null_safe_eq_operator(&self) -> &'static str {
+        "IS NOT DISTINCT FROM"
     }
 
     fn bind_null<'q>(&self, query: SqlBindQuery<'q>, kind: ColumnBindingKind) -> SqlBindQuery<'q> {
@@ -80,18 +101,80 @@ impl SqlDialect for SqliteDialect {
             }
             sql.push_str(&self.quote_identifier(&col.name));
         }
-        sql.push_str(") VALUES (");
+        sql.push_str(") VALUES ");
+        sql.push_str(&self.values_tuple_sql(columns, 0));
 
-        // Use ? for MySQL and SQLite
-        for (i, _col) in columns.iter().enumerate() {
+        sql
+    }
+
+    fn upsert_sql(&self, mut sql: String, conflict: &[&str], update: &[&str]) -> String {
+        let conflict: Vec<String> = conflict
+            .iter()
+            .map(|col| self.quote_identifier(col))
+            .collect();
+        sql.push_str(&format!(" ON CONFLICT ({})", conflict.join(", ")));
+
+        if update.is_empty() {
+            sql.push_str(" DO NOTHING");
+            return sql;
+        }
+
+        sql.push_str(" DO UPDATE SET ");
+        for (i, col) in update.iter().enumerate() {
             if i > 0 {
                 sql.push_str(", ");
             }
-            sql.push_str("?");
+            let col = self.quote_identifier(col);
+            sql.push_str(&format!("{} = EXCLUDED.{}", col, col));
         }
 
-        sql.push_str(")");
-
         sql
     }
+
+    fn invisible_column_sql(&self) -> &'static str {
+        ""
+    }
+
+    fn truncate_sql(&self, table: &str, _options: &TruncateOptions) -> String {
+        // SQLite has no TRUNCATE; an unqualified DELETE uses the truncate optimization
+        format!("DELETE FROM {}", self.quote_qualified(table))
+    }
+
+    fn native_enum_column_type(&self, column: &str, _type_name: &str, values: &[&str]) -> String {
+        format!(
+            "TEXT CHECK ({} IN ({}))",
+            self.quote_identifier(column),
+            enum_values_sql(self, values)
+        )
+    }
+
+    fn string_agg_sql(&self, column: &str, separator: &str, params: &mut Vec<Value>) -> String {
+        let placeholder = self.placeholder(params.len());
+        params.push(Value::String(separator.to_string()));
+        format!("GROUP_CONCAT({}, {})", column, placeholder)
+    }
+
+    fn date_part_sql(&self, column: &str, part: DatePart) -> String {
+        let format = match part {
+            DatePart::Year => "%Y",
+            DatePart::Month => "%m",
+            DatePart::Day => "%d",
+            DatePart::Hour => "%H",
+            DatePart::Minute => "%M",
+            DatePart::Second => "%S",
+        };
+        format!("CAST(strftime('{}', {}) AS INTEGER)", format, column)
+    }
+
+    fn explain_sql(&self, sql: &str, _analyze: bool) -> String {
+        format!("EXPLAIN QUERY PLAN {}", sql)
+    }
+
+    fn existing_columns_sql(&self) -> &'static str {
+        "SELECT name FROM pragma_table_info(?)"
+    }
+
+    fn add_column_allows_expression_default(&self) -> bool {
+        false
+    }
 }
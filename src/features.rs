@@ -0,0 +1,15 @@
+// Every module binds values and builds SQL for a single backend and is gated
+// on the `lume_backend` cfg set by `build.rs`, so with none or several
+// backends enabled only these errors are reported. The checks live in their
+// own file so `tests/ui` can build them without the rest of the crate.
+#[cfg(not(any(feature = "mysql", feature = "postgres", feature = "sqlite")))]
+compile_error!("enable one of the mysql/postgres/sqlite features");
+
+#[cfg(any(
+    all(feature = "mysql", feature = "postgres"),
+    all(feature = "mysql", feature = "sqlite"),
+    all(feature = "postgres", feature = "sqlite")
+))]
+compile_error!(
+    "enable only one of the mysql/postgres/sqlite features (use default-features = false for postgres or sqlite)"
+);
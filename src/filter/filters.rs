@@ -3,8 +3,10 @@
 use std::fmt::Debug;
 
 use crate::{
+    dialects::get_dialect,
     filter::{
-        AndFilter, ArrayFilter, Filter, FilterType, Filtered, NotFilter, OrFilter, SqlFilter,
+        AndFilter, ArrayFilter, Bounds, DatePart, Extract, Filter, FilterType, Filtered,
+        JsonPathFilter, NotFilter, OrFilter, SqlFilter,
     },
     schema::{Column, Value},
 };
@@ -38,7 +40,7 @@ use crate::{
 ///
 /// let filter = eq_value(User::name(), "Alice");
 /// ```
-pub fn eq_value<T, V>(column: &'static Column<T>, value: V) -> Filter
+pub fn eq_value<T, V>(column: &Column<T>, value: V) -> Filter
 where
     V: Into<Value>,
 {
@@ -50,6 +52,94 @@ where
         value: Some(value.into()),
         column_two: None,
         filter_type: FilterType::Eq,
+        enum_type: column.native_enum_type(),
+    }
+}
+
+/// Creates a null-safe equality filter between a column and a value.
+///
+/// Unlike [`eq_value`], two `NULL`s compare as equal, which makes it suitable
+/// for dedup and change-detection queries. This emits `<=>` on MySQL and
+/// `IS NOT DISTINCT FROM` on PostgreSQL and SQLite. Passing `None` (or
+/// [`Value::Null`]) matches rows where the column is `NULL`.
+///
+/// # Arguments
+///
+/// * `column` - The column to compare.
+/// * `value` - The value to compare against, which may be `None`.
+///
+/// # Returns
+///
+/// A [`Filter`] representing the null-safe equality condition.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::eq_null_safe;
+/// use lume::define_schema;
+/// use lume::schema::ColumnInfo;
+/// use lume::schema::Schema;
+///
+/// define_schema! {
+///     User {
+///         id: i32 [primary_key()],
+///         nickname: String,
+///     }
+/// }
+///
+/// let filter = eq_null_safe(User::nickname(), None::<String>);
+/// ```
+pub fn eq_null_safe<T, V>(column: &Column<T>, value: V) -> Filter
+where
+    V: Into<Value>,
+{
+    Filter {
+        column_one: (
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+        ),
+        value: Some(value.into()),
+        column_two: None,
+        filter_type: FilterType::NullSafeEq,
+        enum_type: column.native_enum_type(),
+    }
+}
+
+/// Creates a filter matching rows whose column equals any element of `values`.
+///
+/// Unlike [`in_array`], the values are bound as a single Postgres array
+/// parameter and emitted as `table.column = ANY($1)`, so the prepared
+/// statement stays the same no matter how many values are passed. All values
+/// must be of the same type. An empty list matches nothing.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::eq_any;
+/// use lume::define_schema;
+/// use lume::schema::ColumnInfo;
+/// use lume::schema::Schema;
+///
+/// define_schema! {
+///     User {
+///         id: i32 [primary_key()],
+///         name: String,
+///     }
+/// }
+///
+/// let filter = eq_any(User::id(), vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "postgres")]
+pub fn eq_any<T, K: Into<Value>>(column: &Column<T>, values: Vec<K>) -> Filter {
+    Filter {
+        column_one: (
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+        ),
+        value: Some(Value::Array(values.into_iter().map(Into::into).collect())),
+        column_two: None,
+        filter_type: FilterType::EqAny,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -61,7 +151,10 @@ where
 /// Never pass untrusted user input to this function. Doing so creates SQL injection vulnerabilities.
 /// Prefer parameterized filters (e.g., `eq_value`, `in_array`) when possible.
 pub fn sql(filter: String) -> SqlFilter {
-    SqlFilter { sql: filter }
+    SqlFilter {
+        sql: filter,
+        params: Vec::new(),
+    }
 }
 
 /// Creates an equality filter (`=`) for joining two columns.
@@ -100,7 +193,7 @@ pub fn sql(filter: String) -> SqlFilter {
 ///
 /// let join_filter = eq_column(User::id(), Post::user_id());
 /// ```
-pub fn eq_column<T>(column_1: &'static Column<T>, column_2: &'static Column<T>) -> Filter {
+pub fn eq_column<T>(column_1: &Column<T>, column_2: &Column<T>) -> Filter {
     Filter {
         column_one: (
             column_1.__internal_table_name().to_string(),
@@ -112,6 +205,7 @@ pub fn eq_column<T>(column_1: &'static Column<T>, column_2: &'static Column<T>)
             column_2.__internal_name().to_string(),
         )),
         filter_type: FilterType::Eq,
+        enum_type: None,
     }
 }
 
@@ -144,7 +238,7 @@ pub fn eq_column<T>(column_1: &'static Column<T>, column_2: &'static Column<T>)
 ///
 /// let filter = ne_value(User::age(), 30);
 /// ```
-pub fn ne_value<T, V>(column: &'static Column<T>, value: V) -> Filter
+pub fn ne_value<T, V>(column: &Column<T>, value: V) -> Filter
 where
     V: Into<Value>,
 {
@@ -156,6 +250,7 @@ where
         value: Some(value.into()),
         column_two: None,
         filter_type: FilterType::Neq,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -193,7 +288,7 @@ where
 ///
 /// let filter = ne_column(User::age(), User::other_age());
 /// ```
-pub fn ne_column<T>(column1: &'static Column<T>, column2: &'static Column<T>) -> Filter {
+pub fn ne_column<T>(column1: &Column<T>, column2: &Column<T>) -> Filter {
     Filter {
         column_one: (
             column1.__internal_table_name().to_string(),
@@ -205,6 +300,7 @@ pub fn ne_column<T>(column1: &'static Column<T>, column2: &'static Column<T>) ->
             column2.__internal_name().to_string(),
         )),
         filter_type: FilterType::Neq,
+        enum_type: None,
     }
 }
 
@@ -237,7 +333,7 @@ pub fn ne_column<T>(column1: &'static Column<T>, column2: &'static Column<T>) ->
 ///
 /// let filter = gt(User::age(), 18);
 /// ```
-pub fn gt<T, V>(column: &'static Column<T>, value: V) -> Filter
+pub fn gt<T, V>(column: &Column<T>, value: V) -> Filter
 where
     V: Into<Value>,
 {
@@ -249,6 +345,7 @@ where
         value: Some(value.into()),
         column_two: None,
         filter_type: FilterType::Gt,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -281,7 +378,7 @@ where
 /// use lume::filter::gte;
 /// let filter = gte(User::age(), 18);
 /// ```
-pub fn gte<T, V>(column: &'static Column<T>, value: V) -> Filter
+pub fn gte<T, V>(column: &Column<T>, value: V) -> Filter
 where
     V: Into<Value>,
 {
@@ -293,6 +390,7 @@ where
         value: Some(value.into()),
         column_two: None,
         filter_type: FilterType::Gte,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -325,7 +423,7 @@ where
 ///
 /// let filter = lt(User::age(), 65);
 /// ```
-pub fn lt<T, V>(column: &'static Column<T>, value: V) -> Filter
+pub fn lt<T, V>(column: &Column<T>, value: V) -> Filter
 where
     V: Into<Value>,
 {
@@ -337,6 +435,7 @@ where
         value: Some(value.into()),
         column_two: None,
         filter_type: FilterType::Lt,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -369,7 +468,7 @@ where
 ///
 /// let filter = lte(User::age(), 29);
 /// ```
-pub fn lte<T, V>(column: &'static Column<T>, value: V) -> Filter
+pub fn lte<T, V>(column: &Column<T>, value: V) -> Filter
 where
     V: Into<Value>,
 {
@@ -381,6 +480,7 @@ where
         value: Some(value.into()),
         column_two: None,
         filter_type: FilterType::Lte,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -510,6 +610,8 @@ pub fn not(filter: impl Filtered + 'static) -> NotFilter {
 /// This is equivalent to a SQL `IN` clause. The filter will match if the column's value is equal to
 /// any of the values in the provided array.
 ///
+/// A [`Value::Null`] in the array also matches rows where the column is `NULL`.
+///
 /// # Arguments
 ///
 /// * `column` - The column to filter on.
@@ -539,7 +641,7 @@ pub fn not(filter: impl Filtered + 'static) -> NotFilter {
 /// let filter = in_array(User::id(), ids);
 /// ```
 pub fn in_array<T: Debug, K: Into<Value>>(
-    column: &'static Column<T>,
+    column: &Column<T>,
     values: Vec<K>,
 ) -> impl Filtered + 'static {
     ArrayFilter {
@@ -550,6 +652,7 @@ pub fn in_array<T: Debug, K: Into<Value>>(
         values: Some(values.into_iter().map(|v| v.into()).collect()),
         _column2: None,
         in_array: true,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -558,6 +661,9 @@ pub fn in_array<T: Debug, K: Into<Value>>(
 /// This is equivalent to a SQL `NOT IN` clause. The filter will match if the column's value is not equal to
 /// any of the values in the provided array.
 ///
+/// A [`Value::Null`] in the array excludes rows where the column is `NULL`,
+/// instead of making the whole `NOT IN` match nothing as it would in plain SQL.
+///
 /// # Arguments
 ///
 /// * `column` - The column to filter on.
@@ -587,7 +693,7 @@ pub fn in_array<T: Debug, K: Into<Value>>(
 /// let filter = not_in_array(User::id(), ids);
 /// ```
 pub fn not_in_array<T: Debug, K: Into<Value>>(
-    column: &'static Column<T>,
+    column: &Column<T>,
     values: Vec<K>,
 ) -> impl Filtered + 'static {
     ArrayFilter {
@@ -598,6 +704,7 @@ pub fn not_in_array<T: Debug, K: Into<Value>>(
         values: Some(values.into_iter().map(|v| v.into()).collect()),
         _column2: None,
         in_array: false,
+        enum_type: column.native_enum_type(),
     }
 }
 
@@ -629,8 +736,8 @@ pub fn not_in_array<T: Debug, K: Into<Value>>(
 /// let filter = not_in_column(User::id(), Post::user_id());
 /// ```
 pub fn not_in_column<T: Debug, K: Debug>(
-    col1: &'static Column<T>,
-    col2: &'static Column<K>,
+    col1: &Column<T>,
+    col2: &Column<K>,
 ) -> impl Filtered + 'static {
     ArrayFilter {
         column1: Some((
@@ -643,6 +750,7 @@ pub fn not_in_column<T: Debug, K: Debug>(
             col2.__internal_name().to_string(),
         )),
         in_array: false,
+        enum_type: None,
     }
 }
 
@@ -674,8 +782,8 @@ pub fn not_in_column<T: Debug, K: Debug>(
 /// let filter = in_column(User::id(), Post::user_id());
 /// ```
 pub fn in_column<T: Debug, K: Debug>(
-    col1: &'static Column<T>,
-    col2: &'static Column<K>,
+    col1: &Column<T>,
+    col2: &Column<K>,
 ) -> impl Filtered + 'static {
     ArrayFilter {
         column1: Some((
@@ -688,6 +796,7 @@ pub fn in_column<T: Debug, K: Debug>(
             col2.__internal_name().to_string(),
         )),
         in_array: true,
+        enum_type: None,
     }
 }
 
@@ -720,7 +829,7 @@ pub fn in_column<T: Debug, K: Debug>(
 ///
 /// let filter = is_null(User::name());
 /// ```
-pub fn is_null<T: Debug>(column: &'static Column<T>) -> impl Filtered + 'static {
+pub fn is_null<T: Debug>(column: &Column<T>) -> impl Filtered + 'static {
     Filter {
         column_one: (
             column.__internal_table_name().to_string(),
@@ -729,6 +838,7 @@ pub fn is_null<T: Debug>(column: &'static Column<T>) -> impl Filtered + 'static
         value: Some(Value::Null),
         column_two: None,
         filter_type: FilterType::Eq,
+        enum_type: None,
     }
 }
 
@@ -761,7 +871,7 @@ pub fn is_null<T: Debug>(column: &'static Column<T>) -> impl Filtered + 'static
 ///
 /// let filter = is_not_null(User::name());
 /// ```
-pub fn is_not_null<T: Debug>(column: &'static Column<T>) -> impl Filtered + 'static {
+pub fn is_not_null<T: Debug>(column: &Column<T>) -> impl Filtered + 'static {
     Filter {
         column_one: (
             column.__internal_table_name().to_string(),
@@ -770,6 +880,7 @@ pub fn is_not_null<T: Debug>(column: &'static Column<T>) -> impl Filtered + 'sta
         value: Some(Value::Null),
         column_two: None,
         filter_type: FilterType::Neq,
+        enum_type: None,
     }
 }
 
@@ -803,10 +914,7 @@ pub fn is_not_null<T: Debug>(column: &'static Column<T>) -> impl Filtered + 'sta
 ///
 /// let filter = like(User::name(), "%doe%");
 /// ```
-pub fn like<T: Debug, P: Into<String>>(
-    column: &'static Column<T>,
-    pattern: P,
-) -> impl Filtered + 'static {
+pub fn like<T: Debug, P: Into<String>>(column: &Column<T>, pattern: P) -> impl Filtered + 'static {
     Filter {
         column_one: (
             column.__internal_table_name().to_string(),
@@ -815,7 +923,83 @@ pub fn like<T: Debug, P: Into<String>>(
         value: Some(Value::String(pattern.into())),
         column_two: None,
         filter_type: FilterType::Like,
+        enum_type: None,
+    }
+}
+
+/// Creates a `LIKE` filter with an `ESCAPE` clause, so wildcards in user data
+/// can be matched literally.
+///
+/// Inside `pattern`, `escape_char` followed by `%`, `_` or itself matches that
+/// character literally. Use [`escape_like`] to escape untrusted input before
+/// adding your own wildcards.
+///
+/// # Arguments
+///
+/// * `column` - The column to filter on.
+/// * `pattern` - The pattern to match, bound as a parameter.
+/// * `escape_char` - The escape character, rendered as `ESCAPE '<char>'`.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::{escape_like, like_escaped};
+/// use lume::define_schema;
+/// use lume::schema::ColumnInfo;
+/// use lume::schema::Schema;
+///
+/// define_schema! {
+///     Product {
+///         id: i32 [primary_key()],
+///         name: String,
+///     }
+/// }
+///
+/// // Matches names containing the literal text "100%"
+/// let pattern = format!("%{}%", escape_like("100%", '\\'));
+/// let filter = like_escaped(Product::name(), pattern, '\\');
+/// ```
+pub fn like_escaped<T: Debug, P: Into<String>>(
+    column: &Column<T>,
+    pattern: P,
+    escape_char: char,
+) -> SqlFilter {
+    let dialect = get_dialect();
+
+    SqlFilter {
+        sql: format!(
+            "{}.{} LIKE ? ESCAPE {}",
+            dialect.quote_qualified(column.__internal_table_name()),
+            dialect.quote_identifier(column.__internal_name()),
+            dialect.string_literal(&escape_char.to_string())
+        ),
+        params: vec![Value::String(pattern.into())],
+    }
+}
+
+/// Escapes the `LIKE` wildcards `%` and `_`, and `escape_char` itself, in `input`.
+///
+/// The result matches `input` literally when used with [`like_escaped`] and the
+/// same `escape_char`.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::escape_like;
+///
+/// assert_eq!(escape_like("50%_off", '\\'), "50\\%\\_off");
+/// ```
+pub fn escape_like(input: &str, escape_char: char) -> String {
+    let mut escaped = String::with_capacity(input.len());
+
+    for ch in input.chars() {
+        if ch == '%' || ch == '_' || ch == escape_char {
+            escaped.push(escape_char);
+        }
+        escaped.push(ch);
     }
+
+    escaped
 }
 
 #[cfg(not(feature = "mysql"))]
@@ -850,10 +1034,7 @@ pub fn like<T: Debug, P: Into<String>>(
 ///
 /// let filter = ilike(User::name(), "%doe%");
 /// ```
-pub fn ilike<T: Debug, P: Into<String>>(
-    column: &'static Column<T>,
-    pattern: P,
-) -> impl Filtered + 'static {
+pub fn ilike<T: Debug, P: Into<String>>(column: &Column<T>, pattern: P) -> impl Filtered + 'static {
     Filter {
         column_one: (
             column.__internal_table_name().to_string(),
@@ -862,6 +1043,55 @@ pub fn ilike<T: Debug, P: Into<String>>(
         value: Some(Value::String(pattern.into())),
         column_two: None,
         filter_type: FilterType::ILike,
+        enum_type: None,
+    }
+}
+
+/// Creates a filter that matches rows where the element at `path` inside a JSON column equals the given value.
+///
+/// The path uses the `$.key[index]` syntax, e.g. `$.address.city` or `$.tags[0]`. On MySQL and SQLite this
+/// renders `JSON_EXTRACT(column, '$.address.city') = ?`; on Postgres it renders `column->'address'->>'city'`,
+/// which extracts the element as text. A malformed path matches no rows.
+///
+/// # Arguments
+///
+/// * `column` - The JSON column to filter on.
+/// * `path` - The path of the element to compare, starting with `$`.
+/// * `value` - The value to compare against. Can be any type that converts into [`Value`].
+///
+/// # Returns
+///
+/// An object implementing [`Filtered`] that represents the JSON path comparison.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::json_eq;
+/// use lume::define_schema;
+/// use lume::schema::ColumnInfo;
+/// use lume::schema::Schema;
+///
+/// define_schema! {
+///     Order {
+///         id: i32 [primary_key()],
+///         metadata: String,
+///     }
+/// }
+///
+/// let filter = json_eq(Order::metadata(), "$.status", "shipped");
+/// ```
+pub fn json_eq<T: Debug, V: Into<Value>>(
+    column: &Column<T>,
+    path: &str,
+    value: V,
+) -> JsonPathFilter {
+    JsonPathFilter {
+        column: (
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+        ),
+        path: path.to_string(),
+        value: value.into(),
     }
 }
 
@@ -898,7 +1128,7 @@ pub fn ilike<T: Debug, P: Into<String>>(
 /// let filter = between(User::age(), 18, 30);
 /// ```
 pub fn between<T: Debug, V: Into<Value>>(
-    column: &'static Column<T>,
+    column: &Column<T>,
     min: V,
     max: V,
 ) -> impl Filtered + 'static {
@@ -910,5 +1140,158 @@ pub fn between<T: Debug, V: Into<Value>>(
         value: Some(Value::Between(Box::new(min.into()), Box::new(max.into()))),
         column_two: None,
         filter_type: FilterType::Between,
+        enum_type: column.native_enum_type(),
+    }
+}
+
+/// Creates a filter matching values between `low` and `high`, with `bounds`
+/// choosing whether each end is included.
+///
+/// Unlike [`between`], which always includes both ends, this renders two
+/// comparisons, e.g. `col >= ? AND col < ?` for [`Bounds::InclusiveExclusive`].
+/// Half-open ranges let consecutive time windows share a boundary without a
+/// row matching both.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::{Bounds, range};
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+///
+/// define_schema! {
+///     Event {
+///         id: i32 [primary_key()],
+///         created_at: i64 [not_null()],
+///     }
+/// }
+///
+/// // One hour, excluding the first second of the next
+/// let filter = range(Event::created_at(), 1_700_000_000, 1_700_003_600, Bounds::InclusiveExclusive);
+/// ```
+pub fn range<T, V: Into<Value>>(column: &Column<T>, low: V, high: V, bounds: Bounds) -> AndFilter {
+    let (low, high) = (low.into(), high.into());
+    match bounds {
+        Bounds::Inclusive => and(gte(column, low), lte(column, high)),
+        Bounds::InclusiveExclusive => and(gte(column, low), lt(column, high)),
+        Bounds::ExclusiveInclusive => and(gt(column, low), lte(column, high)),
+        Bounds::Exclusive => and(gt(column, low), lt(column, high)),
+    }
+}
+
+/// Creates a filter matching timestamps on the given UTC calendar day.
+///
+/// Expands to the half-open range `column >= <day start> AND column < <next day start>`,
+/// which avoids the off-by-one mistakes of comparing timestamps for equality or
+/// using an inclusive `BETWEEN`. The column is expected to hold Unix timestamps in
+/// seconds (e.g. an `i64` field), and both bounds are bound as parameters.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::on_date;
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+/// use time::macros::date;
+///
+/// define_schema! {
+///     Event {
+///         id: i32 [primary_key()],
+///         created_at: i64 [not_null()],
+///     }
+/// }
+///
+/// let filter = on_date(Event::created_at(), date!(2025 - 01 - 31));
+/// ```
+pub fn on_date<T>(column: &Column<T>, date: time::Date) -> AndFilter {
+    let start = date.midnight().assume_utc().unix_timestamp();
+    and(gte(column, start), lt(column, start + 86_400))
+}
+
+/// Creates a filter matching timestamps strictly before `moment`.
+///
+/// Like [`on_date`], the column is expected to hold Unix timestamps in seconds.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::before;
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+/// use time::macros::datetime;
+///
+/// define_schema! {
+///     Event {
+///         id: i32 [primary_key()],
+///         created_at: i64 [not_null()],
+///     }
+/// }
+///
+/// let filter = before(Event::created_at(), datetime!(2025-01-31 12:00 UTC));
+/// ```
+pub fn before<T>(column: &Column<T>, moment: time::OffsetDateTime) -> Filter {
+    lt(column, moment.unix_timestamp())
+}
+
+/// Creates a filter matching timestamps strictly after `moment`.
+///
+/// Like [`on_date`], the column is expected to hold Unix timestamps in seconds.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::after;
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+/// use time::macros::datetime;
+///
+/// define_schema! {
+///     Event {
+///         id: i32 [primary_key()],
+///         created_at: i64 [not_null()],
+///     }
+/// }
+///
+/// let filter = after(Event::created_at(), datetime!(2025-01-31 12:00 UTC));
+/// ```
+pub fn after<T>(column: &Column<T>, moment: time::OffsetDateTime) -> Filter {
+    gt(column, moment.unix_timestamp())
+}
+
+/// Reads a part of a date column, such as its year, to compare in a filter.
+///
+/// Renders `EXTRACT(YEAR FROM column)` on Postgres, `YEAR(column)` on MySQL
+/// and `CAST(strftime('%Y', column) AS INTEGER)` on SQLite, so the part
+/// compares as a number everywhere. On SQLite the column must hold a date
+/// string `strftime` understands, such as `2024-03-01 12:00:00`.
+///
+/// # Example
+///
+/// ```
+/// use lume::filter::{extract, DatePart};
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+///
+/// define_schema! {
+///     Event {
+///         id: i32 [primary_key()],
+///         created_at: time::OffsetDateTime [not_null()],
+///     }
+/// }
+///
+/// let filter = extract(Event::created_at(), DatePart::Year).eq_value(2024);
+/// ```
+pub fn extract<T>(column: &Column<T>, part: DatePart) -> Extract {
+    Extract {
+        column: (
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+        ),
+        part,
     }
 }
@@ -7,6 +7,7 @@
 
 use std::fmt::Debug;
 
+use crate::dialects::get_dialect;
 use crate::schema::Value;
 
 mod filters;
@@ -25,6 +26,7 @@ pub use filters::*;
 /// - `Gte`: Greater than or equal (>=)
 /// - `Lt`: Less than (<)
 /// - `Lte`: Less than or equal (<=)
+/// - `NullSafeEq`: Equality that treats two NULLs as equal (<=> / IS NOT DISTINCT FROM)
 /// - `In`: IN clause (currently unused)
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum FilterType {
@@ -32,7 +34,9 @@ pub enum FilterType {
     Eq,
     /// Not equal operator (!=)
     Neq,
-    /// IN clause operator (currently unused)
+    /// IN clause operator, used by [`ArrayFilter`] (`in_array` / `not_in_array`).
+    ///
+    /// A filter with this type and a single value renders as `col IN (?)`.
     In,
     /// Greater than operator (>)
     Gt,
@@ -42,6 +46,11 @@ pub enum FilterType {
     Gte,
     /// Less than or equal operator (<=)
     Lte,
+    /// Null-safe equality (`<=>` on MySQL, `IS NOT DISTINCT FROM` elsewhere)
+    NullSafeEq,
+    /// Equality against any element of a bound array (`= ANY($1)`, Postgres only)
+    #[cfg(feature = "postgres")]
+    EqAny,
     /// OR operator (logical OR)
     Or,
     /// AND operator (logical AND)
@@ -75,6 +84,9 @@ impl FilterType {
             FilterType::Lt => "<",
             FilterType::Gte => ">=",
             FilterType::Lte => "<=",
+            FilterType::NullSafeEq => get_dialect().null_safe_eq_operator(),
+            #[cfg(feature = "postgres")]
+            FilterType::EqAny => "= ANY",
             FilterType::Or => "OR",
             FilterType::And => "AND",
             FilterType::Like => "LIKE",
@@ -108,9 +120,10 @@ impl FilterType {
 ///     filter_type: FilterType::Gt,
 ///     value: Some(Value::Int8(18)),
 ///     column_two: None,
+///     enum_type: None,
 /// };
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Filter {
     /// The name of the column to filter on
     pub column_one: (String, String),
@@ -120,13 +133,18 @@ pub struct Filter {
     pub column_two: Option<(String, String)>,
     /// The type of comparison to perform
     pub filter_type: FilterType,
+    /// The `native_enum()` type the value is cast to on Postgres, which
+    /// doesn't compare an enum column with a `TEXT` parameter
+    pub enum_type: Option<&'static str>,
 }
 
 /// Wrapper for embedding raw SQL into filters.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SqlFilter {
     /// Raw SQL snippet to embed directly
     pub sql: String,
+    /// Values bound to the snippet's `?` placeholders, in order
+    pub params: Vec<Value>,
 }
 
 /// Represents 'OR'  filter condition for query WHERE clauses.
@@ -160,7 +178,7 @@ pub struct SqlFilter {
 ///     lte(User::age(), 30)
 /// );
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct OrFilter {
     pub(crate) filter1: Box<dyn Filtered>,
     pub(crate) filter2: Box<dyn Filtered>,
@@ -197,7 +215,7 @@ pub struct OrFilter {
 ///     lt(User::age(), 30)
 /// );
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AndFilter {
     pub(crate) filter1: Box<dyn Filtered>,
     pub(crate) filter2: Box<dyn Filtered>,
@@ -230,7 +248,7 @@ pub struct AndFilter {
 /// let filter = not(eq_value(User::name(), "Alice"));
 /// // This will generate a SQL condition like: NOT (users.name = 'Alice')
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NotFilter {
     /// The filter condition to be negated.
     pub(crate) filter: Box<dyn Filtered>,
@@ -247,7 +265,7 @@ pub struct NotFilter {
 /// - `values`: A static slice of `Value` items to compare against the column.
 /// - `in_array`: If `true`, generates an `IN` filter; if `false`, generates a `NOT IN` filter.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ArrayFilter {
     /// The column to filter on, as (table, column) or None.
     pub(crate) column1: Option<(String, String)>,
@@ -257,6 +275,9 @@ pub struct ArrayFilter {
 
     /// Whether this is an `IN` (true) or `NOT IN` (false) filter.
     pub(crate) in_array: bool,
+
+    /// The `native_enum()` type each value is cast to on Postgres.
+    pub(crate) enum_type: Option<&'static str>,
 }
 
 /// Trait for all filter types used in query building.
@@ -332,6 +353,12 @@ pub trait Filtered: Debug + Send + Sync {
         None
     }
 
+    /// Returns the `native_enum()` type the filter's values are cast to, if
+    /// the column is a native enum.
+    fn enum_type(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Returns `Some(true)` if this filter is a logical NOT combinator, `None` otherwise.
     ///
     /// For filters that represent a logical NOT (negation), this should return `Some(true)`.
@@ -344,6 +371,35 @@ pub trait Filtered: Debug + Send + Sync {
     fn is_sql(&self) -> Option<&String> {
         None
     }
+
+    /// Returns the values bound to a raw SQL fragment's `?` placeholders, if any.
+    fn sql_params(&self) -> Option<&Vec<Value>> {
+        None
+    }
+
+    /// Returns the JSON path when this filter compares an element inside a JSON column.
+    fn json_path(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the date part when this filter compares a part of a date column.
+    fn date_part(&self) -> Option<DatePart> {
+        None
+    }
+
+    /// Returns `true` if this filter wraps another filter in parentheses.
+    fn is_group(&self) -> bool {
+        false
+    }
+
+    /// Creates a boxed clone of this filter.
+    fn clone_box(&self) -> Box<dyn Filtered>;
+}
+
+impl Clone for Box<dyn Filtered> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
 }
 
 impl Filtered for Filter {
@@ -366,6 +422,14 @@ impl Filtered for Filter {
     fn filter1(&self) -> Option<&dyn Filtered> {
         None
     }
+
+    fn enum_type(&self) -> Option<&'static str> {
+        self.enum_type
+    }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
 }
 
 impl Filtered for SqlFilter {
@@ -416,6 +480,14 @@ impl Filtered for SqlFilter {
     fn is_sql(&self) -> Option<&String> {
         Some(&self.sql)
     }
+
+    fn sql_params(&self) -> Option<&Vec<Value>> {
+        Some(&self.params)
+    }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
 }
 
 impl Filtered for OrFilter {
@@ -438,6 +510,10 @@ impl Filtered for OrFilter {
     fn is_or_filter(&self) -> bool {
         true
     }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
 }
 
 impl Filtered for AndFilter {
@@ -460,6 +536,184 @@ impl Filtered for AndFilter {
     fn is_and_filter(&self) -> bool {
         true
     }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
+}
+
+/// Compares a value extracted from a JSON column at a path, created by
+/// [`json_eq`](crate::filter::json_eq).
+#[derive(Debug, Clone)]
+pub struct JsonPathFilter {
+    /// The JSON column, as (table, column).
+    pub(crate) column: (String, String),
+    /// The JSON path, e.g. `$.address.city`.
+    pub(crate) path: String,
+    /// The value the extracted element must equal.
+    pub(crate) value: Value,
+}
+
+impl Filtered for JsonPathFilter {
+    fn value(&self) -> Option<&Value> {
+        Some(&self.value)
+    }
+
+    fn column_one(&self) -> Option<&(String, String)> {
+        Some(&self.column)
+    }
+
+    fn filter_type(&self) -> FilterType {
+        FilterType::Eq
+    }
+
+    fn filter1(&self) -> Option<&dyn Filtered> {
+        None
+    }
+
+    fn json_path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
+}
+
+/// Which ends of a [`range`](crate::filter::range) are included, in interval notation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Bounds {
+    /// `[low, high]`: `col >= low AND col <= high`
+    Inclusive,
+    /// `[low, high)`: `col >= low AND col < high`, for back-to-back time windows
+    InclusiveExclusive,
+    /// `(low, high]`: `col > low AND col <= high`
+    ExclusiveInclusive,
+    /// `(low, high)`: `col > low AND col < high`
+    Exclusive,
+}
+
+/// A part of a date or timestamp, read with [`extract`](crate::filter::extract).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DatePart {
+    /// The year, e.g. `2024`
+    Year,
+    /// The month, from 1 to 12
+    Month,
+    /// The day of the month, from 1 to 31
+    Day,
+    /// The hour, from 0 to 23
+    Hour,
+    /// The minute, from 0 to 59
+    Minute,
+    /// The second, from 0 to 59
+    Second,
+}
+
+/// Writes the part's SQL name, as used by `EXTRACT` and MySQL's date functions.
+impl std::fmt::Display for DatePart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DatePart::Year => "YEAR",
+            DatePart::Month => "MONTH",
+            DatePart::Day => "DAY",
+            DatePart::Hour => "HOUR",
+            DatePart::Minute => "MINUTE",
+            DatePart::Second => "SECOND",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A part of a date column, created by [`extract`](crate::filter::extract).
+///
+/// Compare it with [`eq_value`](Self::eq_value), [`gt`](Self::gt) and the
+/// other methods to build a filter.
+#[derive(Debug, Clone)]
+pub struct Extract {
+    pub(crate) column: (String, String),
+    pub(crate) part: DatePart,
+}
+
+impl Extract {
+    fn compare(&self, filter_type: FilterType, value: Value) -> DatePartFilter {
+        DatePartFilter {
+            column: self.column.clone(),
+            part: self.part,
+            filter_type,
+            value,
+        }
+    }
+
+    /// Matches rows where the date part equals `value`.
+    pub fn eq_value<V: Into<Value>>(&self, value: V) -> DatePartFilter {
+        self.compare(FilterType::Eq, value.into())
+    }
+
+    /// Matches rows where the date part doesn't equal `value`.
+    pub fn ne_value<V: Into<Value>>(&self, value: V) -> DatePartFilter {
+        self.compare(FilterType::Neq, value.into())
+    }
+
+    /// Matches rows where the date part is greater than `value`.
+    pub fn gt<V: Into<Value>>(&self, value: V) -> DatePartFilter {
+        self.compare(FilterType::Gt, value.into())
+    }
+
+    /// Matches rows where the date part is greater than or equal to `value`.
+    pub fn gte<V: Into<Value>>(&self, value: V) -> DatePartFilter {
+        self.compare(FilterType::Gte, value.into())
+    }
+
+    /// Matches rows where the date part is less than `value`.
+    pub fn lt<V: Into<Value>>(&self, value: V) -> DatePartFilter {
+        self.compare(FilterType::Lt, value.into())
+    }
+
+    /// Matches rows where the date part is less than or equal to `value`.
+    pub fn lte<V: Into<Value>>(&self, value: V) -> DatePartFilter {
+        self.compare(FilterType::Lte, value.into())
+    }
+}
+
+/// Compares a part of a date column with a value, created by the methods of
+/// [`Extract`].
+#[derive(Debug, Clone)]
+pub struct DatePartFilter {
+    /// The date column, as (table, column).
+    pub(crate) column: (String, String),
+    /// The part of the date to compare.
+    pub(crate) part: DatePart,
+    /// The comparison operator.
+    pub(crate) filter_type: FilterType,
+    /// The value the date part is compared with.
+    pub(crate) value: Value,
+}
+
+impl Filtered for DatePartFilter {
+    fn value(&self) -> Option<&Value> {
+        Some(&self.value)
+    }
+
+    fn column_one(&self) -> Option<&(String, String)> {
+        Some(&self.column)
+    }
+
+    fn filter_type(&self) -> FilterType {
+        self.filter_type
+    }
+
+    fn filter1(&self) -> Option<&dyn Filtered> {
+        None
+    }
+
+    fn date_part(&self) -> Option<DatePart> {
+        Some(self.part)
+    }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
 }
 
 impl Filtered for ArrayFilter {
@@ -486,6 +740,14 @@ impl Filtered for ArrayFilter {
     fn is_in_array(&self) -> Option<bool> {
         Some(self.in_array)
     }
+
+    fn enum_type(&self) -> Option<&'static str> {
+        self.enum_type
+    }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
 }
 
 impl Filtered for NotFilter {
@@ -504,6 +766,42 @@ impl Filtered for NotFilter {
     fn is_not(&self) -> Option<bool> {
         Some(true)
     }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
+}
+
+/// A filter that renders its inner filter inside parentheses.
+///
+/// Built by [`Query::filter_group`](crate::operations::query::Query::filter_group)
+/// so a compound condition keeps its precedence when ANDed with other filters.
+#[derive(Debug, Clone)]
+pub struct GroupFilter {
+    /// The filter condition to be grouped.
+    pub(crate) filter: Box<dyn Filtered>,
+}
+
+impl Filtered for GroupFilter {
+    fn column_one(&self) -> Option<&(String, String)> {
+        None
+    }
+
+    fn filter_type(&self) -> FilterType {
+        FilterType::Eq
+    }
+
+    fn filter1(&self) -> Option<&dyn Filtered> {
+        Some(&*self.filter)
+    }
+
+    fn is_group(&self) -> bool {
+        true
+    }
+
+    fn clone_box(&self) -> Box<dyn Filtered> {
+        Box::new(self.clone())
+    }
 }
 
 impl Default for Filter {
@@ -513,6 +811,7 @@ impl Default for Filter {
             filter_type: FilterType::Eq,
             column_one: ("".to_string(), "".to_string()),
             column_two: None,
+            enum_type: None,
         }
     }
 }
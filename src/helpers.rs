@@ -1,7 +1,8 @@
 use crate::{
+    database::error::DatabaseError,
     dialects::get_dialect,
     filter::Filtered,
-    schema::{ColumnInfo, Value},
+    schema::{ColumnInfo, ValidationError, Value},
 };
 use std::sync::LazyLock;
 
@@ -23,7 +24,7 @@ pub(crate) enum StartingSql {
 }
 
 pub(crate) fn get_starting_sql(starting_sql: StartingSql, table_name: &str) -> String {
-    let table_ident = get_dialect().quote_identifier(table_name);
+    let table_ident = get_dialect().quote_qualified(table_name);
     match starting_sql {
         StartingSql::Select => "SELECT ".to_string(),
         StartingSql::Insert => format!("INSERT INTO {} (", table_ident),
@@ -32,10 +33,67 @@ pub(crate) fn get_starting_sql(starting_sql: StartingSql, table_name: &str) -> S
     }
 }
 
-pub(crate) fn build_filter_expr(filter: &dyn Filtered, params: &mut Vec<Value>) -> String {
+/// `IN` lists longer than this are bound as a single array on dialects that
+/// support it, instead of one placeholder per value.
+pub(crate) const LARGE_IN_LIST: usize = 1_000;
+
+/// The most bind parameters put in one statement, such as a multi-row
+/// `INSERT` or an `IN` list, below SQLite's default limit of 32766 (MySQL and
+/// Postgres allow 65535).
+pub(crate) const MAX_BIND_PARAMS: usize = 32_766;
+
+/// Returns true if `values` can be bound as one array: all of the same type,
+/// and of a type arrays can hold.
+fn binds_as_array(values: &[Value]) -> bool {
+    let Some(first) = values.first() else {
+        return false;
+    };
+    if matches!(
+        first,
+        Value::Int128(_) | Value::UInt128(_) | Value::Array(_) | Value::Between(_, _)
+    ) {
+        return false;
+    }
+
+    let kind = std::mem::discriminant(first);
+    values.iter().all(|v| std::mem::discriminant(v) == kind)
+}
+
+/// Casts a filter's placeholder to its column's `native_enum()` type, if any.
+/// With `array`, the placeholder binds a whole array of values.
+fn enum_param(filter: &dyn Filtered, placeholder: String, array: bool) -> String {
+    match filter.enum_type() {
+        Some(type_name) if array => {
+            get_dialect().enum_param_sql(placeholder, &format!("{}[]", type_name))
+        }
+        Some(type_name) => get_dialect().enum_param_sql(placeholder, type_name),
+        None => placeholder,
+    }
+}
+
+pub(crate) fn build_filter_expr(
+    filter: &dyn Filtered,
+    params: &mut Vec<Value>,
+) -> Result<String, DatabaseError> {
     if filter.is_sql().is_some() {
         let sql = filter.is_sql().unwrap();
-        return format!("{}", sql);
+        return Ok(match filter.sql_params() {
+            Some(values) if !values.is_empty() => bind_raw_sql(sql, values, params)?,
+            _ => format!("{}", sql),
+        });
+    }
+
+    // Handle explicit groups; AND/OR already render their own parentheses
+    if filter.is_group() {
+        let Some(f) = filter.filter1() else {
+            eprintln!("Warning: Group filter missing filter1, using tautology");
+            return Ok("1=1".to_string());
+        };
+        let expr = build_filter_expr(f, params)?;
+        if f.is_or_filter() || f.is_and_filter() || f.is_group() {
+            return Ok(expr);
+        }
+        return Ok(format!("({})", expr));
     }
 
     // Handle logical combinators (AND/OR)
@@ -43,62 +101,104 @@ pub(crate) fn build_filter_expr(filter: &dyn Filtered, params: &mut Vec<Value>)
         let op = if filter.is_or_filter() { "OR" } else { "AND" };
         let Some(f1) = filter.filter1() else {
             eprintln!("Warning: Composite filter missing filter1, using tautology");
-            return "1=1".to_string();
+            return Ok("1=1".to_string());
         };
         let Some(f2) = filter.filter2() else {
             eprintln!("Warning: Composite filter missing filter2, using tautology");
-            return "1=1".to_string();
+            return Ok("1=1".to_string());
         };
-        let left = build_filter_expr(f1, params);
-        let right = build_filter_expr(f2, params);
-        return format!("({} {} {})", left, op, right);
+        let left = build_filter_expr(f1, params)?;
+        let right = build_filter_expr(f2, params)?;
+        return Ok(format!("({} {} {})", left, op, right));
     }
 
     // Handle NOT
     if filter.is_not().unwrap_or(false) {
         let Some(f) = filter.filter1() else {
             eprintln!("Warning: Not filter missing filter1, using tautology");
-            return "1=1".to_string();
+            return Ok("1=1".to_string());
         };
-        return format!("NOT ({})", build_filter_expr(f, params));
+        return Ok(format!("NOT ({})", build_filter_expr(f, params)?));
     }
 
     // Handle actual column filters
     let Some(col1) = filter.column_one() else {
         eprintln!("Warning: Simple filter missing column_one, using tautology");
-        return "1=1".to_string();
+        return Ok("1=1".to_string());
     };
 
     // Handle IN / NOT IN array filters (only when explicitly marked as such)
     if let Some(in_array) = filter.is_in_array() {
         if let Some(values) = filter.array_values() {
             if values.is_empty() {
-                return if in_array {
+                return Ok(if in_array {
                     "1=0".to_string()
                 } else {
                     "1=1".to_string()
-                };
+                });
             }
 
-            let start_idx = params.len();
-            let mut placeholders: Vec<String> = Vec::with_capacity(values.len());
+            let dialect = get_dialect();
+            let column = format!(
+                "{}.{}",
+                dialect.quote_qualified(&col1.0),
+                dialect.quote_identifier(&col1.1)
+            );
 
-            for (i, v) in values.iter().cloned().enumerate() {
-                params.push(v);
-                placeholders.push(get_dialect().placeholder(start_idx + i));
+            // `x IN (NULL)` is never true, so a NULL in the list is matched
+            // with IS NULL instead, and NOT IN excludes NULL rows explicitly
+            // so the filter also negates correctly inside `not`
+            let has_null = values.iter().any(|v| matches!(v, Value::Null));
+            let values: Vec<Value> = values
+                .iter()
+                .filter(|v| !matches!(v, Value::Null))
+                .cloned()
+                .collect();
+
+            if values.is_empty() {
+                let null_check = if in_array { "IS NULL" } else { "IS NOT NULL" };
+                return Ok(format!("{} {}", column, null_check));
             }
 
-            let dialect = get_dialect();
-            let tbl = dialect.quote_identifier(&col1.0);
-            let col = dialect.quote_identifier(&col1.1);
-            let op = if in_array { "IN" } else { "NOT IN" };
+            // Long lists are bound as one array where the dialect allows it,
+            // so they can't run past the placeholder limit
+            let array_expr = if values.len() > LARGE_IN_LIST && binds_as_array(&values) {
+                let placeholder = enum_param(filter, dialect.placeholder(params.len()), true);
+                dialect.in_array_param_sql(&column, in_array, &placeholder)
+            } else {
+                None
+            };
+
+            let expr = if let Some(expr) = array_expr {
+                params.push(Value::Array(values));
+                expr
+            } else {
+                let start_idx = params.len();
+                let mut placeholders: Vec<String> = Vec::with_capacity(values.len());
+
+                for (i, v) in values.into_iter().enumerate() {
+                    params.push(v);
+                    placeholders.push(enum_param(
+                        filter,
+                        dialect.placeholder(start_idx + i),
+                        false,
+                    ));
+                }
 
-            return format!("{}.{} {} ({})", tbl, col, op, placeholders.join(", "));
+                let op = if in_array { "IN" } else { "NOT IN" };
+                format!("{} {} ({})", column, op, placeholders.join(", "))
+            };
+
+            return Ok(match (has_null, in_array) {
+                (false, _) => expr,
+                (true, true) => format!("({} OR {} IS NULL)", expr, column),
+                (true, false) => format!("({} AND {} IS NOT NULL)", expr, column),
+            });
         } else if let Some(col2) = filter.column_two() {
             let dialect = get_dialect();
             let left = format!(
                 "{}.{}",
-                dialect.quote_identifier(&col1.0),
+                dialect.quote_qualified(&col1.0),
                 dialect.quote_identifier(&col1.1)
             );
 
@@ -107,46 +207,119 @@ pub(crate) fn build_filter_expr(filter: &dyn Filtered, params: &mut Vec<Value>)
                 eprintln!(
                     "Warning: IN/NOT IN filter column_two missing table name, using tautology"
                 );
-                return if in_array {
+                return Ok(if in_array {
                     "1=0".to_string()
                 } else {
                     "1=1".to_string()
-                };
+                });
             }
 
             // Generate proper subquery: (SELECT <quoted_col2> FROM <quoted_table2>)
-            let quoted_table2 = dialect.quote_identifier(&col2.0);
+            let quoted_table2 = dialect.quote_qualified(&col2.0);
             let quoted_col2 = dialect.quote_identifier(&col2.1);
             let subquery = format!("(SELECT {} FROM {})", quoted_col2, quoted_table2);
             let op = if in_array { "IN" } else { "NOT IN" };
 
-            return format!("{} {} {}", left, op, subquery);
+            return Ok(format!("{} {} {}", left, op, subquery));
         } else {
             eprintln!(
                 "Warning: IN/NOT IN filter missing array_values and column_two, using tautology"
             );
-            return if in_array {
+            return Ok(if in_array {
                 "1=0".to_string()
             } else {
                 "1=1".to_string()
-            };
+            });
         }
     }
 
+    // Handle comparisons of an element inside a JSON column
+    if let Some(path) = filter.json_path() {
+        let dialect = get_dialect();
+        let column = format!(
+            "{}.{}",
+            dialect.quote_qualified(&col1.0),
+            dialect.quote_identifier(&col1.1)
+        );
+        let placeholder = dialect.placeholder(params.len());
+
+        return Ok(
+            match (
+                filter.value(),
+                dialect.json_path_eq_sql(&column, path, &placeholder),
+            ) {
+                (Some(value), Some(sql)) => {
+                    params.push(value.clone());
+                    sql
+                }
+                _ => {
+                    eprintln!("Warning: Invalid JSON path {:?}, matching nothing", path);
+                    "1=0".to_string()
+                }
+            },
+        );
+    }
+
+    // Handle comparisons of a part of a date column
+    if let Some(part) = filter.date_part() {
+        let dialect = get_dialect();
+        let column = format!(
+            "{}.{}",
+            dialect.quote_qualified(&col1.0),
+            dialect.quote_identifier(&col1.1)
+        );
+        let Some(value) = filter.value() else {
+            eprintln!("Warning: Date part filter missing value, matching nothing");
+            return Ok("1=0".to_string());
+        };
+        let placeholder = dialect.placeholder(params.len());
+        params.push(value.clone());
+
+        return Ok(format!(
+            "{} {} {}",
+            dialect.date_part_sql(&column, part),
+            filter.filter_type().to_sql(),
+            placeholder
+        ));
+    }
+
     // Handle value-based filters
     if let Some(value) = filter.value() {
         match value {
             Value::Null => {
                 let op = filter.filter_type();
                 let null_sql = match op {
-                    crate::filter::FilterType::Eq => "IS NULL",
+                    crate::filter::FilterType::Eq | crate::filter::FilterType::NullSafeEq => {
+                        "IS NULL"
+                    }
                     crate::filter::FilterType::Neq => "IS NOT NULL",
                     _ => {
                         // Unsupported operator with NULL; force false to avoid surprising results
-                        return "1=0".to_string();
+                        return Ok("1=0".to_string());
                     }
                 };
-                return format!("{}.{} {}", col1.0, col1.1, null_sql);
+                let dialect = get_dialect();
+                return Ok(format!(
+                    "{}.{} {}",
+                    dialect.quote_qualified(&col1.0),
+                    dialect.quote_identifier(&col1.1),
+                    null_sql
+                ));
+            }
+            #[cfg(feature = "postgres")]
+            Value::Array(values) if filter.filter_type() == crate::filter::FilterType::EqAny => {
+                if values.is_empty() {
+                    return Ok("1=0".to_string());
+                }
+
+                params.push(value.clone());
+                let dialect = get_dialect();
+                return Ok(format!(
+                    "{}.{} = ANY({})",
+                    dialect.quote_qualified(&col1.0),
+                    dialect.quote_identifier(&col1.1),
+                    enum_param(filter, dialect.placeholder(params.len() - 1), true)
+                ));
             }
             Value::Between(min, max) => {
                 params.push((**min).clone());
@@ -154,20 +327,33 @@ pub(crate) fn build_filter_expr(filter: &dyn Filtered, params: &mut Vec<Value>)
 
                 let dialect = get_dialect();
                 let base = params.len() - 2;
-                return format!(
+                return Ok(format!(
                     "{}.{} BETWEEN {} AND {}",
-                    dialect.quote_identifier(&col1.0),
+                    dialect.quote_qualified(&col1.0),
                     dialect.quote_identifier(&col1.1),
-                    dialect.placeholder(base),
-                    dialect.placeholder(base + 1)
-                );
+                    enum_param(filter, dialect.placeholder(base), false),
+                    enum_param(filter, dialect.placeholder(base + 1), false)
+                ));
             }
             _ => {
                 params.push(value.clone());
                 let filter_type = filter.filter_type();
+
+                // A lone `In` with a single value: parenthesize so it never renders as `IN ?`
+                if filter_type == crate::filter::FilterType::In {
+                    let dialect = get_dialect();
+                    return Ok(format!(
+                        "{}.{} IN ({})",
+                        dialect.quote_qualified(&col1.0),
+                        dialect.quote_identifier(&col1.1),
+                        enum_param(filter, dialect.placeholder(params.len() - 1), false)
+                    ));
+                }
+
+                // The fallback ends with the placeholder, so a cast can follow it
                 let sql =
                     get_dialect().build_filter_expr_fallback(col1, &filter_type, params.len());
-                return sql;
+                return Ok(enum_param(filter, sql, false));
             }
         }
     }
@@ -175,18 +361,103 @@ pub(crate) fn build_filter_expr(filter: &dyn Filtered, params: &mut Vec<Value>)
 
     if let Some(col2) = filter.column_two() {
         let dialect = get_dialect();
-        return format!(
+        if filter.filter_type() == crate::filter::FilterType::In {
+            return Ok(format!(
+                "{}.{} IN ({}.{})",
+                dialect.quote_qualified(&col1.0),
+                dialect.quote_identifier(&col1.1),
+                dialect.quote_qualified(&col2.0),
+                dialect.quote_identifier(&col2.1)
+            ));
+        }
+
+        return Ok(format!(
             "{}.{} {} {}.{}",
-            dialect.quote_identifier(&col1.0),
+            dialect.quote_qualified(&col1.0),
             dialect.quote_identifier(&col1.1),
             filter.filter_type().to_sql(),
-            dialect.quote_identifier(&col2.0),
+            dialect.quote_qualified(&col2.0),
             dialect.quote_identifier(&col2.1)
-        );
+        ));
     } else {
         // Fallback
-        "1=1".to_string()
+        Ok("1=1".to_string())
+    }
+}
+
+/// Renders a raw fragment's `?` placeholders with the dialect's placeholders,
+/// pushing `values` onto `params` in order.
+///
+/// Every `?` is treated as a placeholder, including ones inside string literals.
+/// Fails with [`DatabaseError::InvalidValue`] if the number of placeholders
+/// differs from the number of values.
+pub(crate) fn bind_raw_sql(
+    sql: &str,
+    values: &[Value],
+    params: &mut Vec<Value>,
+) -> Result<String, DatabaseError> {
+    let placeholders = sql.chars().filter(|&ch| ch == '?').count();
+    if placeholders != values.len() {
+        return Err(DatabaseError::InvalidValue(format!(
+            "Raw SQL `{}` has {} `?` placeholders but {} parameters",
+            sql,
+            placeholders,
+            values.len()
+        )));
+    }
+
+    let dialect = get_dialect();
+    let mut values = values.iter();
+    let mut out = String::with_capacity(sql.len());
+
+    for ch in sql.chars() {
+        if ch == '?'
+            && let Some(value) = values.next()
+        {
+            out.push_str(&dialect.placeholder(params.len()));
+            params.push(value.clone());
+            continue;
+        }
+        out.push(ch);
     }
+
+    Ok(out)
+}
+
+/// Splits a script into its trimmed, non-empty statements.
+///
+/// Semicolons inside single-quoted literals and `$$`-quoted bodies, as used by
+/// Postgres `DO` blocks, don't end a statement.
+pub(crate) fn split_statements(sql: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut in_literal = false;
+    let mut in_dollar_quote = false;
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' if !in_dollar_quote => in_literal = !in_literal,
+            b'$' if !in_literal && bytes.get(i + 1) == Some(&b'$') => {
+                in_dollar_quote = !in_dollar_quote;
+                i += 1;
+            }
+            b';' if !in_literal && !in_dollar_quote => {
+                statements.push(&sql[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    statements.push(&sql[start..]);
+
+    statements
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
 }
 
 #[cfg(feature = "mysql")]
@@ -242,176 +513,77 @@ pub(crate) fn bind_column_value<'q>(
     query: SqlBindQuery<'q>,
     column: &ColumnInfo,
     value: Option<&Value>,
-) -> SqlBindQuery<'q> {
+) -> Result<SqlBindQuery<'q>, DatabaseError> {
     let kind = ColumnBindingKind::from_column(column);
     match value {
-        None => get_dialect().bind_null(query, kind),
-        Some(Value::Null) => get_dialect().bind_null(query, kind),
-        Some(Value::Array(_)) => get_dialect().bind_null(query, kind),
+        // Empty and NULL arrays carry no element to take the array type from
+        #[cfg(feature = "postgres")]
+        None | Some(Value::Null) if column.data_type.ends_with("[]") => {
+            Ok(bind_empty_array(query, column.data_type, true))
+        }
+        #[cfg(feature = "postgres")]
+        Some(Value::Array(values)) if values.is_empty() => {
+            Ok(bind_empty_array(query, column.data_type, false))
+        }
+        None => Ok(get_dialect().bind_null(query, kind)),
+        Some(Value::Null) => Ok(get_dialect().bind_null(query, kind)),
         Some(other) => bind_value(query, other.clone()),
     }
 }
 
-pub(crate) fn validate_column_value(column: &ColumnInfo, value: Option<&Value>) -> bool {
+pub(crate) fn validate_column_value(
+    column: &ColumnInfo,
+    value: Option<&Value>,
+) -> Result<(), ValidationError> {
     use crate::schema::ColumnValidators;
 
-    match value {
-        Some(Value::String(s)) => {
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Email => {
-                        if !EMAIL_REGEX.is_match(s) {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Url => {
-                        if !LINK_REGEX.is_match(s) {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::MinLen(min) => {
-                        if s.len() < min {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::MaxLen(max) => {
-                        if s.len() > max {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Min(min) => {
-                        // For backward compatibility, treat as MinLen for string
-                        if s.len() < min {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        // For backward compatibility, treat as MaxLen for string
-                        if s.len() > max {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Pattern(pattern) => {
-                        let regex = Regex::new(pattern).unwrap();
-                        if !regex.is_match(s) {
-                            return false;
-                        }
-                    }
-                }
-            }
-            true
-        }
-        Some(Value::Int32(i)) => {
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Min(min) => {
-                        if *i < min as i32 {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        if *i > max as i32 {
-                            return false;
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            true
-        }
-        Some(Value::Int64(i)) => {
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Min(min) => {
-                        if *i < min as i64 {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        if *i > max as i64 {
-                            return false;
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            true
-        }
-        Some(Value::UInt32(u)) => {
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Min(min) => {
-                        if *u < min as u32 {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        if *u > max as u32 {
-                            return false;
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            true
-        }
-        Some(Value::UInt64(u)) => {
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Min(min) => {
-                        if *u < min as u64 {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        if *u > max as u64 {
-                            return false;
-                        }
-                    }
-                    _ => {}
-                }
+    let Some(value) = value else {
+        return Ok(());
+    };
+
+    for validator in column.validators {
+        let is_valid = match (value, *validator) {
+            (Value::String(s), ColumnValidators::Email) => EMAIL_REGEX.is_match(s),
+            (Value::String(s), ColumnValidators::Url) => LINK_REGEX.is_match(s),
+            // For backward compatibility, Min/Max are treated as MinLen/MaxLen for strings
+            (Value::String(s), ColumnValidators::MinLen(min) | ColumnValidators::Min(min)) => {
+                s.len() >= min
             }
-            true
-        }
-        Some(Value::Float32(f)) => {
-            let f = *f as f64;
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Min(min) => {
-                        if f < min as f64 {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        if f > max as f64 {
-                            return false;
-                        }
-                    }
-                    _ => {}
-                }
+            (Value::String(s), ColumnValidators::MaxLen(max) | ColumnValidators::Max(max)) => {
+                s.len() <= max
             }
-            true
-        }
-        Some(Value::Float64(f)) => {
-            for validator in column.validators {
-                match *validator {
-                    ColumnValidators::Min(min) => {
-                        if *f < min as f64 {
-                            return false;
-                        }
-                    }
-                    ColumnValidators::Max(max) => {
-                        if *f > max as f64 {
-                            return false;
-                        }
-                    }
-                    _ => {}
-                }
+            (Value::String(s), ColumnValidators::Pattern(pattern)) => {
+                Regex::new(pattern).unwrap().is_match(s)
             }
-            true
+            (Value::Int32(i), ColumnValidators::Min(min)) => *i >= min as i32,
+            (Value::Int32(i), ColumnValidators::Max(max)) => *i <= max as i32,
+            (Value::Int64(i), ColumnValidators::Min(min)) => *i >= min as i64,
+            (Value::Int64(i), ColumnValidators::Max(max)) => *i <= max as i64,
+            (Value::UInt32(u), ColumnValidators::Min(min)) => *u >= min as u32,
+            (Value::UInt32(u), ColumnValidators::Max(max)) => *u <= max as u32,
+            (Value::UInt64(u), ColumnValidators::Min(min)) => *u >= min as u64,
+            (Value::UInt64(u), ColumnValidators::Max(max)) => *u <= max as u64,
+            (Value::Int128(i), ColumnValidators::Min(min)) => *i >= min as i128,
+            (Value::Int128(i), ColumnValidators::Max(max)) => *i <= max as i128,
+            (Value::UInt128(u), ColumnValidators::Min(min)) => *u >= min as u128,
+            (Value::UInt128(u), ColumnValidators::Max(max)) => *u <= max as u128,
+            (Value::Float32(f), ColumnValidators::Min(min)) => *f as f64 >= min as f64,
+            (Value::Float32(f), ColumnValidators::Max(max)) => *f as f64 <= max as f64,
+            (Value::Float64(f), ColumnValidators::Min(min)) => *f >= min as f64,
+            (Value::Float64(f), ColumnValidators::Max(max)) => *f <= max as f64,
+            _ => true,
+        };
+
+        if !is_valid {
+            return Err(ValidationError {
+                column: column.name,
+                rule: *validator,
+                value: value.clone(),
+            });
         }
-        _ => true,
     }
+
+    Ok(())
 }
 
 static EMAIL_REGEX: LazyLock<Regex> =
@@ -421,8 +593,16 @@ static LINK_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^https?:\/\/[^\s/$.?#].[^\s]*$").unwrap());
 
 /// Binds a generic [`Value`] into the provided SQLx query, handling backend differences.
-pub(crate) fn bind_value<'q>(query: SqlBindQuery<'q>, value: Value) -> SqlBindQuery<'q> {
-    match value {
+///
+/// Fails with [`DatabaseError::ValueOutOfRange`] if a `UInt64` is above
+/// `i64::MAX` on Postgres or SQLite, where it's bound as a signed `BIGINT`,
+/// and with [`DatabaseError::InvalidValue`] for a `Value::Array` Postgres
+/// can't bind as one array.
+pub(crate) fn bind_value<'q>(
+    query: SqlBindQuery<'q>,
+    value: Value,
+) -> Result<SqlBindQuery<'q>, DatabaseError> {
+    let query = match value {
         Value::String(s) => query.bind(s),
         Value::Int8(i) => query.bind(i),
         Value::Int16(i) => query.bind(i),
@@ -437,14 +617,6 @@ pub(crate) fn bind_value<'q>(query: SqlBindQuery<'q>, value: Value) -> SqlBindQu
         Value::Uuid(uuid) => query.bind(uuid.to_string()),
         #[cfg(feature = "postgres")]
         Value::UInt32(u) => query.bind(u as i64),
-        #[cfg(feature = "postgres")]
-        Value::UInt64(u) => {
-            debug_assert!(
-                u <= i64::MAX as u64,
-                "UInt64 value exceeds i64::MAX, data loss will occur"
-            );
-            query.bind(u as i64)
-        }
 
         #[cfg(any(feature = "mysql", feature = "sqlite"))]
         Value::UInt16(u) => query.bind(u),
@@ -452,19 +624,559 @@ pub(crate) fn bind_value<'q>(query: SqlBindQuery<'q>, value: Value) -> SqlBindQu
         Value::UInt32(u) => query.bind(u),
         #[cfg(feature = "mysql")]
         Value::UInt64(u) => query.bind(u),
-        #[cfg(feature = "sqlite")]
-        Value::UInt64(u) => query.bind(u as i64),
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        Value::UInt64(u) => query.bind(u64_to_i64(u)?),
+        #[cfg(feature = "postgres")]
+        Value::Int128(i) => query.bind(PgNumeric::from(i)),
+        #[cfg(feature = "postgres")]
+        Value::UInt128(u) => query.bind(PgNumeric::from(u)),
+        // Neither backend has a 128-bit integer type to bind, so send the digits
+        #[cfg(any(feature = "mysql", feature = "sqlite"))]
+        Value::Int128(i) => query.bind(i.to_string()),
+        #[cfg(any(feature = "mysql", feature = "sqlite"))]
+        Value::UInt128(u) => query.bind(u.to_string()),
         Value::Float32(f) => query.bind(f),
         Value::Float64(f) => query.bind(f),
         Value::Bool(b) => query.bind(b),
+        #[cfg(feature = "postgres")]
+        Value::DateTimeTz(dt) => query.bind(dt.to_offset(time::UtcOffset::UTC)),
+        // DATETIME keeps no offset, so store the UTC wall-clock time
+        #[cfg(any(feature = "mysql", feature = "sqlite"))]
+        Value::DateTimeTz(dt) => query.bind(utc_primitive(dt)),
         Value::Between(min, max) => {
-            let query = bind_value(query, *min);
-            bind_value(query, *max)
-        }
-        Value::Array(_arr) => {
-            eprintln!("Warning: Attempted to bind Value::Array, which is not supported. Skipping.");
-            query
+            let query = bind_value(query, *min)?;
+            bind_value(query, *max)?
         }
+        #[cfg(feature = "postgres")]
+        Value::Array(arr) => bind_array(query, arr)?,
+        #[cfg(any(feature = "mysql", feature = "sqlite"))]
+        Value::Array(arr) => query.bind(array_to_json(&arr)),
         Value::Null => query,
+    };
+
+    Ok(query)
+}
+
+/// Drops the offset from `dt` after moving it to UTC, for backends whose
+/// `DATETIME` has no time zone.
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+fn utc_primitive(dt: time::OffsetDateTime) -> time::PrimitiveDateTime {
+    let utc = dt.to_offset(time::UtcOffset::UTC);
+    time::PrimitiveDateTime::new(utc.date(), utc.time())
+}
+
+/// Converts a `u64` to the signed `BIGINT` Postgres and SQLite store it as,
+/// rather than letting values above `i64::MAX` wrap negative.
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+fn u64_to_i64(value: u64) -> Result<i64, DatabaseError> {
+    i64::try_from(value).map_err(|_| {
+        DatabaseError::ValueOutOfRange(format!(
+            "{} exceeds the maximum BIGINT value {}",
+            value,
+            i64::MAX
+        ))
+    })
+}
+
+/// An integer bound to or read from a Postgres `NUMERIC` column, wide enough
+/// for any `i128` or `u128`.
+#[cfg(feature = "postgres")]
+#[derive(Debug, PartialEq)]
+pub(crate) struct PgNumeric {
+    negative: bool,
+    magnitude: u128,
+}
+
+#[cfg(feature = "postgres")]
+impl From<i128> for PgNumeric {
+    fn from(value: i128) -> Self {
+        Self {
+            negative: value < 0,
+            magnitude: value.unsigned_abs(),
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<u128> for PgNumeric {
+    fn from(value: u128) -> Self {
+        Self {
+            negative: false,
+            magnitude: value,
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl PgNumeric {
+    const NUMERIC_OID: sqlx::postgres::types::Oid = sqlx::postgres::types::Oid(1700);
+    const NEGATIVE: u16 = 0x4000;
+    const NAN: u16 = 0xC000;
+
+    /// Converts to an `Int128`, or a `UInt128` for values above `i128::MAX`.
+    /// Returns `None` for negative values below `i128::MIN`.
+    pub(crate) fn into_value(self) -> Option<Value> {
+        if self.negative {
+            0i128
+                .checked_sub_unsigned(self.magnitude)
+                .map(Value::Int128)
+        } else {
+            Some(
+                i128::try_from(self.magnitude)
+                    .map_or(Value::UInt128(self.magnitude), Value::Int128),
+            )
+        }
+    }
+
+    /// Writes the binary `NUMERIC` format: digit count, weight, sign and scale,
+    /// followed by the base-10000 digits, most significant first.
+    pub(crate) fn to_binary(&self) -> Vec<u8> {
+        let mut digits = Vec::new();
+        let mut rest = self.magnitude;
+        while rest > 0 {
+            digits.push((rest % 10_000) as u16);
+            rest /= 10_000;
+        }
+        let weight = (digits.len() as i16 - 1).max(0);
+        digits.reverse();
+        // Trailing zero digits are implied by the weight
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        let sign = if self.negative && self.magnitude > 0 {
+            Self::NEGATIVE
+        } else {
+            0
+        };
+
+        let mut out = Vec::with_capacity(8 + digits.len() * 2);
+        out.extend((digits.len() as i16).to_be_bytes());
+        out.extend(weight.to_be_bytes());
+        out.extend(sign.to_be_bytes());
+        out.extend(0u16.to_be_bytes());
+        for digit in digits {
+            out.extend(digit.to_be_bytes());
+        }
+        out
+    }
+
+    /// Reads the binary `NUMERIC` format, failing on `NaN`, non-zero fractions
+    /// and values too wide for 128 bits.
+    pub(crate) fn from_binary(bytes: &[u8]) -> Result<Self, String> {
+        let read = |index: usize| -> Result<u16, String> {
+            bytes
+                .get(index..index + 2)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]))
+                .ok_or_else(|| "truncated NUMERIC value".to_string())
+        };
+
+        let ndigits = read(0)? as usize;
+        let weight = read(2)? as i16;
+        let sign = read(4)?;
+        if sign == Self::NAN {
+            return Err("NUMERIC NaN can't be read as an integer".to_string());
+        }
+        let digit = |index: usize| -> Result<u16, String> {
+            if index < ndigits {
+                read(8 + index * 2)
+            } else {
+                Ok(0)
+            }
+        };
+
+        // Digits up to the weight form the integer part, the rest a fraction
+        let integer_digits = (weight as i32 + 1).max(0) as usize;
+
+        let mut magnitude: u128 = 0;
+        for index in 0..integer_digits {
+            let digit = digit(index)? as u128;
+            magnitude = magnitude
+                .checked_mul(10_000)
+                .and_then(|m| m.checked_add(digit))
+                .ok_or_else(|| "NUMERIC value doesn't fit in 128 bits".to_string())?;
+        }
+
+        for index in integer_digits..ndigits {
+            if digit(index)? != 0 {
+                return Err("NUMERIC value has a fractional part".to_string());
+            }
+        }
+
+        Ok(Self {
+            negative: sign == Self::NEGATIVE && magnitude > 0,
+            magnitude,
+        })
     }
+
+    /// Parses the text `NUMERIC` format, e.g. `-12345` or `12345.000`.
+    pub(crate) fn from_text(text: &str) -> Result<Self, String> {
+        let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+        if !fraction.bytes().all(|b| b == b'0') {
+            return Err("NUMERIC value has a fractional part".to_string());
+        }
+        let (negative, digits) = match integer.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, integer),
+        };
+        let magnitude = digits
+            .parse::<u128>()
+            .map_err(|_| format!("'{}' isn't a 128-bit integer", text))?;
+
+        Ok(Self {
+            negative: negative && magnitude > 0,
+            magnitude,
+        })
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl sqlx::Type<Postgres> for PgNumeric {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_oid(Self::NUMERIC_OID)
+    }
+
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        ty.oid() == Some(Self::NUMERIC_OID)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl sqlx::Encode<'_, Postgres> for PgNumeric {
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        buf.extend(self.to_binary());
+        Ok(sqlx::encode::IsNull::No)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'r> sqlx::Decode<'r, Postgres> for PgNumeric {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let numeric = match value.format() {
+            sqlx::postgres::PgValueFormat::Binary => Self::from_binary(value.as_bytes()?)?,
+            sqlx::postgres::PgValueFormat::Text => Self::from_text(value.as_str()?)?,
+        };
+        Ok(numeric)
+    }
+}
+
+/// A Postgres `NUMERIC` with a fractional part, such as the result of `AVG`,
+/// read as the nearest `f64`.
+#[cfg(feature = "postgres")]
+#[derive(Debug, PartialEq)]
+pub(crate) struct PgNumericFloat(pub(crate) f64);
+
+#[cfg(feature = "postgres")]
+impl PgNumericFloat {
+    /// Reads the binary `NUMERIC` format; `NaN` reads as `f64::NAN`.
+    pub(crate) fn from_binary(bytes: &[u8]) -> Result<Self, String> {
+        let read = |index: usize| -> Result<u16, String> {
+            bytes
+                .get(index..index + 2)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]))
+                .ok_or_else(|| "truncated NUMERIC value".to_string())
+        };
+
+        let ndigits = read(0)? as usize;
+        let weight = read(2)? as i16 as i32;
+        let sign = read(4)?;
+        if sign == PgNumeric::NAN {
+            return Ok(Self(f64::NAN));
+        }
+
+        let mut value = 0f64;
+        for index in 0..ndigits {
+            let digit = read(8 + index * 2)? as f64;
+            value += digit * 10_000f64.powi(weight - index as i32);
+        }
+
+        if sign == PgNumeric::NEGATIVE {
+            value = -value;
+        }
+        Ok(Self(value))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl sqlx::Type<Postgres> for PgNumericFloat {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        PgNumeric::type_info()
+    }
+
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        PgNumeric::compatible(ty)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'r> sqlx::Decode<'r, Postgres> for PgNumericFloat {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let numeric = match value.format() {
+            sqlx::postgres::PgValueFormat::Binary => Self::from_binary(value.as_bytes()?)?,
+            sqlx::postgres::PgValueFormat::Text => Self(value.as_str()?.parse()?),
+        };
+        Ok(numeric)
+    }
+}
+
+/// Binds a [`Value::Array`] as a single Postgres array parameter.
+///
+/// The element type is taken from the first value, using the same mapping as
+/// [`bind_value`] (e.g. `UInt32` binds as `BIGINT`). Empty arrays, mixed
+/// element types and elements with no array type (128-bit integers, arrays,
+/// ranges, `NULL`s) fail with [`DatabaseError::InvalidValue`], and
+/// out-of-range `UInt64`s fail the bind.
+#[cfg(feature = "postgres")]
+fn bind_array<'q>(
+    query: SqlBindQuery<'q>,
+    values: Vec<Value>,
+) -> Result<SqlBindQuery<'q>, DatabaseError> {
+    fn collect<T>(values: Vec<Value>, convert: impl Fn(Value) -> Option<T>) -> Option<Vec<T>> {
+        values.into_iter().map(convert).collect()
+    }
+
+    macro_rules! bind_as {
+        ($($pattern:pat => $out:expr),+) => {
+            match collect(values, |v| match v {
+                $($pattern => Some($out),)+
+                _ => None,
+            }) {
+                Some(arr) => query.bind(arr),
+                None => {
+                    return Err(DatabaseError::InvalidValue(
+                        "Value::Array elements must all have the same type".to_string(),
+                    ));
+                }
+            }
+        };
+    }
+
+    let Some(first) = values.first() else {
+        return Err(DatabaseError::InvalidValue(
+            "Cannot bind an empty Value::Array".to_string(),
+        ));
+    };
+
+    for value in &values {
+        if let Value::UInt64(u) = value {
+            u64_to_i64(*u)?;
+        }
+    }
+
+    let query = match first {
+        Value::String(_) | Value::Uuid(_) => {
+            bind_as!(Value::String(s) => s, Value::Uuid(uuid) => uuid.to_string())
+        }
+        Value::Int8(_) => bind_as!(Value::Int8(i) => i),
+        Value::Int16(_) => bind_as!(Value::Int16(i) => i),
+        Value::Int32(_) => bind_as!(Value::Int32(i) => i),
+        Value::Int64(_) => bind_as!(Value::Int64(i) => i),
+        Value::UInt16(_) => bind_as!(Value::UInt16(u) => u as i32),
+        Value::UInt32(_) => bind_as!(Value::UInt32(u) => u as i64),
+        Value::UInt64(_) => bind_as!(Value::UInt64(u) => u as i64),
+        Value::Float32(_) => bind_as!(Value::Float32(f) => f),
+        Value::Float64(_) => bind_as!(Value::Float64(f) => f),
+        Value::Bool(_) => bind_as!(Value::Bool(b) => b),
+        Value::DateTimeTz(_) => {
+            bind_as!(Value::DateTimeTz(dt) => dt.to_offset(time::UtcOffset::UTC))
+        }
+        Value::Int128(_)
+        | Value::UInt128(_)
+        | Value::Array(_)
+        | Value::Between(_, _)
+        | Value::Null => {
+            return Err(DatabaseError::InvalidValue(
+                "Value::Array of 128-bit integers, arrays, ranges or NULLs is not supported"
+                    .to_string(),
+            ));
+        }
+    };
+
+    Ok(query)
+}
+
+/// Binds an empty (or, with `null`, a `NULL`) array typed after an array
+/// column's `data_type`, e.g. `INT[]` binds an empty `Vec<i32>`.
+#[cfg(feature = "postgres")]
+fn bind_empty_array<'q>(query: SqlBindQuery<'q>, data_type: &str, null: bool) -> SqlBindQuery<'q> {
+    macro_rules! bind_as {
+        ($t:ty) => {
+            if null {
+                query.bind(None::<Vec<$t>>)
+            } else {
+                query.bind(Vec::<$t>::new())
+            }
+        };
+    }
+
+    match data_type {
+        "BOOLEAN[]" => bind_as!(bool),
+        "SMALLINT[]" => bind_as!(i16),
+        "INT[]" => bind_as!(i32),
+        "BIGINT[]" => bind_as!(i64),
+        "REAL[]" => bind_as!(f32),
+        "DOUBLE PRECISION[]" => bind_as!(f64),
+        _ => bind_as!(String),
+    }
+}
+
+/// Serializes a [`Value::Array`] to the JSON text MySQL and SQLite store
+/// array columns as. Non-finite floats and ranges become `null`.
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+pub(crate) fn array_to_json(values: &[Value]) -> String {
+    fn write_value(out: &mut String, value: &Value) {
+        match value {
+            Value::String(s) => write_json_string(out, s),
+            Value::Uuid(uuid) => write_json_string(out, uuid.as_str()),
+            Value::Int8(i) => out.push_str(&i.to_string()),
+            Value::Int16(i) => out.push_str(&i.to_string()),
+            Value::Int32(i) => out.push_str(&i.to_string()),
+            Value::Int64(i) => out.push_str(&i.to_string()),
+            Value::UInt8(u) => out.push_str(&u.to_string()),
+            Value::UInt16(u) => out.push_str(&u.to_string()),
+            Value::UInt32(u) => out.push_str(&u.to_string()),
+            Value::UInt64(u) => out.push_str(&u.to_string()),
+            Value::Float32(f) if f.is_finite() => out.push_str(&f.to_string()),
+            Value::Float64(f) if f.is_finite() => out.push_str(&f.to_string()),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Array(values) => out.push_str(&array_to_json(values)),
+            _ => out.push_str("null"),
+        }
+    }
+
+    fn write_json_string(out: &mut String, s: &str) {
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    let mut out = String::from("[");
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_value(&mut out, value);
+    }
+    out.push(']');
+    out
+}
+
+/// Parses a JSON array written by [`array_to_json`] back into its values.
+///
+/// Integers become `Int64` and other numbers `Float64`, which the `TryFrom`
+/// conversions narrow to the field's element type. Returns `None` if `json`
+/// isn't a JSON array.
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+pub(crate) fn json_to_array(json: &str) -> Option<Vec<Value>> {
+    struct Parser<'a> {
+        chars: std::iter::Peekable<std::str::Chars<'a>>,
+    }
+
+    impl Parser<'_> {
+        fn skip_whitespace(&mut self) {
+            while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+        }
+
+        fn expect(&mut self, word: &str) -> Option<()> {
+            word.chars()
+                .all(|c| self.chars.next() == Some(c))
+                .then_some(())
+        }
+
+        fn value(&mut self) -> Option<Value> {
+            self.skip_whitespace();
+            match *self.chars.peek()? {
+                '[' => self.array().map(Value::Array),
+                '"' => self.string().map(Value::String),
+                't' => self.expect("true").map(|_| Value::Bool(true)),
+                'f' => self.expect("false").map(|_| Value::Bool(false)),
+                'n' => self.expect("null").map(|_| Value::Null),
+                _ => self.number(),
+            }
+        }
+
+        fn array(&mut self) -> Option<Vec<Value>> {
+            self.chars.next();
+            let mut values = Vec::new();
+
+            self.skip_whitespace();
+            if self.chars.next_if_eq(&']').is_some() {
+                return Some(values);
+            }
+
+            loop {
+                values.push(self.value()?);
+                self.skip_whitespace();
+                match self.chars.next()? {
+                    ',' => continue,
+                    ']' => return Some(values),
+                    _ => return None,
+                }
+            }
+        }
+
+        fn string(&mut self) -> Option<String> {
+            self.chars.next();
+            let mut out = String::new();
+
+            loop {
+                match self.chars.next()? {
+                    '"' => return Some(out),
+                    '\\' => match self.chars.next()? {
+                        'n' => out.push('\n'),
+                        'r' => out.push('\r'),
+                        't' => out.push('\t'),
+                        'b' => out.push('\u{8}'),
+                        'f' => out.push('\u{c}'),
+                        'u' => {
+                            let hex: String = (0..4).filter_map(|_| self.chars.next()).collect();
+                            out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                        }
+                        c => out.push(c),
+                    },
+                    c => out.push(c),
+                }
+            }
+        }
+
+        fn number(&mut self) -> Option<Value> {
+            let mut number = String::new();
+            while let Some(c) = self
+                .chars
+                .next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+            {
+                number.push(c);
+            }
+
+            match number.parse::<i64>() {
+                Ok(i) => Some(Value::Int64(i)),
+                Err(_) => number.parse::<f64>().ok().map(Value::Float64),
+            }
+        }
+    }
+
+    let mut parser = Parser {
+        chars: json.chars().peekable(),
+    };
+    parser.skip_whitespace();
+    if parser.chars.peek() != Some(&'[') {
+        return None;
+    }
+
+    let values = parser.array()?;
+    parser.skip_whitespace();
+    parser.chars.peek().is_none().then_some(values)
 }
@@ -54,7 +54,7 @@
 //!
 //! ## Supported Database Types
 //!
-//! - `String` → `VARCHAR(255)`
+//! - `String` → `VARCHAR(255)` (`TEXT` on Postgres)
 //! - `i32` → `INTEGER`
 //! - `i64` → `BIGINT`
 //! - `f32` → `FLOAT`
@@ -67,28 +67,42 @@
 //! - `not_null()` - Makes the column NOT NULL
 //! - `unique()` - Adds a UNIQUE constraint
 //! - `indexed()` - Creates an index on the column
+//! - `indexed_expr(expr)` - Creates an index on an expression such as `LOWER(email)`
+//! - `indexed_where(predicate)` - Creates a partial index on the rows matching `predicate`
+//! - `version()` - Makes the column an optimistic-locking version, bumped on every update
 //! - `default_value(value)` - Sets a default value
 
+mod features;
+
 /// Database connection and management functionality
+#[cfg(lume_backend)]
 pub mod database;
 
 /// Query filtering and condition building
+#[cfg(lume_backend)]
 pub mod filter;
 
 /// Database operations (queries, inserts, etc.)
+#[cfg(lume_backend)]
 pub mod operations;
 
 /// Row abstraction for type-safe data access
+#[cfg(lume_backend)]
 pub mod row;
 
 /// Schema definition and column management
+#[cfg(lume_backend)]
 pub mod schema;
 
 /// Table registry and definition management
+#[cfg(lume_backend)]
 pub mod table;
 
+#[cfg(lume_backend)]
 mod tests;
 
+#[cfg(lume_backend)]
 pub(crate) mod helpers;
 
+#[cfg(lume_backend)]
 mod dialects;
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 
 #[cfg(feature = "mysql")]
 use sqlx::MySqlPool;
@@ -8,9 +8,10 @@ use sqlx::PgPool;
 use sqlx::SqlitePool;
 
 use crate::{
-    database::error::DatabaseError,
-    filter::Filtered,
-    schema::{Schema, Value},
+    database::{error::DatabaseError, timeout},
+    dialects::get_dialect,
+    filter::{ArrayFilter, Filter, FilterType, Filtered},
+    schema::{ColumnConstraint, Schema, Value},
 };
 
 use crate::helpers::{StartingSql, bind_value, build_filter_expr, get_starting_sql};
@@ -62,7 +63,7 @@ pub struct Delete<T> {
     /// Marker for the schema type `T`.
     table: PhantomData<T>,
     /// List of filters to apply to the delete query.
-    filters: Vec<Box<dyn Filtered>>,
+    pub(crate) filters: Vec<Box<dyn Filtered>>,
     /// Database connection pool.
     #[cfg(feature = "mysql")]
     conn: Arc<MySqlPool>,
@@ -72,6 +73,12 @@ pub struct Delete<T> {
 
     #[cfg(feature = "sqlite")]
     conn: Arc<SqlitePool>,
+
+    /// Maximum number of rows to delete.
+    limit: Option<u64>,
+
+    /// How long the statement may run.
+    timeout: Option<Duration>,
 }
 
 impl<T: Schema + Debug> Delete<T> {
@@ -91,6 +98,8 @@ impl<T: Schema + Debug> Delete<T> {
             table: PhantomData,
             conn,
             filters: Vec::new(),
+            limit: None,
+            timeout: None,
         }
     }
 
@@ -110,6 +119,8 @@ impl<T: Schema + Debug> Delete<T> {
             table: PhantomData,
             conn,
             filters: Vec::new(),
+            limit: None,
+            timeout: None,
         }
     }
 
@@ -128,6 +139,8 @@ impl<T: Schema + Debug> Delete<T> {
             table: PhantomData,
             conn,
             filters: Vec::new(),
+            limit: None,
+            timeout: None,
         }
     }
 
@@ -180,11 +193,124 @@ impl<T: Schema + Debug> Delete<T> {
         self
     }
 
+    /// Restricts the delete to rows whose primary key is one of `ids`.
+    ///
+    /// This builds a `WHERE <primary key> IN (...)` filter against the schema's
+    /// primary-key column, so there's no need to build an `in_array` filter by hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::InvalidValue`] if the table doesn't have exactly
+    /// one primary-key column.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::Schema;
+    /// use lume::schema::ColumnInfo;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     db.delete::<User>().by_ids(vec![1, 2, 3])?.execute().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn by_ids<K: Into<Value>>(mut self, ids: Vec<K>) -> Result<Self, DatabaseError> {
+        let columns = T::get_all_columns();
+        let primary_keys: Vec<_> = columns
+            .iter()
+            .filter(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+            .collect();
+
+        let [primary_key] = primary_keys.as_slice() else {
+            return Err(DatabaseError::InvalidValue(format!(
+                "Table {} must have exactly one primary key to delete by ids, found {}",
+                T::table_name(),
+                primary_keys.len()
+            )));
+        };
+
+        self.filters.push(Box::new(ArrayFilter {
+            column1: Some((T::table_name().to_string(), primary_key.name.to_string())),
+            values: Some(ids.into_iter().map(Into::into).collect()),
+            _column2: None,
+            in_array: true,
+            enum_type: None,
+        }));
+
+        Ok(self)
+    }
+
+    /// Deletes at most `limit` of the matching rows.
+    ///
+    /// Useful for purging a large table in batches, each holding its locks only
+    /// briefly. Which of the matching rows are deleted is unspecified.
+    ///
+    /// MySQL appends `LIMIT n` to the statement. Postgres has no `DELETE ... LIMIT`,
+    /// and SQLite only with a compile-time option, so there the statement becomes
+    /// `DELETE ... WHERE <primary key> IN (SELECT <primary key> ... LIMIT n)`,
+    /// which needs exactly one primary-key column.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::lt;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Event {
+    ///         id: i64 [primary_key()],
+    ///         created_at: i64 [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     db.delete::<Event>()
+    ///         .filter(lt(Event::created_at(), 1_700_000_000))
+    ///         .limit(1000)
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Fails the delete with [`DatabaseError::Timeout`] if it runs longer than `timeout`.
+    /// On MySQL and SQLite only the client stops waiting, so the delete may
+    /// still complete on the server.
+    ///
+    /// See [`Query::timeout`](crate::operations::query::Query::timeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Executes the delete operation.
     ///
     /// This method builds and executes the SQL DELETE query, removing records
     /// that match the specified filters.
     ///
+    /// On a table declared with `#[soft_delete]` the rows are kept: the statement
+    /// becomes `UPDATE ... SET deleted_at = CURRENT_TIMESTAMP` on the matching
+    /// rows that aren't deleted yet.
+    ///
     /// # Returns
     ///
     /// - `Ok(())`: If the delete operation was successful
@@ -219,11 +345,9 @@ impl<T: Schema + Debug> Delete<T> {
     /// }
     /// ```
     pub async fn execute(self) -> Result<(), DatabaseError> {
-        let sql = get_starting_sql(StartingSql::Delete, T::table_name());
-
         let mut params: Vec<Value> = Vec::new();
 
-        let sql = Self::filter_sql(sql, self.filters, &mut params);
+        let sql = Self::delete_sql(self.filters, self.limit, &mut params)?;
 
         let mut conn = self
             .conn
@@ -232,33 +356,104 @@ impl<T: Schema + Debug> Delete<T> {
             .map_err(|e| DatabaseError::ConnectionError(e))?;
         let mut query = sqlx::query(&sql);
         for v in params {
-            query = bind_value(query, v);
+            query = bind_value(query, v)?;
         }
 
-        query
-            .execute(&mut *conn)
-            .await
-            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+        timeout::start(&mut conn, self.timeout).await?;
+        let result = timeout::run(self.timeout, query.execute(&mut *conn), |e| {
+            DatabaseError::ExecutionError(e.to_string())
+        })
+        .await;
+        timeout::finish(&mut conn, self.timeout, result).await?;
 
         Ok(())
     }
 
+    /// Builds the `DELETE` statement, rewriting a limit into a primary-key
+    /// subquery on backends without `DELETE ... LIMIT`.
+    ///
+    /// Soft-deleted tables get an `UPDATE` setting their `deleted_at` column instead.
+    pub(crate) fn delete_sql(
+        mut filters: Vec<Box<dyn Filtered>>,
+        limit: Option<u64>,
+        params: &mut Vec<Value>,
+    ) -> Result<String, DatabaseError> {
+        let dialect = get_dialect();
+        let sql = match T::soft_delete_column() {
+            Some(column) => {
+                // Keep the timestamp of rows that are already deleted
+                filters.push(Box::new(Filter {
+                    column_one: (T::table_name().to_string(), column.to_string()),
+                    value: Some(Value::Null),
+                    column_two: None,
+                    filter_type: FilterType::Eq,
+                    enum_type: None,
+                }));
+                format!(
+                    "{}{} = CURRENT_TIMESTAMP",
+                    get_starting_sql(StartingSql::Update, T::table_name()),
+                    dialect.quote_identifier(column)
+                )
+            }
+            None => get_starting_sql(StartingSql::Delete, T::table_name()),
+        };
+
+        let Some(limit) = limit else {
+            return Self::filter_sql(sql, filters, params);
+        };
+
+        if dialect.supports_delete_limit() {
+            let mut sql = Self::filter_sql(sql, filters, params)?;
+            sql.push_str(&format!(" LIMIT {}", limit));
+            return Ok(sql);
+        }
+
+        let columns = T::get_all_columns();
+        let primary_keys: Vec<_> = columns
+            .iter()
+            .filter(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+            .collect();
+
+        let [primary_key] = primary_keys.as_slice() else {
+            return Err(DatabaseError::InvalidValue(format!(
+                "Table {} must have exactly one primary key to delete with a limit, found {}",
+                T::table_name(),
+                primary_keys.len()
+            )));
+        };
+
+        let key = format!(
+            "{}.{}",
+            dialect.quote_qualified(T::table_name()),
+            dialect.quote_identifier(primary_key.name)
+        );
+        let subquery = format!(
+            "SELECT {} FROM {}",
+            key,
+            dialect.quote_qualified(T::table_name())
+        );
+        let mut subquery = Self::filter_sql(subquery, filters, params)?;
+        subquery.push_str(&format!(" LIMIT {}", limit));
+
+        Ok(format!("{} WHERE {} IN ({})", sql, key, subquery))
+    }
+
     pub(crate) fn filter_sql(
         mut sql: String,
         filters: Vec<Box<dyn Filtered>>,
         params: &mut Vec<Value>,
-    ) -> String {
+    ) -> Result<String, DatabaseError> {
         if filters.is_empty() {
-            return sql;
+            return Ok(sql);
         }
 
         sql.push_str(" WHERE ");
         let mut parts: Vec<String> = Vec::with_capacity(filters.len());
         for filter in &filters {
-            parts.push(build_filter_expr(filter.as_ref(), params));
+            parts.push(build_filter_expr(filter.as_ref(), params)?);
         }
         sql.push_str(&parts.join(" AND "));
 
-        sql
+        Ok(sql)
     }
 }
@@ -8,9 +8,15 @@
 
 use crate::database::error::DatabaseError;
 use crate::dialects::get_dialect;
-use crate::helpers::{StartingSql, bind_column_value, get_starting_sql, validate_column_value};
+use crate::helpers::{
+    MAX_BIND_PARAMS, StartingSql, bind_column_value, bind_value, get_starting_sql,
+    validate_column_value,
+};
+use crate::operations::upsert::conflict_insert_sql;
 use crate::row::Row;
-use crate::schema::{ColumnConstraint, ColumnInfo, Schema, Select, Value};
+use crate::schema::{
+    ColumnConstraint, ColumnInfo, ColumnValidators, Schema, Select, ValidationError, Value,
+};
 
 #[cfg(feature = "mysql")]
 use sqlx::{MySql, MySqlPool};
@@ -29,13 +35,19 @@ use std::sync::Arc;
 
 /// Select columns that should be included in an INSERT statement based on provided values.
 ///
-/// Omits columns that have defaults or are auto-incremented when their value is absent or Null.
-fn select_insertable_columns<'a>(
+/// Omits columns that have defaults or are auto-incremented when their value is absent or Null,
+/// and generated columns, which the database computes itself.
+pub(crate) fn select_insertable_columns<'a>(
     all_columns: Vec<ColumnInfo<'a>>,
     values: &HashMap<String, Value>,
 ) -> Vec<ColumnInfo<'a>> {
     all_columns
         .into_iter()
+        .filter(|col| {
+            !col.constraints
+                .iter()
+                .any(|constraint| matches!(constraint, ColumnConstraint::Generated(_)))
+        })
         .filter(|col| match values.get(col.name) {
             None => {
                 !(col.has_default || col.constraints.contains(&ColumnConstraint::AutoIncrement))
@@ -48,6 +60,100 @@ fn select_insertable_columns<'a>(
         .collect()
 }
 
+/// Builds the `SELECT` that reads an inserted row back on MySQL, which has
+/// no `RETURNING`: the returning columns, filtered by the primary key.
+///
+/// Returns the statement and the primary key's name. Fails with
+/// [`DatabaseError::InvalidValue`] if the table has no primary key.
+#[cfg(feature = "mysql")]
+pub(crate) fn mysql_returning_sql<T: Schema>(
+    returning: &[&str],
+) -> Result<(String, &'static str), DatabaseError> {
+    let Some(primary_key) = T::get_all_columns()
+        .into_iter()
+        .find(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+    else {
+        return Err(DatabaseError::InvalidValue(format!(
+            "Table {} must have a primary key to return inserted rows on MySQL",
+            T::table_name()
+        )));
+    };
+
+    let dialect = get_dialect();
+    let mut sql = get_starting_sql(StartingSql::Select, T::table_name());
+    let columns: Vec<String> = returning
+        .iter()
+        .map(|column| dialect.quote_column_ref(column))
+        .collect();
+    sql.push_str(&columns.join(", "));
+    sql.push_str(&format!(
+        " FROM {} WHERE {} = ?",
+        dialect.quote_qualified(T::table_name()),
+        dialect.quote_identifier(primary_key.name)
+    ));
+
+    Ok((sql, primary_key.name))
+}
+
+/// Runs the database-backed `unique_check()` validators for a row about to be inserted.
+///
+/// Issues one `SELECT EXISTS` per checked column with a non-NULL value and fails with
+/// [`DatabaseError::UniqueViolation`] when the value is already present.
+async fn check_unique_columns(
+    #[cfg(feature = "mysql")] conn: &mut PoolConnection<MySql>,
+    #[cfg(feature = "postgres")] conn: &mut PoolConnection<Postgres>,
+    #[cfg(feature = "sqlite")] conn: &mut PoolConnection<Sqlite>,
+    table_name: &str,
+    columns: &[ColumnInfo<'_>],
+    values: &HashMap<String, Value>,
+) -> Result<(), DatabaseError> {
+    use sqlx::Row as _;
+
+    let dialect = get_dialect();
+
+    for col in columns {
+        if !col.validators.contains(&ColumnValidators::Unique) {
+            continue;
+        }
+
+        let value = match values.get(col.name) {
+            None | Some(Value::Null) => continue,
+            Some(value) => value,
+        };
+
+        let sql = format!(
+            "SELECT EXISTS(SELECT 1 FROM {} WHERE {} = {})",
+            dialect.quote_qualified(table_name),
+            dialect.quote_identifier(col.name),
+            dialect.placeholder(0)
+        );
+
+        let row = bind_value(sqlx::query(&sql), value.clone())?
+            .fetch_one(&mut **conn)
+            .await
+            .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+
+        // Postgres returns a real boolean, MySQL and SQLite return 0 or 1
+        #[cfg(feature = "postgres")]
+        let exists = row.try_get::<bool, _>(0);
+
+        #[cfg(not(feature = "postgres"))]
+        let exists = row.try_get::<i64, _>(0).map(|found| found != 0);
+
+        let exists = exists.map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+
+        if exists {
+            return Err(DatabaseError::UniqueViolation(ValidationError {
+                column: col.name,
+                rule: ColumnValidators::Unique,
+                value: value.clone(),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
 /// A type-safe insert operation for a given schema type.
 ///
 /// The [`Insert`] struct allows you to insert a record of type `T` (which must
@@ -139,6 +245,43 @@ impl<T: Schema + Debug> Insert<T> {
         self
     }
 
+    /// Configures the insert to return the whole inserted row.
+    ///
+    /// Uses `RETURNING *` on Postgres and SQLite. MySQL has no `RETURNING`, so
+    /// the row is read back with a `SELECT *` by its primary key: the one in the
+    /// data, or the generated `LAST_INSERT_ID()` when it was left unset.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use lume::database::Database;
+    /// # use lume::define_schema;
+    /// # use lume::schema::Schema;
+    /// # use lume::schema::ColumnInfo;
+    /// # define_schema! {
+    /// #     User {
+    /// #         id: i32 [primary_key().auto_increment()],
+    /// #         name: String [not_null()],
+    /// #     }
+    /// # }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    /// let db = Database::connect("mysql://...").await?;
+    /// let rows = db
+    ///     .insert(User { id: None, name: "guru".to_string() })
+    ///     .returning_all()
+    ///     .execute()
+    ///     .await?
+    ///     .unwrap_or_default();
+    /// let id: Option<i32> = rows[0].get(User::id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn returning_all(mut self) -> Self {
+        self.returning = vec!["*"];
+        self
+    }
+
     /// Executes the insert operation asynchronously.
     ///
     /// This method builds the SQL `INSERT` statement, binds all values
@@ -192,17 +335,12 @@ impl<T: Schema + Debug> Insert<T> {
 
         for col in selected.iter() {
             let value = values.get(col.name);
-            if validate_column_value(col, value) {
-                query = bind_column_value(query, col, value);
-            } else {
-                eprintln!("Warning: Column {} is not valid for insert", col.name);
-                return Err(DatabaseError::InvalidValue(format!(
-                    "Column {} is not valid for insert",
-                    col.name
-                )));
-            }
+            validate_column_value(col, value).map_err(DatabaseError::ValidationFailed)?;
+            query = bind_column_value(query, col, value)?;
         }
 
+        check_unique_columns(&mut conn, T::table_name(), &selected, &values).await?;
+
         // For PostgreSQL with RETURNING, we need to add RETURNING clause to the INSERT
         #[cfg(feature = "postgres")]
         if !self.returning.is_empty() {
@@ -213,7 +351,7 @@ impl<T: Schema + Debug> Insert<T> {
 
             for col in selected.iter() {
                 let value = values.get(col.name);
-                query = bind_column_value(query, col, value);
+                query = bind_column_value(query, col, value)?;
             }
 
             let rows = query.fetch_all(&mut *conn).await;
@@ -235,7 +373,7 @@ impl<T: Schema + Debug> Insert<T> {
 
             for col in selected.iter() {
                 let value = values.get(col.name);
-                query = bind_column_value(query, col, value);
+                query = bind_column_value(query, col, value)?;
             }
 
             let rows = query.fetch_all(&mut *conn).await;
@@ -259,12 +397,10 @@ impl<T: Schema + Debug> Insert<T> {
             return Ok(None);
         }
 
-        // For MySQL, build SELECT ... WHERE id = ? using either provided id or last_insert_id
+        // For MySQL, read the row back by its primary key: the provided one or last_insert_id
         #[cfg(feature = "mysql")]
         {
-            let select_sql = get_starting_sql(StartingSql::Select, T::table_name());
-            let mut select_sql = get_dialect().returning_sql(select_sql, &self.returning);
-            select_sql.push_str(format!(" FROM {} WHERE id = ?;", T::table_name()).as_str());
+            let (select_sql, primary_key) = mysql_returning_sql::<T>(&self.returning)?;
 
             let conn = self.conn.acquire().await;
 
@@ -276,7 +412,10 @@ impl<T: Schema + Debug> Insert<T> {
 
             let mut query = sqlx::query(&select_sql);
 
-            query = query.bind(_result.last_insert_id());
+            query = match values.get(primary_key) {
+                Some(id) if *id != Value::Null => bind_value(query, id.clone())?,
+                _ => query.bind(_result.last_insert_id()),
+            };
 
             let rows = query.fetch_all(&mut *conn).await;
 
@@ -335,6 +474,111 @@ impl<T: Schema + Debug> Insert<T> {
             Ok(None)
         }
     }
+
+    /// Builds the INSERT statement used by [`execute_returning_id`](Self::execute_returning_id).
+    ///
+    /// Appends `RETURNING <pk>` on Postgres and SQLite; MySQL reads the id from
+    /// the statement result instead. Fails with [`DatabaseError::InvalidValue`] if
+    /// the table has no primary key.
+    pub(crate) fn returning_id_sql(selected: &[ColumnInfo<'_>]) -> Result<String, DatabaseError> {
+        let Some(primary_key) = T::get_all_columns()
+            .into_iter()
+            .find(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+        else {
+            return Err(DatabaseError::InvalidValue(format!(
+                "Table {} must have a primary key to return an inserted id",
+                T::table_name()
+            )));
+        };
+
+        let dialect = get_dialect();
+        let sql = get_starting_sql(StartingSql::Insert, T::table_name());
+        let mut sql = dialect.insert_sql(sql, &selected.to_vec());
+
+        if cfg!(any(feature = "postgres", feature = "sqlite")) {
+            sql.push_str(&format!(
+                " RETURNING {}",
+                dialect.quote_identifier(primary_key.name)
+            ));
+        }
+
+        Ok(sql)
+    }
+
+    /// Executes the insert and returns the new row's primary key.
+    ///
+    /// Lighter than [`returning`](Self::returning) when only the id is needed: the
+    /// key comes from `LAST_INSERT_ID()` on MySQL and `RETURNING <pk>` on Postgres
+    /// and SQLite, with no follow-up SELECT. On MySQL only `AUTO_INCREMENT` keys are
+    /// reported; an explicitly set key returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use lume::database::Database;
+    /// # use lume::define_schema;
+    /// # use lume::schema::Schema;
+    /// # use lume::schema::ColumnInfo;
+    /// # define_schema! {
+    /// #     User {
+    /// #         id: i64 [primary_key().auto_increment()],
+    /// #         name: String [not_null()],
+    /// #     }
+    /// # }
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let db = Database::connect("mysql://...").await.unwrap();
+    /// let id = db
+    ///     .insert(User { id: None, name: "guru".to_string() })
+    ///     .execute_returning_id()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn execute_returning_id(self) -> Result<i64, DatabaseError> {
+        let mut conn = self
+            .conn
+            .acquire()
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        let values = self.data.values();
+        let selected = select_insertable_columns(T::get_all_columns(), &values);
+
+        let sql = Self::returning_id_sql(&selected)?;
+        let mut query = sqlx::query(&sql);
+
+        for col in selected.iter() {
+            let value = values.get(col.name);
+            validate_column_value(col, value).map_err(DatabaseError::ValidationFailed)?;
+            query = bind_column_value(query, col, value)?;
+        }
+
+        check_unique_columns(&mut conn, T::table_name(), &selected, &values).await?;
+
+        #[cfg(feature = "mysql")]
+        {
+            let result = query
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+            Ok(result.last_insert_id() as i64)
+        }
+
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        {
+            let row = query
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+            // Integer keys may come back as INT or SMALLINT rather than BIGINT
+            sqlx::Row::try_get::<i64, _>(&row, 0)
+                .or_else(|_| sqlx::Row::try_get::<i32, _>(&row, 0).map(i64::from))
+                .or_else(|_| sqlx::Row::try_get::<i16, _>(&row, 0).map(i64::from))
+                .map_err(|e| DatabaseError::QueryError(e.to_string()))
+        }
+    }
 }
 
 /// A type-safe insert operation for inserting multiple records of a given schema type.
@@ -359,6 +603,18 @@ pub struct InsertMany<T: Schema + Debug> {
 
     /// Whether to return the inserted rows.
     returning: Vec<&'static str>,
+
+    /// What to do with records whose primary key already exists.
+    on_conflict: Option<OnConflict>,
+}
+
+/// What [`InsertMany::on_conflict`] does with a record whose primary key already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Overwrite the existing row's other columns with the record's values
+    Update,
+    /// Keep the existing row and skip the record
+    Ignore,
 }
 
 impl<T: Schema + Debug> InsertMany<T> {
@@ -369,6 +625,7 @@ impl<T: Schema + Debug> InsertMany<T> {
             data,
             conn,
             returning: Vec::new(),
+            on_conflict: None,
         }
     }
 
@@ -379,6 +636,7 @@ impl<T: Schema + Debug> InsertMany<T> {
             data,
             conn,
             returning: Vec::new(),
+            on_conflict: None,
         }
     }
 
@@ -389,6 +647,7 @@ impl<T: Schema + Debug> InsertMany<T> {
             data,
             conn,
             returning: Vec::new(),
+            on_conflict: None,
         }
     }
 
@@ -398,6 +657,267 @@ impl<T: Schema + Debug> InsertMany<T> {
         self
     }
 
+    /// Sets what happens to records whose primary key already exists.
+    ///
+    /// The conflict clause is added to every record's `INSERT`: `ON DUPLICATE KEY
+    /// UPDATE` on MySQL and `ON CONFLICT (...) DO UPDATE` / `DO NOTHING` on
+    /// Postgres and SQLite, keyed on the primary key like [`Upsert`](crate::operations::upsert::Upsert).
+    /// On MySQL any unique key conflict triggers it, not only the primary key.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use lume::database::Database;
+    /// # use lume::define_schema;
+    /// # use lume::operations::insert::OnConflict;
+    /// # use lume::schema::Schema;
+    /// # use lume::schema::ColumnInfo;
+    /// # define_schema! {
+    /// #     Product {
+    /// #         sku: String [primary_key()],
+    /// #         price: i32 [not_null()],
+    /// #     }
+    /// # }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    /// let db = Database::connect("mysql://...").await?;
+    /// // Re-syncing the catalog updates known products and adds new ones
+    /// db.insert_many(vec![
+    ///     Product { sku: "A-1".to_string(), price: 10 },
+    ///     Product { sku: "B-2".to_string(), price: 25 },
+    /// ])
+    /// .on_conflict(OnConflict::Update)
+    /// .execute()
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// Builds one record's `INSERT`, with the conflict clause if one was set.
+    fn insert_statement(&self, selected: &[ColumnInfo<'_>]) -> Result<String, DatabaseError> {
+        match self.on_conflict {
+            None => {
+                let sql = get_starting_sql(StartingSql::Insert, T::table_name());
+                Ok(get_dialect().insert_sql(sql, &selected.to_vec()))
+            }
+            Some(on_conflict) => {
+                conflict_insert_sql::<T>(selected, on_conflict == OnConflict::Update)
+            }
+        }
+    }
+
+    /// Builds one multi-row `INSERT` of `rows` records that all set the `selected`
+    /// columns, as used by [`execute_returning_ids`](Self::execute_returning_ids).
+    ///
+    /// Appends `RETURNING <pk>` on Postgres and SQLite; MySQL reads the ids from
+    /// the statement result instead. Fails with [`DatabaseError::InvalidValue`] if
+    /// the table has no primary key.
+    pub(crate) fn returning_ids_sql(
+        selected: &[ColumnInfo<'_>],
+        rows: usize,
+    ) -> Result<String, DatabaseError> {
+        let Some(primary_key) = T::get_all_columns()
+            .into_iter()
+            .find(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+        else {
+            return Err(DatabaseError::InvalidValue(format!(
+                "Table {} must have a primary key to return inserted ids",
+                T::table_name()
+            )));
+        };
+
+        let dialect = get_dialect();
+        let mut sql = get_starting_sql(StartingSql::Insert, T::table_name());
+        let columns: Vec<String> = selected
+            .iter()
+            .map(|col| dialect.quote_identifier(col.name))
+            .collect();
+        sql.push_str(&columns.join(", "));
+        sql.push_str(") VALUES ");
+
+        let tuples: Vec<String> = (0..rows)
+            .map(|row| dialect.values_tuple_sql(selected, row * selected.len()))
+            .collect();
+        sql.push_str(&tuples.join(", "));
+
+        if cfg!(any(feature = "postgres", feature = "sqlite")) {
+            sql.push_str(&format!(
+                " RETURNING {}",
+                dialect.quote_identifier(primary_key.name)
+            ));
+        }
+
+        Ok(sql)
+    }
+
+    /// Inserts all records and returns their primary keys, in the same order as the records.
+    ///
+    /// Consecutive records that set the same columns go in one multi-row
+    /// `INSERT`, and all batches run in one transaction, so a failing batch
+    /// leaves none of the records inserted. Postgres and SQLite read the keys from `RETURNING <pk>`, which
+    /// both return in `VALUES` order.
+    ///
+    /// MySQL has no `RETURNING`: a record's own key is used when it sets one, and
+    /// generated keys are counted up from `LAST_INSERT_ID()`, the first key the
+    /// statement generated. That assumes the statement got a contiguous run of
+    /// `AUTO_INCREMENT` values, which InnoDB guarantees for multi-row `INSERT`s
+    /// under `innodb_autoinc_lock_mode` 0 and 1, but not under 2 (the MySQL 8
+    /// default) while other inserts into the table run concurrently.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if the table has no primary key
+    /// or [`on_conflict`](Self::on_conflict) is set, since skipped or updated
+    /// records get no new key.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use lume::database::Database;
+    /// # use lume::define_schema;
+    /// # use lume::schema::Schema;
+    /// # use lume::schema::ColumnInfo;
+    /// # define_schema! {
+    /// #     User {
+    /// #         id: i64 [primary_key().auto_increment()],
+    /// #         name: String [not_null()],
+    /// #     }
+    /// # }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    /// let db = Database::connect("mysql://...").await?;
+    /// let ids = db
+    ///     .insert_many(vec![
+    ///         User { id: None, name: "ada".to_string() },
+    ///         User { id: None, name: "grace".to_string() },
+    ///     ])
+    ///     .execute_returning_ids()
+    ///     .await?;
+    /// assert_eq!(ids.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_returning_ids(self) -> Result<Vec<i64>, DatabaseError> {
+        if self.on_conflict.is_some() {
+            return Err(DatabaseError::InvalidValue(
+                "execute_returning_ids can't be combined with on_conflict".to_string(),
+            ));
+        }
+
+        #[cfg(feature = "mysql")]
+        let primary_key = T::get_all_columns()
+            .into_iter()
+            .find(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+            .map(|col| col.name)
+            .unwrap_or_default();
+
+        let mut conn = self
+            .conn
+            .acquire()
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        let records: Vec<_> = self
+            .data
+            .iter()
+            .map(|record| {
+                let values = record.values();
+                let selected = select_insertable_columns(T::get_all_columns(), &values);
+                (values, selected)
+            })
+            .collect();
+
+        for (values, selected) in &records {
+            for col in selected.iter() {
+                validate_column_value(col, values.get(col.name))
+                    .map_err(DatabaseError::ValidationFailed)?;
+            }
+            check_unique_columns(&mut conn, T::table_name(), selected, values).await?;
+        }
+
+        // A failing batch must not leave the earlier batches inserted
+        let mut tx = sqlx::Connection::begin(&mut *conn)
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        let mut ids = Vec::with_capacity(records.len());
+        let mut start = 0;
+        while start < records.len() {
+            let columns = &records[start].1;
+            let max_rows = (MAX_BIND_PARAMS / columns.len().max(1)).max(1);
+            let mut end = start + 1;
+            while end < records.len()
+                && end - start < max_rows
+                && records[end].1.len() == columns.len()
+                && records[end]
+                    .1
+                    .iter()
+                    .zip(columns.iter())
+                    .all(|(a, b)| a.name == b.name)
+            {
+                end += 1;
+            }
+
+            let batch = &records[start..end];
+            let sql = Self::returning_ids_sql(columns, batch.len())?;
+            let mut query = sqlx::query(&sql);
+            for (values, selected) in batch {
+                for col in selected.iter() {
+                    query = bind_column_value(query, col, values.get(col.name))?;
+                }
+            }
+
+            #[cfg(feature = "mysql")]
+            {
+                let result = query
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+                let mut next = result.last_insert_id() as i64;
+                for (values, _) in batch {
+                    match values
+                        .get(primary_key)
+                        .and_then(|value| i64::try_from(value.clone()).ok())
+                    {
+                        Some(id) => ids.push(id),
+                        None => {
+                            ids.push(next);
+                            next += 1;
+                        }
+                    }
+                }
+            }
+
+            #[cfg(any(feature = "postgres", feature = "sqlite"))]
+            {
+                let rows = query
+                    .fetch_all(&mut *tx)
+                    .await
+                    .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+                for row in &rows {
+                    // Integer keys may come back as INT or SMALLINT rather than BIGINT
+                    let id = sqlx::Row::try_get::<i64, _>(row, 0)
+                        .or_else(|_| sqlx::Row::try_get::<i32, _>(row, 0).map(i64::from))
+                        .or_else(|_| sqlx::Row::try_get::<i16, _>(row, 0).map(i64::from))
+                        .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
+                    ids.push(id);
+                }
+            }
+
+            start = end;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+        Ok(ids)
+    }
+
     /// Executes the insert operation for all records asynchronously.
     pub async fn execute(self) -> Result<Option<Vec<Row<T>>>, DatabaseError> {
         let conn = self.conn.acquire().await;
@@ -418,13 +938,12 @@ impl<T: Schema + Debug> InsertMany<T> {
             let all_columns = T::get_all_columns();
             let selected: Vec<ColumnInfo> = select_insertable_columns(all_columns, &values);
 
-            let sql = get_starting_sql(StartingSql::Insert, T::table_name());
-            let sql = get_dialect().insert_sql(sql, &selected);
+            let sql = self.insert_statement(&selected)?;
             let mut query = sqlx::query(&sql);
 
             for col in selected.iter() {
                 let value = values.get(col.name);
-                query = bind_column_value(query, col, value);
+                query = bind_column_value(query, col, value)?;
             }
 
             #[cfg(feature = "mysql")]
@@ -517,9 +1036,7 @@ impl<T: Schema + Debug> InsertMany<T> {
         }
 
         // Fetch selected columns for all inserted ids
-        let select_sql = get_starting_sql(StartingSql::Select, T::table_name());
-        let mut select_sql = get_dialect().returning_sql(select_sql, &self.returning);
-        select_sql.push_str(format!(" FROM {} WHERE id = ?;", T::table_name()).as_str());
+        let (select_sql, _) = mysql_returning_sql::<T>(&self.returning)?;
 
         for id in inserted_ids {
             let q = sqlx::query(&select_sql).bind(id);
@@ -583,14 +1100,13 @@ impl<T: Schema + Debug> InsertMany<T> {
     ) -> Result<Result<Vec<Row<T>>, u64>, DatabaseError> {
         // If RETURNING is requested, return the resulting row(s)
         if !self.returning.is_empty() {
-            let sql = get_starting_sql(StartingSql::Insert, T::table_name());
-            let sql = get_dialect().insert_sql(sql, &selected);
+            let sql = self.insert_statement(&selected)?;
             let sql = get_dialect().returning_sql(sql, &self.returning);
             let mut query = sqlx::query(&sql);
 
             for col in selected.iter() {
                 let value = values.get(col.name);
-                query = bind_column_value(query, col, value);
+                query = bind_column_value(query, col, value)?;
             }
 
             let rows = query.fetch_all(&mut **conn).await;
@@ -602,13 +1118,12 @@ impl<T: Schema + Debug> InsertMany<T> {
             return Ok(Ok(out_rows));
         } else {
             // Otherwise, execute and return the inserted id (rowid)
-            let sql = get_starting_sql(StartingSql::Insert, T::table_name());
-            let sql = get_dialect().insert_sql(sql, &selected);
+            let sql = self.insert_statement(&selected)?;
             let mut query = sqlx::query(&sql);
 
             for col in selected.iter() {
                 let value = values.get(col.name);
-                query = bind_column_value(query, col, value);
+                query = bind_column_value(query, col, value)?;
             }
 
             let result = query.execute(&mut **conn).await;
@@ -631,9 +1146,12 @@ impl<T: Schema + Debug> InsertMany<T> {
                     Value::UInt8(v) => *v as u64,
                     Value::String(_)
                     | Value::Uuid(_)
+                    | Value::Int128(_)
+                    | Value::UInt128(_)
                     | Value::Float32(_)
                     | Value::Float64(_)
                     | Value::Bool(_)
+                    | Value::DateTimeTz(_)
                     | Value::Between(_, _)
                     | Value::Array(_)
                     | Value::Null => result.last_insert_rowid() as u64,
@@ -677,9 +1195,12 @@ impl<T: Schema + Debug> InsertMany<T> {
                 Value::UInt8(v) => inserted_ids.push(*v as u64),
                 Value::String(_)
                 | Value::Uuid(_)
+                | Value::Int128(_)
+                | Value::UInt128(_)
                 | Value::Float32(_)
                 | Value::Float64(_)
                 | Value::Bool(_)
+                | Value::DateTimeTz(_)
                 | Value::Between(_, _)
                 | Value::Null => inserted_ids.push(result.last_insert_id()),
             }
@@ -704,14 +1225,13 @@ impl<T: Schema + Debug> InsertMany<T> {
     ) -> Result<(), DatabaseError> {
         // For PostgreSQL, if returning is requested, we need to use RETURNING clause
         if !self.returning.is_empty() {
-            let sql = get_starting_sql(StartingSql::Insert, T::table_name());
-            let sql = get_dialect().insert_sql(sql, &selected);
+            let sql = self.insert_statement(&selected)?;
             let sql = get_dialect().returning_sql(sql, &self.returning);
             let mut query = sqlx::query(&sql);
 
             for col in selected.iter() {
                 let value = values.get(col.name);
-                query = bind_column_value(query, col, value);
+                query = bind_column_value(query, col, value)?;
             }
 
             let rows = query.fetch_all(&mut **conn).await;
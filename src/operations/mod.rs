@@ -14,4 +14,11 @@ pub mod query;
 /// Delete operations for removing data from database tables
 pub mod delete;
 
+/// Upsert operations for inserting or updating data by primary key
+pub mod upsert;
+
+/// Replace operations for MySQL's delete-then-insert `REPLACE INTO`
+#[cfg(feature = "mysql")]
+pub mod replace;
+
 pub mod update;
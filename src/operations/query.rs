@@ -5,21 +5,28 @@
 //! This module provides type-safe query building and execution functionality.
 //! It includes the `Query<T>` struct for building and executing database queries.
 
-use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 
 #[cfg(feature = "mysql")]
-use sqlx::MySqlPool;
+use sqlx::{MySql as Db, MySqlPool, mysql::MySqlRow as DbRow};
 #[cfg(feature = "postgres")]
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres as Db, postgres::PgRow as DbRow};
 #[cfg(feature = "sqlite")]
-use sqlx::SqlitePool;
+use sqlx::{Sqlite as Db, SqlitePool, sqlite::SqliteRow as DbRow};
 
+use crate::database::cache::ResultCache;
+use crate::database::retry::{RetryPolicy, statement_error};
+use crate::database::timeout;
+use crate::database::transaction::Transaction;
 use crate::dialects::get_dialect;
-use crate::filter::{Filter, Filtered};
-use crate::helpers::{StartingSql, bind_value, build_filter_expr, get_starting_sql};
-use crate::schema::{ColumnInfo, Select, Value};
+use crate::filter::{Filter, FilterType, Filtered, GroupFilter, SqlFilter};
+use crate::helpers::{StartingSql, bind_raw_sql, bind_value, build_filter_expr, get_starting_sql};
+use crate::schema::{AnyColumn, Column, ColumnConstraint, ColumnInfo, Select, Value};
 use crate::{database::error::DatabaseError, row::Row, schema::Schema};
 
+/// Alias of the per-group count selected by [`Query::count_by`].
+const COUNT_BY_ALIAS: &str = "lume_group_count";
+
 /// A type-safe query builder for database operations.
 ///
 /// The `Query<T, S>` struct provides a fluent interface for building and executing
@@ -84,30 +91,139 @@ pub struct Query<T, S> {
     pub(crate) conn: Arc<SqlitePool>,
 
     pub(crate) select: Option<S>,
+    pub(crate) raw_select: Vec<String>,
+    pub(crate) coalesce: Vec<(String, String, Value)>,
+    pub(crate) aliases: Vec<(String, String, String)>,
+    pub(crate) windows: Vec<(String, String)>,
     pub(crate) distinct: bool,
 
     pub(crate) joins: Vec<JoinInfo>,
 
     pub(crate) limit: Option<u64>,
     pub(crate) offset: Option<u64>,
+
+    pub(crate) result_cache: Option<Arc<ResultCache>>,
+    pub(crate) cache_ttl: Option<Duration>,
+
+    pub(crate) lock: Option<RowLock>,
+
+    pub(crate) order: Vec<(String, String, SortOrder)>,
+    pub(crate) stable_order: bool,
+    pub(crate) group_by: Vec<(String, String)>,
+    pub(crate) having: Vec<(String, Vec<Value>)>,
+
+    pub(crate) unions: Vec<(UnionType, Query<T, S>)>,
+
+    pub(crate) retry: Option<RetryPolicy>,
+
+    pub(crate) timeout: Option<Duration>,
+
+    pub(crate) with_deleted: bool,
+}
+
+/// Copies the query with its filters, joins, selection and ordering, so a
+/// base query can be run in several variations, such as a `count` and an
+/// `execute`.
+impl<T, S: Clone> Clone for Query<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            table: PhantomData,
+            filters: self.filters.clone(),
+            conn: Arc::clone(&self.conn),
+            select: self.select.clone(),
+            raw_select: self.raw_select.clone(),
+            coalesce: self.coalesce.clone(),
+            aliases: self.aliases.clone(),
+            windows: self.windows.clone(),
+            distinct: self.distinct,
+            joins: self.joins.clone(),
+            limit: self.limit,
+            offset: self.offset,
+            result_cache: self.result_cache.clone(),
+            cache_ttl: self.cache_ttl,
+            lock: self.lock,
+            order: self.order.clone(),
+            stable_order: self.stable_order,
+            group_by: self.group_by.clone(),
+            having: self.having.clone(),
+            unions: self.unions.clone(),
+            retry: self.retry,
+            timeout: self.timeout,
+            with_deleted: self.with_deleted,
+        }
+    }
 }
 
 /// Information about a join operation
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct JoinInfo {
     /// The table to join
     pub(crate) table_name: String,
-    /// The join condition (column-to-column comparison)
-    pub(crate) condition: Filter,
+    /// The join condition, or `None` for a cross join
+    pub(crate) condition: Option<Box<dyn Filtered>>,
 
     pub(crate) join_type: JoinType,
 
     pub(crate) columns: Vec<ColumnInfo<'static>>,
 
-    pub(crate) selected_columns: Vec<&'static str>,
+    /// The selected `(table, column)` pairs, unquoted
+    pub(crate) selected_columns: Vec<(String, String)>,
+
+    /// The alias the joined table is referenced by, set with [`Query::join_alias`]
+    pub(crate) alias: Option<String>,
+
+    /// The joined table's `#[soft_delete]` column, whose deleted rows the join skips
+    pub(crate) soft_delete_column: Option<&'static str>,
+
+    /// Whether the selected columns are named `table.column` in the result,
+    /// set by [`Query::execute_joined`]
+    pub(crate) prefix_columns: bool,
+}
+
+/// Splits a join's `Table.column` selection into `(table, column)` pairs.
+fn join_selection(columns: Vec<&'static str>) -> Vec<(String, String)> {
+    columns
+        .into_iter()
+        .filter_map(|column| column.rsplit_once('.'))
+        .map(|(table, column)| (table.to_string(), column.to_string()))
+        .collect()
+}
+
+impl JoinInfo {
+    /// Returns true if `table` names this join, by its table name or alias.
+    pub(crate) fn references(&self, table: &str) -> bool {
+        self.table_name == table || self.alias.as_deref() == Some(table)
+    }
+}
+
+/// The direction of an `ORDER BY` term
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// How a query is combined with the one before it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum UnionType {
+    /// `UNION`, dropping duplicate rows
+    Distinct,
+    /// `UNION ALL`, keeping every row
+    All,
+}
+
+/// How a `FOR UPDATE` row lock behaves when a row is already locked
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RowLock {
+    /// Wait for the other transaction to release the row
+    Wait,
+    /// Leave locked rows out of the result (`SKIP LOCKED`)
+    SkipLocked,
+    /// Fail immediately (`NOWAIT`)
+    NoWait,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum JoinType {
     Left,
     Inner,
@@ -134,10 +250,25 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
             table: PhantomData,
             filters: Vec::new(),
             select: None,
+            raw_select: Vec::new(),
+            coalesce: Vec::new(),
+            aliases: Vec::new(),
+            windows: Vec::new(),
             distinct: false,
             limit: None,
             offset: None,
             joins: Vec::new(),
+            result_cache: None,
+            cache_ttl: None,
+            lock: None,
+            order: Vec::new(),
+            stable_order: false,
+            group_by: Vec::new(),
+            having: Vec::new(),
+            unions: Vec::new(),
+            retry: None,
+            timeout: None,
+            with_deleted: false,
             conn,
         }
     }
@@ -157,10 +288,25 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
             table: PhantomData,
             filters: Vec::new(),
             select: None,
+            raw_select: Vec::new(),
+            coalesce: Vec::new(),
+            aliases: Vec::new(),
+            windows: Vec::new(),
             distinct: false,
             limit: None,
             offset: None,
             joins: Vec::new(),
+            result_cache: None,
+            cache_ttl: None,
+            lock: None,
+            order: Vec::new(),
+            stable_order: false,
+            group_by: Vec::new(),
+            having: Vec::new(),
+            unions: Vec::new(),
+            retry: None,
+            timeout: None,
+            with_deleted: false,
             conn,
         }
     }
@@ -180,84 +326,122 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
             table: PhantomData,
             filters: Vec::new(),
             select: None,
+            raw_select: Vec::new(),
+            coalesce: Vec::new(),
+            aliases: Vec::new(),
+            windows: Vec::new(),
             distinct: false,
             limit: None,
             offset: None,
             joins: Vec::new(),
+            result_cache: None,
+            cache_ttl: None,
+            lock: None,
+            order: Vec::new(),
+            stable_order: false,
+            group_by: Vec::new(),
+            having: Vec::new(),
+            unions: Vec::new(),
+            retry: None,
+            timeout: None,
+            with_deleted: false,
             conn,
         }
     }
 
-    /// Adds a filter condition to the query.
-    ///
-    /// This method allows chaining multiple filter conditions to build
-    /// complex WHERE clauses. All filters are combined with AND logic.
-    ///
-    /// # Arguments
+    /// Attaches the database's result cache.
+    pub(crate) fn with_result_cache(mut self, cache: Arc<ResultCache>) -> Self {
+        self.result_cache = Some(cache);
+        self
+    }
+
+    /// Attaches the database's retry policy, if any.
+    pub(crate) fn with_retry(mut self, policy: Option<RetryPolicy>) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Retries this query if it fails with a transient connection error,
+    /// overriding any policy set with [`Database::with_retry`].
     ///
-    /// - `filter`: The filter condition to add
+    /// [`Database::with_retry`]: crate::database::Database::with_retry
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Fails the query with [`DatabaseError::Timeout`] if it runs longer than `timeout`.
     ///
-    /// # Returns
+    /// The timeout is enforced on the client: it covers sending the statement
+    /// and reading back all of its rows, but not waiting for a pool connection.
+    /// When it expires the query returns `DatabaseError::Timeout(timeout)` and
+    /// its connection is closed instead of going back to the pool.
     ///
-    /// The query builder instance for method chaining
+    /// On Postgres the statement also runs with a server-side
+    /// `statement_timeout` of the same length, so the server stops working on
+    /// it too; if the server cancels it first, the error is still
+    /// `DatabaseError::Timeout`. MySQL and SQLite have no server-side limit, so
+    /// the statement keeps running after the timeout, and on SQLite it holds its
+    /// locks until it finishes. With a retry policy, each attempt gets the full
+    /// `timeout`.
     ///
     /// # Example
     ///
     /// ```no_run
+    /// use std::time::Duration;
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
     /// use lume::schema::{Schema, ColumnInfo};
-    /// use lume::filter::eq_value;
     ///
     /// define_schema! {
     ///     User {
     ///         id: i32 [primary_key()],
-    ///         name: String [not_null()],
-    ///         age: i32,
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let query = db.query::<User, SelectUser>()
-    ///         .filter(eq_value(User::name(), Value::String("John".to_string())));
+    ///     let users = db.query::<User, SelectUser>()
+    ///         .timeout(Duration::from_secs(2))
+    ///         .execute()
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn filter<F>(mut self, filter: F) -> Self
-    where
-        F: Filtered + 'static,
-    {
-        self.filters.push(Box::new(filter));
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
         self
     }
 
-    /// Adds a limit to the query.
+    /// Serves this query from the database's result cache for up to `ttl`.
     ///
-    /// This method adds a LIMIT clause to the SQL query, limiting the number of rows returned.
+    /// The first execution stores its rows, and identical executions (same SQL
+    /// and parameters) within `ttl` return a copy of them without touching the
+    /// database. Meant for lookup tables that rarely change: writes made in the
+    /// meantime aren't seen until the entry expires or the cache is cleared with
+    /// [`ResultCache::clear`].
     ///
     /// # Arguments
     ///
-    /// * `limit` - The maximum number of rows to return.
+    /// - `ttl`: How long a cached result stays valid
     ///
     /// # Returns
     ///
-    /// The query builder instance for method chaining.
+    /// The query builder instance for method chaining
     ///
     /// # Example
     ///
     /// ```no_run
+    /// use std::time::Duration;
+    ///
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
     /// use lume::schema::{Schema, ColumnInfo};
-    /// use lume::filter::eq_value;
     ///
     /// define_schema! {
-    ///     User {
-    ///         id: i32 [primary_key()],
+    ///     Country {
+    ///         code: String [primary_key()],
     ///         name: String [not_null()],
     ///     }
     /// }
@@ -265,26 +449,30 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let query = db.query::<User, SelectUser>().limit(10);
+    ///     let countries = db.query::<Country, SelectCountry>()
+    ///         .cached(Duration::from_secs(300))
+    ///         .execute()
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn limit(mut self, limit: u64) -> Self {
-        self.limit = Some(limit);
+    pub fn cached(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
         self
     }
 
-    /// Adds an offset to the query.
+    /// Adds a filter condition to the query.
     ///
-    /// This method adds an OFFSET clause to the SQL query, skipping the specified number of rows before starting to return rows.
+    /// This method allows chaining multiple filter conditions to build
+    /// complex WHERE clauses. All filters are combined with AND logic.
     ///
     /// # Arguments
     ///
-    /// * `offset` - The number of rows to skip before starting to return rows.
+    /// - `filter`: The filter condition to add
     ///
     /// # Returns
     ///
-    /// The query builder instance for method chaining.
+    /// The query builder instance for method chaining
     ///
     /// # Example
     ///
@@ -293,482 +481,2355 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
     /// use lume::database::Database;
     /// use lume::filter::Filter;
     /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::eq_value;
     ///
     /// define_schema! {
     ///     User {
     ///         id: i32 [primary_key()],
     ///         name: String [not_null()],
+    ///         age: i32,
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let query = db.query::<User, SelectUser>().offset(20);
+    ///     let query = db.query::<User, SelectUser>()
+    ///         .filter(eq_value(User::name(), Value::String("John".to_string())));
     ///     Ok(())
     /// }
     /// ```
-    pub fn offset(mut self, offset: u64) -> Self {
-        self.offset = Some(offset);
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Filtered + 'static,
+    {
+        self.filters.push(Box::new(filter));
         self
     }
 
-    /// Specifies which columns to select in the query.
+    /// Adds a filter only if `condition` is true.
     ///
-    /// This method accepts a selection schema that determines which columns
-    /// will be included in the SELECT clause of the SQL query.
+    /// # Example
     ///
-    /// # Arguments
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::{eq_value, gte};
+    /// use lume::schema::{Schema, ColumnInfo};
     ///
-    /// - `select_schema`: The selection schema specifying which columns to include
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///         age: i32,
+    ///     }
+    /// }
     ///
-    /// # Returns
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let adults_only = true;
+    ///     let name: Option<&str> = None;
     ///
-    /// The query builder instance for method chaining
-
-    pub fn select(mut self, select_schema: S) -> Self {
-        self.select = Some(select_schema);
-        self
+    ///     let users = db.query::<User, SelectUser>()
+    ///         .filter_if(adults_only, gte(User::age(), 18))
+    ///         .filter_opt(name.map(|name| eq_value(User::name(), name)))
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn filter_if<F>(self, condition: bool, filter: F) -> Self
+    where
+        F: Filtered + 'static,
+    {
+        if condition { self.filter(filter) } else { self }
     }
 
-    /// Specifies that the query should select only distinct rows for the given columns.
-    ///
-    /// This method works like [`select`](Self::select), but adds a `DISTINCT` clause to the SQL query,
-    /// ensuring that duplicate rows are removed from the result set.
+    /// Adds a filter only if it is `Some`.
     ///
-    /// # Arguments
-    ///
-    /// - `select_schema`: The selection schema specifying which columns to include in the DISTINCT selection.
-    ///
-    /// # Returns
+    /// See [`filter_if`](Self::filter_if).
+    pub fn filter_opt<F>(self, filter: Option<F>) -> Self
+    where
+        F: Filtered + 'static,
+    {
+        match filter {
+            Some(filter) => self.filter(filter),
+            None => self,
+        }
+    }
+
+    /// Adds a shared filter, so a common predicate can be defined once and
+    /// applied to many queries.
     ///
-    /// The query builder instance for method chaining.
+    /// The query keeps its own copy of the filter, so the `Arc` can be reused
+    /// for as many queries as needed.
     ///
     /// # Example
     ///
     /// ```no_run
+    /// use std::sync::Arc;
+    ///
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
+    /// use lume::filter::{Filtered, eq_value};
     /// use lume::schema::{Schema, ColumnInfo};
     ///
     /// define_schema! {
-    ///     User {
+    ///     Invoice {
     ///         id: i32 [primary_key()],
-    ///         name: String [not_null()],
+    ///         tenant_id: i32 [not_null()],
+    ///         paid: bool [not_null()],
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     // Selects only unique user names
-    ///     let query = db.query::<User, SelectUser>().select_distinct(SelectUser::selected().name());
+    ///     let tenant: Arc<dyn Filtered> = Arc::new(eq_value(Invoice::tenant_id(), 7));
+    ///
+    ///     let all = db.query::<Invoice, SelectInvoice>()
+    ///         .filter_arc(tenant.clone())
+    ///         .execute()
+    ///         .await?;
+    ///     let unpaid = db.query::<Invoice, SelectInvoice>()
+    ///         .filter_arc(tenant)
+    ///         .filter(eq_value(Invoice::paid(), false))
+    ///         .execute()
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn select_distinct(mut self, select_schema: S) -> Self {
-        self.select = Some(select_schema);
-        self.distinct = true;
+    pub fn filter_arc(mut self, filter: Arc<dyn Filtered>) -> Self {
+        self.filters.push(filter.clone_box());
         self
     }
 
-    /// Adds a left join to the query.
-    ///
-    /// This method joins the specified schema table to the current query using a LEFT JOIN.
-    /// All records from the left table (current query) are returned, along with matching
-    /// records from the right table (joined table).
-    ///
-    /// # Arguments
-    ///
-    /// - `filter`: The join condition specifying how tables should be joined
-    ///
-    /// # Returns
+    /// Adds a filter wrapped in parentheses, ANDed with the other filters.
     ///
-    /// The query builder instance for method chaining
+    /// [`or`](crate::filter::or) and [`and`](crate::filter::and) already render
+    /// their own parentheses, so for them this only spells out the precedence:
+    /// `filter(c).filter_group(or(a, b))` builds the same `c AND (a OR b)` as
+    /// `filter(c).filter(or(a, b))`. A raw [`sql`](crate::filter::sql) fragment
+    /// is embedded as is, though, and needs the group to keep an `OR` inside it
+    /// from binding looser than the surrounding `AND`.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
-    /// use lume::schema::{Schema, ColumnInfo};
-    /// use lume::filter::eq_column;
+    /// use lume::filter::{eq_value, gt, or};
     ///
     /// define_schema! {
     ///     User {
     ///         id: i32 [primary_key()],
     ///         name: String [not_null()],
-    ///     }
-    ///
-    ///     Post {
-    ///         id: i32 [primary_key()],
-    ///         user_id: i32,
-    ///         title: String,
+    ///         age: i32,
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let results = db.query::<User, SelectUser>()
-    ///         .left_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost { title: true, ..Default::default() })
+    ///     let users = db
+    ///         .query::<User, SelectUser>()
+    ///         .filter(gt(User::age(), 18))
+    ///         .filter_group(or(
+    ///             eq_value(User::name(), "Alice"),
+    ///             eq_value(User::name(), "Bob"),
+    ///         ))
     ///         .execute()
     ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn left_join<LeftJoinSchema: Schema + Debug, LeftJoinSchemaSelect: Select + Debug>(
-        mut self,
-        filter: Filter,
-        select_schema: LeftJoinSchemaSelect,
-    ) -> Self {
-        self.joins.push(JoinInfo {
-            table_name: LeftJoinSchema::table_name().to_string(),
-            condition: filter,
-            join_type: JoinType::Left,
-            columns: LeftJoinSchema::get_all_columns(),
-            selected_columns: select_schema.get_selected(),
-        });
-
+    pub fn filter_group<F>(mut self, filter: F) -> Self
+    where
+        F: Filtered + 'static,
+    {
+        self.filters.push(Box::new(GroupFilter {
+            filter: Box::new(filter),
+        }));
         self
     }
 
-    /// Adds an inner join to the query.
-    ///
-    /// This method joins the specified schema table to the current query using an INNER JOIN.
-    /// Only records that have matching values in both tables are returned.
+    /// Adds a raw SQL condition to the WHERE clause, ANDed with the other filters.
     ///
-    /// # Arguments
-    ///
-    /// - `filter`: The join condition specifying how tables should be joined
+    /// Use `?` for each parameter; placeholders are renumbered for Postgres. Parameters
+    /// are bound in the order conditions were added, so a `filter` added before
+    /// `where_raw` binds its values first. The fragment is wrapped in parentheses.
+    /// When `params` isn't empty, running the query fails with
+    /// [`DatabaseError::InvalidValue`] if the number of `?`s differs from it.
     ///
-    /// # Returns
+    /// # Safety
     ///
-    /// The query builder instance for method chaining
+    /// **Warning:** the fragment is embedded unchecked. Never build it from untrusted
+    /// input; pass values through `params` instead.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
     /// use lume::schema::{Schema, ColumnInfo};
-    /// use lume::filter::eq_column;
+    /// use lume::filter::eq_value;
     ///
     /// define_schema! {
     ///     User {
     ///         id: i32 [primary_key()],
     ///         name: String [not_null()],
-    ///     }
-    ///
-    ///     Post {
-    ///         id: i32 [primary_key()],
-    ///         user_id: i32,
-    ///         title: String,
+    ///         age: i32,
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let results = db.query::<User, SelectUser>()
-    ///         .inner_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost { title: true, ..Default::default() })
+    ///     let rows = db.query::<User, SelectUser>()
+    ///         .filter(eq_value(User::name(), "John"))
+    ///         .where_raw("age % ? = 0", vec![Value::Int32(2)])
     ///         .execute()
     ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn inner_join<InnerJoinSchema: Schema + Debug, InnerJoinSchemaSelect: Select + Debug>(
-        mut self,
-        filter: Filter,
-        select_schema: InnerJoinSchemaSelect,
-    ) -> Self {
-        self.joins.push(JoinInfo {
-            table_name: InnerJoinSchema::table_name().to_string(),
-            condition: filter,
-            join_type: JoinType::Inner,
-            columns: InnerJoinSchema::get_all_columns(),
-            selected_columns: select_schema.get_selected(),
-        });
-
+    pub fn where_raw(mut self, sql: &str, params: Vec<Value>) -> Self {
+        self.filters.push(Box::new(SqlFilter {
+            sql: format!("({})", sql),
+            params,
+        }));
         self
     }
 
-    #[cfg(not(feature = "sqlite"))]
-    /// Adds a right join to the query.
-    ///
-    /// This method joins the specified schema table to the current query using a RIGHT JOIN.
-    /// All records from the right table (joined table) are returned, along with matching
-    /// records from the left table (current query).
-    ///
-    /// # Arguments
-    ///
-    /// - `filter`: The join condition specifying how tables should be joined
+    /// Includes soft-deleted rows in the results.
     ///
-    /// # Returns
+    /// Queries on a table declared with `#[soft_delete]` only return rows whose
+    /// `deleted_at` is `NULL`, and joins to such a table skip its deleted rows;
+    /// this drops both conditions. It has no effect on other tables.
     ///
-    /// The query builder instance for method chaining
+    /// The condition applies to everything built from a query, including
+    /// [`count`](Self::count) and the other aggregates. Updates and raw SQL
+    /// still see deleted rows, so a row can be restored by setting its
+    /// `deleted_at` back to `NULL`.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
     /// use lume::schema::{Schema, ColumnInfo};
-    /// use lume::filter::eq_column;
+    /// use lume::filter::is_not_null;
     ///
     /// define_schema! {
+    ///     #[soft_delete]
     ///     User {
     ///         id: i32 [primary_key()],
     ///         name: String [not_null()],
-    ///     }
-    ///
-    ///     Post {
-    ///         id: i32 [primary_key()],
-    ///         user_id: i32,
-    ///         title: String,
+    ///         deleted_at: time::OffsetDateTime,
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let results = db.query::<User, SelectUser>()
-    ///         .right_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost { title: true, ..Default::default() })
+    ///     let deleted = db.query::<User, SelectUser>()
+    ///         .with_deleted()
+    ///         .filter(is_not_null(User::deleted_at()))
     ///         .execute()
     ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn right_join<RightJoinSchema: Schema + Debug, RightJoinSchemaSelect: Select + Debug>(
-        mut self,
-        filter: Filter,
-        select_schema: RightJoinSchemaSelect,
+    pub fn with_deleted(mut self) -> Self {
+        self.with_deleted = true;
+        self
+    }
+
+    /// Adds a limit to the query.
+    ///
+    /// This method adds a LIMIT clause to the SQL query, limiting the number of rows returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum number of rows to return.
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::eq_value;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let query = db.query::<User, SelectUser>().limit(10);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Adds an offset to the query.
+    ///
+    /// This method adds an OFFSET clause to the SQL query, skipping the specified number of rows before starting to return rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The number of rows to skip before starting to return rows.
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let query = db.query::<User, SelectUser>().offset(20);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sorts the results by a column in ascending order.
+    ///
+    /// The column may belong to the main table or to any joined table, and is
+    /// qualified with its table name. Calling this several times sorts by each
+    /// column in turn.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_column;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         created_at: i64,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let rows = db.query::<User, SelectUser>()
+    ///         .inner_join::<Post, SelectPost>(
+    ///             eq_column(User::id(), Post::user_id()),
+    ///             SelectPost::selected().created_at(),
+    ///         )
+    ///         .order_by_desc(Post::created_at())
+    ///         .order_by(User::name())
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn order_by<C>(mut self, column: &Column<C>) -> Self {
+        self.order.push((
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+            SortOrder::Asc,
+        ));
+        self
+    }
+
+    /// Sorts the results by a column in descending order.
+    ///
+    /// See [`order_by`](Self::order_by).
+    pub fn order_by_desc<C>(mut self, column: &Column<C>) -> Self {
+        self.order.push((
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+            SortOrder::Desc,
+        ));
+        self
+    }
+
+    /// Makes the sort order deterministic by appending the main table's primary
+    /// key, ascending, after the [`order_by`](Self::order_by) columns.
+    ///
+    /// Rows that tie on a non-unique sort column can come back in any order, so
+    /// paging through them with `limit`/`offset` may skip or repeat rows. With a
+    /// primary-key tiebreaker every page is cut at the same place. Has no effect
+    /// on a query without `order_by`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         age: i32,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     // ORDER BY User.age ASC, User.id ASC
+    ///     let page = db.query::<User, SelectUser>()
+    ///         .order_by(User::age())
+    ///         .stable_order()
+    ///         .limit(20)
+    ///         .offset(40)
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stable_order(mut self) -> Self {
+        self.stable_order = true;
+        self
+    }
+
+    /// Groups the results by a column with `GROUP BY`.
+    ///
+    /// Call it once per column to group by several columns. The column may
+    /// belong to the main table or to a joined one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Order {
+    ///         id: i32 [primary_key()],
+    ///         customer: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let customers = db.query::<Order, SelectOrder>()
+    ///         .select(SelectOrder::selected().customer())
+    ///         .group_by(Order::customer())
+    ///         .count()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn group_by<C>(mut self, column: &Column<C>) -> Self {
+        self.group_by.push((
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+        ));
+        self
+    }
+
+    /// Adds a raw SQL predicate to the `HAVING` clause, ANDed with any others.
+    ///
+    /// Use it after [`group_by`](Self::group_by) to filter groups on an
+    /// aggregate, such as `COUNT(*) > ?`. Use `?` for each parameter;
+    /// placeholders are renumbered for Postgres, and the values are bound after
+    /// those of the `WHERE` filters. The predicate is wrapped in parentheses.
+    /// Running the query fails with [`DatabaseError::InvalidValue`] if the
+    /// number of `?`s differs from the number of `params`.
+    ///
+    /// # Safety
+    ///
+    /// **Warning:** the predicate is embedded unchecked. Never build it from
+    /// untrusted input; pass values through `params` instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Order {
+    ///         id: i32 [primary_key()],
+    ///         customer: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     // Customers with more than five orders
+    ///     let regulars = db.query::<Order, SelectOrder>()
+    ///         .select(SelectOrder::selected().customer())
+    ///         .group_by(Order::customer())
+    ///         .having_raw("COUNT(*) > ?", vec![5.into()])
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn having_raw(mut self, sql: &str, params: Vec<Value>) -> Self {
+        self.having.push((sql.to_string(), params));
+        self
+    }
+
+    /// Combines this query with `other` using `UNION`, dropping duplicate rows.
+    ///
+    /// Both queries must select the same columns, in the same order. Each side
+    /// keeps its own filters, ordering and limit; the parameters of this query
+    /// are bound first, followed by those of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_value;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         role: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let staff = db.query::<User, SelectUser>()
+    ///         .filter(eq_value(User::role(), "admin"))
+    ///         .union(
+    ///             db.query::<User, SelectUser>()
+    ///                 .filter(eq_value(User::role(), "moderator")),
+    ///         )
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn union(mut self, other: Query<T, S>) -> Self {
+        self.unions.push((UnionType::Distinct, other));
+        self
+    }
+
+    /// Combines this query with `other` using `UNION ALL`, keeping duplicate rows.
+    ///
+    /// See [`union`](Self::union).
+    pub fn union_all(mut self, other: Query<T, S>) -> Self {
+        self.unions.push((UnionType::All, other));
+        self
+    }
+
+    /// Locks the selected rows with `FOR UPDATE`.
+    ///
+    /// Other transactions trying to lock the same rows wait until the lock is
+    /// released. SQLite has no row locks, so this is ignored there with a warning.
+    ///
+    /// Locks last until the end of the transaction, so run the query with
+    /// [`execute_in`](Self::execute_in). With [`execute`](Self::execute) the
+    /// statement runs on its own on a pooled connection and its locks are
+    /// released as soon as it returns.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Job {
+    ///         id: i32 [primary_key()],
+    ///         status: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let mut tx = db.begin().await?;
+    ///     let jobs = db.query::<Job, SelectJob>()
+    ///         .for_update()
+    ///         .skip_locked()
+    ///         .limit(1)
+    ///         .execute_in(&mut tx)
+    ///         .await?;
+    ///     tx.commit().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn for_update(mut self) -> Self {
+        self.lock.get_or_insert(RowLock::Wait);
+        self
+    }
+
+    /// Skips rows that are already locked instead of waiting for them (`FOR UPDATE SKIP LOCKED`).
+    ///
+    /// Lets several workers claim different rows from the same job queue without
+    /// blocking each other. Implies [`for_update`](Self::for_update).
+    pub fn skip_locked(mut self) -> Self {
+        self.lock = Some(RowLock::SkipLocked);
+        self
+    }
+
+    /// Fails immediately if any selected row is already locked (`FOR UPDATE NOWAIT`).
+    ///
+    /// Implies [`for_update`](Self::for_update).
+    pub fn nowait(mut self) -> Self {
+        self.lock = Some(RowLock::NoWait);
+        self
+    }
+
+    /// Specifies which columns to select in the query.
+    ///
+    /// This method accepts a selection schema that determines which columns
+    /// will be included in the SELECT clause of the SQL query.
+    ///
+    /// # Arguments
+    ///
+    /// - `select_schema`: The selection schema specifying which columns to include
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+
+    pub fn select(mut self, select_schema: S) -> Self {
+        self.select = Some(select_schema);
+        self
+    }
+
+    /// Selects exactly the given columns, building the selection struct for you.
+    ///
+    /// Shorthand for [`select`](Self::select) with only these fields set.
+    /// Columns of other tables are skipped with a warning.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///         age: i32,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     // SELECT User.name, User.age FROM User
+    ///     let users = db
+    ///         .query::<User, SelectUser>()
+    ///         .columns(&[User::name(), User::age()])
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn columns(mut self, columns: &[&'static dyn AnyColumn]) -> Self
+    where
+        S: Default,
+    {
+        let mut selection = <S as Default>::default();
+        for column in columns {
+            if column.table_name() != T::table_name() || !selection.select_column(column.name()) {
+                eprintln!(
+                    "Warning: {}.{} is not a column of {}, skipping it",
+                    column.table_name(),
+                    column.name(),
+                    T::table_name()
+                );
+            }
+        }
+        self.select = Some(selection);
+        self
+    }
+
+    /// Replaces the select list with raw SQL expressions.
+    ///
+    /// This is an escape hatch for computed columns the generated `Select` struct
+    /// can't express, such as `LOWER(name) AS lname` or `COUNT(*) OVER ()`.
+    /// It overrides [`select`](Self::select) and any columns selected by joins.
+    /// Results are stored in the row under their column name or alias and are
+    /// read back with [`Row::get_by_name`].
+    ///
+    /// **Warning**: The expressions are inserted into the SQL as-is. They are not
+    /// checked against the schema and must never contain untrusted input.
+    ///
+    /// # Arguments
+    ///
+    /// - `expressions`: The SQL expressions to select
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let rows = db.query::<User, SelectUser>()
+    ///         .select_raw(vec!["COUNT(*) AS c"])
+    ///         .execute()
+    ///         .await?;
+    ///     let count: Option<i64> = rows[0].get_by_name("c");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn select_raw(mut self, expressions: Vec<&str>) -> Self {
+        self.raw_select = expressions.into_iter().map(str::to_string).collect();
+        self
+    }
+
+    /// Adds `COALESCE(column, default)` to the select list, aliased as the column's name.
+    ///
+    /// This is mostly useful after a [`left_join`](Self::left_join), where columns
+    /// of the joined table are `NULL` for unmatched rows. The default is bound as
+    /// a parameter, and the value is read back with [`Row::get`] or
+    /// [`Row::get_by_name`] like any other column. The `COALESCE` replaces the
+    /// plain column in the select list, so the result holds it only once.
+    ///
+    /// # Arguments
+    ///
+    /// - `column`: The column to read
+    /// - `default_value`: The value to use when the column is `NULL`
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_column;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let rows = db.query::<User, SelectUser>()
+    ///         .left_join::<Post, SelectPost>(
+    ///             eq_column(User::id(), Post::user_id()),
+    ///             SelectPost::selected().id(),
+    ///         )
+    ///         .select_coalesce(Post::title(), "untitled")
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn select_coalesce<C, V>(mut self, column: &'static Column<C>, default_value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.coalesce.push((
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+            default_value.into(),
+        ));
+        self
+    }
+
+    /// Adds a column to the select list under another name.
+    ///
+    /// Useful when joined tables share a column name, such as `id`: each copy
+    /// can be selected under its own alias and read back with
+    /// [`Row::get_by_name`], instead of one overwriting the other. The alias
+    /// shouldn't match a column name of the queried schemas, as those are read
+    /// first.
+    ///
+    /// # Arguments
+    ///
+    /// - `column`: The column to select, from the queried table or a joined one
+    /// - `alias`: The name to read the value back with
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_column;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let rows = db.query::<User, SelectUser>()
+    ///         .inner_join::<Post, SelectPost>(
+    ///             eq_column(User::id(), Post::user_id()),
+    ///             SelectPost::selected().title(),
+    ///         )
+    ///         .select_as(User::id(), "author_id")
+    ///         .select_as(Post::id(), "post_id")
+    ///         .execute()
+    ///         .await?;
+    ///     let post_id: Option<i64> = rows[0].get_by_name("post_id");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn select_as<C>(mut self, column: &'static Column<C>, alias: &str) -> Self {
+        self.aliases.push((
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+            alias.to_string(),
+        ));
+        self
+    }
+
+    /// Adds a window function expression to the select list, aliased as `alias`.
+    ///
+    /// The expression is appended after the selected columns, so each row keeps
+    /// its schema columns and gains the windowed value, which is read back with
+    /// [`Row::get_by_name`].
+    ///
+    /// The expression is unchecked SQL: it is inserted as written, so never
+    /// build it from user input.
+    ///
+    /// # Arguments
+    ///
+    /// - `expression`: The windowed expression, e.g. `ROW_NUMBER() OVER (ORDER BY id)`
+    /// - `alias`: The name to read the value back with
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Employee {
+    ///         id: i32 [primary_key()],
+    ///         dept: String [not_null()],
+    ///         salary: i32 [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let rows = db.query::<Employee, SelectEmployee>()
+    ///         .select_window(
+    ///             "ROW_NUMBER() OVER (PARTITION BY dept ORDER BY salary DESC)",
+    ///             "dept_rank",
+    ///         )
+    ///         .execute()
+    ///         .await?;
+    ///     let rank: Option<i64> = rows[0].get_by_name("dept_rank");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn select_window(mut self, expression: &str, alias: &str) -> Self {
+        self.windows
+            .push((expression.to_string(), alias.to_string()));
+        self
+    }
+
+    /// Specifies that the query should select only distinct rows for the given columns.
+    ///
+    /// This method works like [`select`](Self::select), but adds a `DISTINCT` clause to the SQL query,
+    /// ensuring that duplicate rows are removed from the result set.
+    ///
+    /// # Arguments
+    ///
+    /// - `select_schema`: The selection schema specifying which columns to include in the DISTINCT selection.
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     // Selects only unique user names
+    ///     let query = db.query::<User, SelectUser>().select_distinct(SelectUser::selected().name());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn select_distinct(mut self, select_schema: S) -> Self {
+        self.select = Some(select_schema);
+        self.distinct = true;
+        self
+    }
+
+    /// Adds a left join to the query.
+    ///
+    /// This method joins the specified schema table to the current query using a LEFT JOIN.
+    /// All records from the left table (current query) are returned, along with matching
+    /// records from the right table (joined table).
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: The join condition specifying how tables should be joined
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::eq_column;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let results = db.query::<User, SelectUser>()
+    ///         .left_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost { title: true, ..Default::default() })
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn left_join<LeftJoinSchema: Schema + Debug, LeftJoinSchemaSelect: Select + Debug>(
+        mut self,
+        filter: impl Filtered + 'static,
+        select_schema: LeftJoinSchemaSelect,
+    ) -> Self {
+        self.joins.push(JoinInfo {
+            table_name: LeftJoinSchema::table_name().to_string(),
+            soft_delete_column: LeftJoinSchema::soft_delete_column(),
+            condition: Some(Box::new(filter)),
+            join_type: JoinType::Left,
+            columns: LeftJoinSchema::get_all_columns(),
+            selected_columns: join_selection(select_schema.get_selected()),
+            alias: None,
+            prefix_columns: false,
+        });
+
+        self
+    }
+
+    /// Adds an inner join to the query.
+    ///
+    /// This method joins the specified schema table to the current query using an INNER JOIN.
+    /// Only records that have matching values in both tables are returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: The join condition specifying how tables should be joined
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::eq_column;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let results = db.query::<User, SelectUser>()
+    ///         .inner_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost { title: true, ..Default::default() })
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn inner_join<InnerJoinSchema: Schema + Debug, InnerJoinSchemaSelect: Select + Debug>(
+        mut self,
+        filter: impl Filtered + 'static,
+        select_schema: InnerJoinSchemaSelect,
+    ) -> Self {
+        self.joins.push(JoinInfo {
+            table_name: InnerJoinSchema::table_name().to_string(),
+            soft_delete_column: InnerJoinSchema::soft_delete_column(),
+            condition: Some(Box::new(filter)),
+            join_type: JoinType::Inner,
+            columns: InnerJoinSchema::get_all_columns(),
+            selected_columns: join_selection(select_schema.get_selected()),
+            alias: None,
+            prefix_columns: false,
+        });
+
+        self
+    }
+
+    /// Adds an inner join that only narrows the rows, without selecting any
+    /// of the joined table's columns.
+    ///
+    /// Use it when the joined table is only needed in the join condition or in
+    /// [`filter`](Self::filter). A row matching several joined rows is still
+    /// returned once per match, as with any inner join.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::{eq_column, eq_value};
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         published: bool,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     // Users with a published post, selecting only User's columns
+    ///     let authors = db.query::<User, SelectUser>()
+    ///         .inner_join_filter_only::<Post>(eq_column(User::id(), Post::user_id()))
+    ///         .filter(eq_value(Post::published(), true))
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn inner_join_filter_only<InnerJoinSchema: Schema + Debug>(
+        mut self,
+        filter: impl Filtered + 'static,
+    ) -> Self {
+        self.joins.push(JoinInfo {
+            table_name: InnerJoinSchema::table_name().to_string(),
+            soft_delete_column: InnerJoinSchema::soft_delete_column(),
+            condition: Some(Box::new(filter)),
+            join_type: JoinType::Inner,
+            // No columns to read back, so rows don't pick up the joined table's
+            // names from the main table's values
+            columns: Vec::new(),
+            selected_columns: Vec::new(),
+            alias: None,
+            prefix_columns: false,
+        });
+
+        self
+    }
+
+    #[cfg(not(feature = "sqlite"))]
+    /// Adds a right join to the query.
+    ///
+    /// This method joins the specified schema table to the current query using a RIGHT JOIN.
+    /// All records from the right table (joined table) are returned, along with matching
+    /// records from the left table (current query).
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: The join condition specifying how tables should be joined
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::eq_column;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let results = db.query::<User, SelectUser>()
+    ///         .right_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost { title: true, ..Default::default() })
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn right_join<RightJoinSchema: Schema + Debug, RightJoinSchemaSelect: Select + Debug>(
+        mut self,
+        filter: impl Filtered + 'static,
+        select_schema: RightJoinSchemaSelect,
     ) -> Self {
         self.joins.push(JoinInfo {
             table_name: RightJoinSchema::table_name().to_string(),
-            condition: filter,
+            soft_delete_column: RightJoinSchema::soft_delete_column(),
+            condition: Some(Box::new(filter)),
             join_type: JoinType::Right,
             columns: RightJoinSchema::get_all_columns(),
-            selected_columns: select_schema.get_selected(),
+            selected_columns: join_selection(select_schema.get_selected()),
+            alias: None,
+            prefix_columns: false,
+        });
+
+        self
+    }
+
+    #[cfg(feature = "postgres")]
+    /// Adds a full outer join to the query.
+    ///
+    /// This method joins the specified schema table to the current query using a FULL OUTER JOIN.
+    /// All records from both tables are returned, with NULL values for non-matching records.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: The join condition specifying how tables should be joined
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::{database::Database, define_schema, filter::eq_column};
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let results = db
+    ///         .query::<User, SelectUser>()
+    ///         .full_join::<Post, SelectPost>(
+    ///             eq_column(User::id(), Post::user_id()),
+    ///             SelectPost::selected(),
+    ///         )
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn full_join<FullJoinSchema: Schema + Debug, FullJoinSchemaSelect: Select + Debug>(
+        mut self,
+        filter: impl Filtered + 'static,
+        select_schema: FullJoinSchemaSelect,
+    ) -> Self {
+        self.joins.push(JoinInfo {
+            table_name: FullJoinSchema::table_name().to_string(),
+            soft_delete_column: FullJoinSchema::soft_delete_column(),
+            condition: Some(Box::new(filter)),
+            join_type: JoinType::Full,
+            columns: FullJoinSchema::get_all_columns(),
+            selected_columns: join_selection(select_schema.get_selected()),
+            alias: None,
+            prefix_columns: false,
+        });
+
+        self
+    }
+
+    /// Adds a cross join to the query.
+    ///
+    /// This method joins the specified schema table to the current query using a CROSS JOIN.
+    /// This produces a Cartesian product of all records from both tables.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: The join condition (note: cross joins typically don't use conditions)
+    ///
+    /// # Returns
+    ///
+    /// The query builder instance for method chaining
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let results = db.query::<User, SelectUser>()
+    ///         .cross_join::<Post, SelectPost>(SelectPost { title: true, ..Default::default() })
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn cross_join<CrossJoinSchema: Schema + Debug, CrossJoinSchemaSelect: Select + Debug>(
+        mut self,
+        select_schema: CrossJoinSchemaSelect,
+    ) -> Self {
+        self.joins.push(JoinInfo {
+            table_name: CrossJoinSchema::table_name().to_string(),
+            soft_delete_column: CrossJoinSchema::soft_delete_column(),
+            condition: None,
+            join_type: JoinType::Cross,
+            columns: CrossJoinSchema::get_all_columns(),
+            selected_columns: join_selection(select_schema.get_selected()),
+            alias: None,
+            prefix_columns: false,
         });
 
-        self
+        self
+    }
+
+    /// Gives the most recently added join a table alias.
+    ///
+    /// Needed to join a table to itself: reference the aliased side with
+    /// [`Column::as_alias`] in the join condition, filters and ordering. The
+    /// join's selected columns come back named `alias.column`; read them with
+    /// [`Row::get_by_name`]. Has no effect if the query has no joins.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::{eq_column, eq_value};
+    ///
+    /// define_schema! {
+    ///     Employee {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///         manager_id: i32,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let reports = db.query::<Employee, SelectEmployee>()
+    ///         .inner_join::<Employee, SelectEmployee>(
+    ///             eq_column(Employee::manager_id(), &Employee::id().as_alias("mgr")),
+    ///             SelectEmployee::selected().name(),
+    ///         )
+    ///         .join_alias("mgr")
+    ///         .filter(eq_value(&Employee::name().as_alias("mgr"), "Grace"))
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     for row in reports {
+    ///         let manager: Option<String> = row.get_by_name("mgr.name");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn join_alias(mut self, alias: &str) -> Self {
+        if let Some(join) = self.joins.last_mut() {
+            join.alias = Some(alias.to_string());
+        }
+        self
+    }
+
+    /// Selects every column of a joined table, qualified by its table name.
+    ///
+    /// Replaces the selection passed to the join for `J`, so there's no need to
+    /// list each column in its select schema. Has no effect if `J` isn't joined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_column;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let results = db.query::<User, SelectUser>()
+    ///         .inner_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost::selected())
+    ///         .with_all::<Post>()
+    ///         .execute()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_all<J: Schema>(mut self) -> Self {
+        if let Some(join) = self
+            .joins
+            .iter_mut()
+            .rev()
+            .find(|join| join.table_name == J::table_name())
+        {
+            join.selected_columns = join
+                .columns
+                .iter()
+                .map(|column| (join.table_name.clone(), column.name.to_string()))
+                .collect();
+        }
+
+        self
+    }
+
+    /// Executes the query and returns the results.
+    ///
+    /// This method builds and executes the SQL query, returning type-safe
+    /// row objects that can be used to access column values.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Row<T>>)`: A vector of type-safe row objects
+    /// - `Err(DatabaseError)`: If there was an error executing the query
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::Filter;
+    /// use lume::schema::{Schema, ColumnInfo};
+    /// use lume::filter::eq_value;
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let users = db.query::<User, SelectUser>()
+    ///         .filter(eq_value(User::name(), Value::String("John".to_string())))
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     for user in users {
+    ///         let name: Option<String> = user.get(User::name());
+    ///         println!("User: {:?}", name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute(mut self) -> Result<Vec<Row<T>>, DatabaseError> {
+        let (sql, params) = self.build_sql(Vec::new())?;
+
+        // Locked reads must always hit the database
+        let result_cache = match (&self.result_cache, self.cache_ttl, self.lock) {
+            (Some(cache), Some(ttl), None) => {
+                Some((cache, ResultCache::key::<T>(&sql, &params), ttl))
+            }
+            _ => None,
+        };
+
+        if let Some((cache, key, _)) = &result_cache
+            && let Some(rows) = cache.get::<T>(key)
+        {
+            return Ok(rows);
+        }
+
+        let data = self.fetch_rows(&sql, &params).await?;
+        let rows = self.read_rows(data);
+
+        if let Some((cache, key, ttl)) = result_cache {
+            cache.insert(key, &rows, ttl);
+        }
+
+        Ok(rows)
+    }
+
+    /// Executes the query and pairs each row with its row of the joined table `J`.
+    ///
+    /// `J` must have been joined without [`join_alias`](Self::join_alias).
+    /// Its selected columns are named `table.column` in the result and moved
+    /// into the second row, so columns both tables share, such as `id`, don't
+    /// clash. Like [`Row::joined`], the joined row is `None` when all of its
+    /// selected columns are `NULL`, as for an unmatched row of a left join.
+    ///
+    /// Fails with [`DatabaseError::QueryError`] if `J` isn't joined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_column;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Post {
+    ///         id: i32 [primary_key()],
+    ///         user_id: i32,
+    ///         title: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let pairs = db.query::<User, SelectUser>()
+    ///         .left_join::<Post, SelectPost>(eq_column(User::id(), Post::user_id()), SelectPost::selected())
+    ///         .with_all::<Post>()
+    ///         .execute_joined::<Post>()
+    ///         .await?;
+    ///
+    ///     for (user, post) in pairs {
+    ///         let title = post.and_then(|post| post.get(Post::title()));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_joined<J: Schema + Debug>(
+        mut self,
+    ) -> Result<Vec<(Row<T>, Option<Row<J>>)>, DatabaseError> {
+        let Some(join) = self
+            .joins
+            .iter_mut()
+            .find(|join| join.table_name == J::table_name() && join.alias.is_none())
+        else {
+            return Err(DatabaseError::QueryError(format!(
+                "Cannot split rows on {}: it isn't joined",
+                J::table_name()
+            )));
+        };
+        join.prefix_columns = true;
+
+        let prefix = format!("{}.", J::table_name());
+        let rows = self.execute().await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let joined = row.joined::<J>();
+                let main: HashMap<String, Value> = row
+                    .into_data()
+                    .into_iter()
+                    .filter(|(name, _)| !name.starts_with(&prefix))
+                    .collect();
+                (Row::from_data(main), joined)
+            })
+            .collect())
+    }
+
+    /// Narrows the selection to a single column of the main or a joined table.
+    pub(crate) fn select_column<C>(mut self, column: &'static Column<C>) -> Self {
+        let dialect = get_dialect();
+        self.select = None;
+        self.coalesce.clear();
+        self.aliases.clear();
+        self.windows.clear();
+        self.raw_select = vec![format!(
+            "{}.{}",
+            dialect.quote_qualified(column.__internal_table_name()),
+            dialect.quote_identifier(column.__internal_name())
+        )];
+        self
+    }
+
+    /// Executes the query selecting only `column`, and collects its values.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if a value is `NULL` or can't
+    /// be converted to `C`; use [`pluck_opt`](Self::pluck_opt) for nullable columns.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::gte;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///         age: i32,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let ids: Vec<i32> = db.query::<User, SelectUser>()
+    ///         .filter(gte(User::age(), 18))
+    ///         .pluck(User::id())
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn pluck<C>(self, column: &'static Column<C>) -> Result<Vec<C>, DatabaseError>
+    where
+        C: TryFrom<Value>,
+    {
+        self.pluck_opt(column)
+            .await?
+            .into_iter()
+            .map(|value| {
+                value.ok_or_else(|| {
+                    DatabaseError::InvalidValue(format!(
+                        "Cannot pluck {}.{}: value is NULL or has an unexpected type",
+                        column.__internal_table_name(),
+                        column.__internal_name()
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Executes the query selecting only `column`, and collects its values,
+    /// with `None` for `NULL`s.
+    ///
+    /// See [`pluck`](Self::pluck).
+    pub async fn pluck_opt<C>(
+        self,
+        column: &'static Column<C>,
+    ) -> Result<Vec<Option<C>>, DatabaseError>
+    where
+        C: TryFrom<Value>,
+    {
+        let rows = self.select_column(column).execute().await?;
+        Ok(rows.iter().map(|row| row.get(column)).collect())
+    }
+
+    /// Groups the matching rows by `column` and counts each group, as in
+    /// `SELECT column, COUNT(*) ... GROUP BY column`.
+    ///
+    /// Filters and joins apply as usual, so the counts cover only the matching
+    /// rows. Groups come back in the database's order unless the query is
+    /// ordered by `column`; ordering by any other column, and
+    /// [`stable_order`](Self::stable_order), is dropped, since the rows are
+    /// grouped and those columns aren't part of the result.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if a group's value is `NULL`
+    /// or can't be converted to `C`; filter nullable columns with
+    /// [`is_not_null`](crate::filter::is_not_null) first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::gte;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Order {
+    ///         id: i32 [primary_key()],
+    ///         status: String [not_null()],
+    ///         total: i32,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let per_status: Vec<(String, i64)> = db.query::<Order, SelectOrder>()
+    ///         .filter(gte(Order::total(), 100))
+    ///         .count_by(Order::status())
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn count_by<C>(
+        self,
+        column: &'static Column<C>,
+    ) -> Result<Vec<(C, i64)>, DatabaseError>
+    where
+        C: TryFrom<Value>,
+    {
+        let rows = self.count_by_query(column).execute().await?;
+        rows.iter()
+            .map(|row| {
+                let group = row.get(column).ok_or_else(|| {
+                    DatabaseError::InvalidValue(format!(
+                        "Cannot count by {}.{}: value is NULL or has an unexpected type",
+                        column.__internal_table_name(),
+                        column.__internal_name()
+                    ))
+                })?;
+                let count = row.get_by_name::<i64>(COUNT_BY_ALIAS).unwrap_or_default();
+                Ok((group, count))
+            })
+            .collect()
+    }
+
+    /// Executes the query and applies `f` to each row, collecting the results.
+    ///
+    /// Equivalent to [`execute`](Self::execute) followed by mapping over the rows,
+    /// without keeping the rows around afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let names: Vec<String> = db.query::<User, SelectUser>()
+    ///         .map(|row| row.get(User::name()).unwrap_or_default())
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn map<R>(self, f: impl Fn(&Row<T>) -> R) -> Result<Vec<R>, DatabaseError> {
+        let rows = self.execute().await?;
+        Ok(rows.iter().map(f).collect())
+    }
+
+    /// Executes the query inside `tx` and returns the matching rows.
+    ///
+    /// The query sees the transaction's uncommitted writes, and row locks from
+    /// [`for_update`](Self::for_update) are held until the transaction commits
+    /// or rolls back. The query's retry policy, timeout and result cache aren't
+    /// used, as one failed statement can't be retried on its own inside a
+    /// transaction.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_value;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Job {
+    ///         id: i32 [primary_key()],
+    ///         status: String [not_null()],
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let mut tx = db.begin().await?;
+    ///     let jobs = db.query::<Job, SelectJob>()
+    ///         .filter(eq_value(Job::status(), "pending"))
+    ///         .for_update()
+    ///         .skip_locked()
+    ///         .limit(1)
+    ///         .execute_in(&mut tx)
+    ///         .await?;
+    ///     // ... work on the claimed job while it stays locked
+    ///     tx.commit().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_in(mut self, tx: &mut Transaction) -> Result<Vec<Row<T>>, DatabaseError> {
+        let (sql, params) = self.build_sql(Vec::new())?;
+
+        let mut query = sqlx::query(&sql);
+        for v in params {
+            query = bind_value(query, v)?;
+        }
+        let data = query
+            .fetch_all(&mut *tx.tx)
+            .await
+            .map_err(statement_error)?;
+
+        Ok(self.read_rows(data))
+    }
+
+    /// Reads fetched rows, including the columns of the query's joins.
+    fn read_rows(&self, data: Vec<DbRow>) -> Vec<Row<T>> {
+        #[cfg(feature = "mysql")]
+        let rows = Row::from_mysql_row(data, Some(&self.joins));
+
+        #[cfg(feature = "postgres")]
+        let rows = Row::from_postgres_row(data, Some(&self.joins));
+
+        #[cfg(feature = "sqlite")]
+        let rows = Row::from_sqlite_row(data, Some(&self.joins));
+
+        rows
+    }
+
+    /// Runs `sql` with `params` on a pooled connection, under the query's retry
+    /// policy and timeout.
+    async fn fetch_rows(&self, sql: &str, params: &[Value]) -> Result<Vec<DbRow>, DatabaseError> {
+        let policy = self.retry.unwrap_or(RetryPolicy::new(1));
+        policy
+            .run(|| async {
+                let mut conn = self
+                    .conn
+                    .acquire()
+                    .await
+                    .map_err(DatabaseError::ConnectionError)?;
+
+                let mut query = sqlx::query(sql);
+                for v in params.iter().cloned() {
+                    query = bind_value(query, v)?;
+                }
+
+                timeout::start(&mut conn, self.timeout).await?;
+                let result =
+                    timeout::run(self.timeout, query.fetch_all(&mut *conn), statement_error).await;
+                timeout::finish(&mut conn, self.timeout, result).await
+            })
+            .await
+    }
+
+    /// Runs `sql` like [`fetch_rows`](Self::fetch_rows) and decodes the first
+    /// column of its first row, or returns `None` if there are no rows.
+    async fn fetch_one_scalar<V>(
+        &self,
+        sql: &str,
+        params: &[Value],
+    ) -> Result<Option<V>, DatabaseError>
+    where
+        V: for<'r> sqlx::Decode<'r, Db> + sqlx::Type<Db>,
+    {
+        let rows = self.fetch_rows(sql, params).await?;
+        rows.first()
+            .map(|row| sqlx::Row::try_get::<V, _>(row, 0))
+            .transpose()
+            .map_err(|e| DatabaseError::QueryError(e.to_string()))
+    }
+
+    /// Builds the `SELECT` statement and its bind parameters.
+    ///
+    /// `params` holds values already bound by raw select expressions, which
+    /// come before everything else in the statement.
+    ///
+    /// Takes the selection and filters out of the query, so it can only be
+    /// called once.
+    pub(crate) fn build_sql(
+        &mut self,
+        mut params: Vec<Value>,
+    ) -> Result<(String, Vec<Value>), DatabaseError> {
+        let mut sql = get_starting_sql(StartingSql::Select, T::table_name());
+
+        if self.distinct {
+            sql.push_str("DISTINCT ");
+        }
+
+        let mut coalesce = Self::coalesce_sql(&self.coalesce, &mut params);
+        coalesce.extend(Self::aliases_sql(&self.aliases));
+        coalesce.extend(Self::windows_sql(&self.windows));
+
+        let sql = if self.raw_select.is_empty() {
+            Self::select_sql(
+                sql,
+                self.select.take(),
+                T::table_name(),
+                &self.joins,
+                &self.coalesce,
+                &coalesce,
+            )
+        } else {
+            let expressions = [std::mem::take(&mut self.raw_select), coalesce].concat();
+            Self::raw_select_sql(sql, &expressions, T::table_name())
+        };
+        let sql = Self::joins_sql(sql, &self.joins, self.with_deleted, &mut params)?;
+        if !self.with_deleted
+            && let Some(column) = T::soft_delete_column()
+        {
+            self.filters.push(Box::new(Filter {
+                column_one: (T::table_name().to_string(), column.to_string()),
+                value: Some(Value::Null),
+                column_two: None,
+                filter_type: FilterType::Eq,
+                enum_type: None,
+            }));
+        }
+        let sql = Self::filter_sql(sql, std::mem::take(&mut self.filters), &mut params)?;
+        let sql = Self::group_sql(sql, &self.group_by, T::table_name(), &self.joins)?;
+        let sql = Self::having_sql(sql, &self.having, &mut params)?;
+        if self.stable_order {
+            Self::primary_key_tiebreak(&mut self.order);
+        }
+        let mut sql = Self::order_sql(sql, &self.order, T::table_name(), &self.joins)?;
+
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+
+        if let Some(offset) = self.offset {
+            if self.limit.is_none() {
+                sql.push_str(" LIMIT 18446744073709551615");
+            }
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        let sql = Self::lock_sql(sql, self.lock);
+        if self.unions.is_empty() {
+            return Ok((sql, params));
+        }
+
+        let dialect = get_dialect();
+        let mut sql = dialect.union_operand_sql(&sql);
+        for (union_type, mut other) in std::mem::take(&mut self.unions) {
+            let (other_sql, other_params) = other.build_sql(params)?;
+            params = other_params;
+
+            sql.push_str(match union_type {
+                UnionType::Distinct => " UNION ",
+                UnionType::All => " UNION ALL ",
+            });
+            sql.push_str(&dialect.union_operand_sql(&other_sql));
+        }
+
+        Ok((sql, params))
+    }
+
+    /// Turns the query into the grouped count run by [`count_by`](Self::count_by).
+    ///
+    /// Only ordering by the grouped column survives: any other `ORDER BY`
+    /// column, including the [`stable_order`](Self::stable_order) primary key,
+    /// isn't in the `GROUP BY`, which Postgres rejects.
+    pub(crate) fn count_by_query<C>(mut self, column: &'static Column<C>) -> Self {
+        let group = (
+            column.__internal_table_name().to_string(),
+            column.__internal_name().to_string(),
+        );
+        self.order
+            .retain(|(table, name, _)| (table, name) == (&group.0, &group.1));
+        self.stable_order = false;
+        self.group_by = vec![group];
+
+        let mut query = self.select_column(column);
+        query.raw_select.push(format!(
+            "COUNT(*) AS {}",
+            get_dialect().quote_identifier(COUNT_BY_ALIAS)
+        ));
+        query
+    }
+
+    /// Builds the `COUNT(*)` statement used by [`count`](Self::count).
+    ///
+    /// Grouped, distinct, limited and combined queries are wrapped in a subquery, so the
+    /// count is of the rows the query would return rather than of the rows in
+    /// the table.
+    pub(crate) fn count_sql(&mut self) -> Result<(String, Vec<Value>), DatabaseError> {
+        let wrap = !self.group_by.is_empty()
+            || !self.having.is_empty()
+            || self.distinct
+            || self.limit.is_some()
+            || self.offset.is_some()
+            || !self.unions.is_empty();
+
+        self.lock = None;
+        if !wrap {
+            self.raw_select = vec!["COUNT(*)".to_string()];
+            self.coalesce.clear();
+            self.aliases.clear();
+            self.windows.clear();
+            self.order.clear();
+            self.stable_order = false;
+        } else if !self.distinct && self.unions.is_empty() {
+            // Only the number of rows matters, not their columns
+            self.raw_select = vec!["1".to_string()];
+            self.coalesce.clear();
+            self.aliases.clear();
+            self.windows.clear();
+        }
+
+        let (sql, params) = self.build_sql(Vec::new())?;
+        if wrap {
+            Ok((format!("SELECT COUNT(*) FROM ({}) sub", sql), params))
+        } else {
+            Ok((sql, params))
+        }
+    }
+
+    /// Builds the statement used by [`count_distinct_many`](Self::count_distinct_many).
+    ///
+    /// Dialects without a multi-column `COUNT(DISTINCT ...)` count the rows of
+    /// a `SELECT DISTINCT` subquery instead. Grouped, limited and combined
+    /// queries are wrapped in a subquery first, so only the combinations among
+    /// the rows the query would return are counted.
+    pub(crate) fn count_distinct_many_sql(
+        &mut self,
+        columns: &[&'static dyn AnyColumn],
+    ) -> Result<(String, Vec<Value>), DatabaseError> {
+        if columns.is_empty() {
+            return Err(DatabaseError::QueryError(
+                "count_distinct_many needs at least one column".to_string(),
+            ));
+        }
+
+        let dialect = get_dialect();
+        let columns: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                format!(
+                    "{}.{}",
+                    dialect.quote_qualified(column.table_name()),
+                    dialect.quote_identifier(column.name())
+                )
+            })
+            .collect();
+
+        let wrap = !self.group_by.is_empty()
+            || !self.having.is_empty()
+            || self.limit.is_some()
+            || self.offset.is_some()
+            || !self.unions.is_empty();
+
+        self.coalesce.clear();
+        self.aliases.clear();
+        self.windows.clear();
+        self.lock = None;
+
+        if !wrap {
+            self.order.clear();
+            self.stable_order = false;
+
+            return match dialect.count_distinct_sql(&columns) {
+                Some(count) => {
+                    self.raw_select = vec![count];
+                    self.distinct = false;
+                    self.build_sql(Vec::new())
+                }
+                None => {
+                    self.raw_select = columns;
+                    self.distinct = true;
+                    let (sql, params) = self.build_sql(Vec::new())?;
+                    Ok((format!("SELECT COUNT(*) FROM ({}) sub", sql), params))
+                }
+            };
+        }
+
+        // The query picks the rows, so its columns are selected under aliases
+        // and the distinct combinations are counted outside it
+        let aliases: Vec<String> = (0..columns.len())
+            .map(|i| dialect.quote_identifier(&format!("lume_distinct_{}", i)))
+            .collect();
+        let selected: Vec<String> = columns
+            .iter()
+            .zip(&aliases)
+            .map(|(column, alias)| format!("{} AS {}", column, alias))
+            .collect();
+        for (_, other) in &mut self.unions {
+            other.raw_select = selected.clone();
+            other.coalesce.clear();
+            other.aliases.clear();
+            other.windows.clear();
+        }
+        self.raw_select = selected;
+
+        let (sql, params) = self.build_sql(Vec::new())?;
+        let counted: Vec<String> = aliases
+            .iter()
+            .map(|alias| format!("sub.{}", alias))
+            .collect();
+        let sql = match dialect.count_distinct_sql(&counted) {
+            Some(count) => format!("SELECT {} FROM ({}) sub", count, sql),
+            None => format!(
+                "SELECT COUNT(*) FROM (SELECT DISTINCT {} FROM ({}) sub) counted",
+                counted.join(", "),
+                sql
+            ),
+        };
+        Ok((sql, params))
     }
 
-    #[cfg(feature = "postgres")]
-    /// Adds a full outer join to the query.
-    ///
-    /// This method joins the specified schema table to the current query using a FULL OUTER JOIN.
-    /// All records from both tables are returned, with NULL values for non-matching records.
-    ///
-    /// # Arguments
-    ///
-    /// - `filter`: The join condition specifying how tables should be joined
+    /// Builds the aggregate statement used by [`string_agg`](Self::string_agg).
+    pub(crate) fn string_agg_sql<C>(
+        &mut self,
+        column: &'static Column<C>,
+        separator: &str,
+    ) -> Result<(String, Vec<Value>), DatabaseError> {
+        let dialect = get_dialect();
+        let column = format!(
+            "{}.{}",
+            dialect.quote_qualified(column.__internal_table_name()),
+            dialect.quote_identifier(column.__internal_name())
+        );
+
+        let mut params = Vec::new();
+        self.raw_select = vec![dialect.string_agg_sql(&column, separator, &mut params)];
+        self.coalesce.clear();
+        self.aliases.clear();
+        self.windows.clear();
+        self.distinct = false;
+        self.lock = None;
+
+        self.build_sql(params)
+    }
+
+    /// Joins a column's values into one string, separated by `separator`.
     ///
-    /// # Returns
+    /// Uses `GROUP_CONCAT` on MySQL and SQLite and `STRING_AGG` on Postgres.
+    /// Filters, joins and [`group_by`](Self::group_by) apply as usual; when
+    /// grouped, the first group's string is returned, so pair it with a filter
+    /// or [`order_by`](Self::order_by) to pick the group.
     ///
-    /// The query builder instance for method chaining
+    /// Returns `None` when no rows match.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use lume::{database::Database, define_schema, filter::eq_column};
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_value;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
     /// define_schema! {
-    ///     User {
+    ///     Tag {
     ///         id: i32 [primary_key()],
+    ///         post_id: i32 [not_null()],
     ///         name: String [not_null()],
     ///     }
-    ///
-    ///     Post {
-    ///         id: i32 [primary_key()],
-    ///         user_id: i32,
-    ///         title: String,
-    ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let results = db
-    ///         .query::<User, SelectUser>()
-    ///         .full_join::<Post, SelectPost>(
-    ///             eq_column(User::id(), Post::user_id()),
-    ///             SelectPost::selected(),
-    ///         )
-    ///         .execute()
+    ///     let tags = db.query::<Tag, SelectTag>()
+    ///         .filter(eq_value(Tag::post_id(), 1))
+    ///         .string_agg(Tag::name(), ", ")
     ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn full_join<FullJoinSchema: Schema + Debug, FullJoinSchemaSelect: Select + Debug>(
+    pub async fn string_agg<C>(
         mut self,
-        filter: Filter,
-        select_schema: FullJoinSchemaSelect,
-    ) -> Self {
-        self.joins.push(JoinInfo {
-            table_name: FullJoinSchema::table_name().to_string(),
-            condition: filter,
-            join_type: JoinType::Full,
-            columns: FullJoinSchema::get_all_columns(),
-            selected_columns: select_schema.get_selected(),
-        });
+        column: &'static Column<C>,
+        separator: &str,
+    ) -> Result<Option<String>, DatabaseError> {
+        let (sql, params) = self.string_agg_sql(column, separator)?;
 
-        self
+        let value = self
+            .fetch_one_scalar::<Option<String>>(&sql, &params)
+            .await?;
+        Ok(value.flatten())
     }
 
-    /// Adds a cross join to the query.
-    ///
-    /// This method joins the specified schema table to the current query using a CROSS JOIN.
-    /// This produces a Cartesian product of all records from both tables.
-    ///
-    /// # Arguments
-    ///
-    /// - `filter`: The join condition (note: cross joins typically don't use conditions)
+    /// Counts the rows the query would return.
     ///
-    /// # Returns
-    ///
-    /// The query builder instance for method chaining
+    /// With [`group_by`](Self::group_by) this is the number of groups, not the
+    /// number of rows in the table.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
+    /// use lume::filter::eq_value;
     /// use lume::schema::{Schema, ColumnInfo};
     ///
     /// define_schema! {
     ///     User {
     ///         id: i32 [primary_key()],
     ///         name: String [not_null()],
+    ///         age: i32,
     ///     }
+    /// }
     ///
-    ///     Post {
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let adults = db.query::<User, SelectUser>()
+    ///         .filter(eq_value(User::age(), 18))
+    ///         .count()
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn count(mut self) -> Result<i64, DatabaseError> {
+        let (sql, params) = self.count_sql()?;
+
+        let count = self.fetch_one_scalar::<i64>(&sql, &params).await?;
+        Ok(count.unwrap_or_default())
+    }
+
+    /// Counts the distinct combinations of `columns` among the matching rows.
+    ///
+    /// Uses `COUNT(DISTINCT a, b)` on MySQL and `COUNT(DISTINCT (a, b))` on
+    /// Postgres. SQLite's `COUNT(DISTINCT ...)` takes a single argument, so
+    /// there the rows of a `SELECT DISTINCT a, b` subquery are counted.
+    /// Filters and joins apply as usual; with a limit, offset, grouping or
+    /// union, only the combinations among the rows the query would return are
+    /// counted.
+    ///
+    /// `NULL`s follow the database: MySQL skips combinations with a `NULL`
+    /// column, while Postgres and SQLite count them.
+    ///
+    /// Fails with [`DatabaseError::QueryError`] if `columns` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_value;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     Visit {
     ///         id: i32 [primary_key()],
-    ///         user_id: i32,
-    ///         title: String,
+    ///         user_id: i32 [not_null()],
+    ///         page: String [not_null()],
+    ///         bot: bool [default_value(false)],
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let results = db.query::<User, SelectUser>()
-    ///         .cross_join::<Post, SelectPost>(SelectPost { title: true, ..Default::default() })
-    ///         .execute()
+    ///     let unique_views = db.query::<Visit, SelectVisit>()
+    ///         .filter(eq_value(Visit::bot(), false))
+    ///         .count_distinct_many(&[Visit::user_id(), Visit::page()])
     ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn cross_join<CrossJoinSchema: Schema + Debug, CrossJoinSchemaSelect: Select + Debug>(
+    pub async fn count_distinct_many(
         mut self,
-        select_schema: CrossJoinSchemaSelect,
-    ) -> Self {
-        self.joins.push(JoinInfo {
-            table_name: CrossJoinSchema::table_name().to_string(),
-            condition: Filter::default(),
-            join_type: JoinType::Cross,
-            columns: CrossJoinSchema::get_all_columns(),
-            selected_columns: select_schema.get_selected(),
-        });
+        columns: &[&'static dyn AnyColumn],
+    ) -> Result<i64, DatabaseError> {
+        let (sql, params) = self.count_distinct_many_sql(columns)?;
 
-        self
+        let count = self.fetch_one_scalar::<i64>(&sql, &params).await?;
+        Ok(count.unwrap_or_default())
     }
 
-    /// Executes the query and returns the results.
-    ///
-    /// This method builds and executes the SQL query, returning type-safe
-    /// row objects that can be used to access column values.
+    /// Returns the database's plan for the query, one line per row of the
+    /// `EXPLAIN` output.
     ///
-    /// # Returns
+    /// With `analyze`, the query is run and the plan includes actual row
+    /// counts and timings (`EXPLAIN ANALYZE`, MySQL 8.0.18+ and Postgres).
+    /// SQLite has no such mode, so there `analyze` is ignored and the plan comes
+    /// from `EXPLAIN QUERY PLAN`. Without `analyze`, MySQL returns the
+    /// `FORMAT=TREE` plan.
     ///
-    /// - `Ok(Vec<Row<T>>)`: A vector of type-safe row objects
-    /// - `Err(DatabaseError)`: If there was an error executing the query
+    /// The format of the plan is the database's own and differs between
+    /// backends and versions; it is meant for reading, not parsing.
     ///
     /// # Example
     ///
     /// ```no_run
     /// use lume::define_schema;
     /// use lume::database::Database;
-    /// use lume::filter::Filter;
-    /// use lume::schema::{Schema, ColumnInfo};
     /// use lume::filter::eq_value;
+    /// use lume::schema::{Schema, ColumnInfo};
     ///
     /// define_schema! {
     ///     User {
     ///         id: i32 [primary_key()],
-    ///         name: String [not_null()],
+    ///         age: i32 [indexed()],
     ///     }
     /// }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
     ///     let db = Database::connect("mysql://...").await?;
-    ///     let users = db.query::<User, SelectUser>()
-    ///         .filter(eq_value(User::name(), Value::String("John".to_string())))
-    ///         .execute()
+    ///     let plan = db.query::<User, SelectUser>()
+    ///         .filter(eq_value(User::age(), 18))
+    ///         .explain(false)
     ///         .await?;
-    ///
-    ///     for user in users {
-    ///         let name: Option<String> = user.get(User::name());
-    ///         println!("User: {:?}", name);
-    ///     }
+    ///     println!("{}", plan);
     ///     Ok(())
     /// }
     /// ```
-    pub async fn execute(self) -> Result<Vec<Row<T>>, DatabaseError> {
-        let mut sql = get_starting_sql(StartingSql::Select, T::table_name());
-
-        if self.distinct {
-            sql.push_str(" DISTINCT ");
-        }
-
-        let sql = Self::select_sql(sql, self.select, T::table_name(), &self.joins);
-        let sql = Self::joins_sql(sql, &self.joins);
-        let mut params: Vec<Value> = Vec::new();
-        let mut sql = Self::filter_sql(sql, self.filters, &mut params);
-
-        if let Some(limit) = self.limit {
-            sql.push_str(&format!(" LIMIT {}", limit));
-        }
-
-        if let Some(offset) = self.offset {
-            if self.limit.is_none() {
-                sql.push_str(" LIMIT 18446744073709551615");
-            }
-            sql.push_str(&format!(" OFFSET {}", offset));
-        }
+    pub async fn explain(mut self, analyze: bool) -> Result<String, DatabaseError> {
+        let (sql, params) = self.build_sql(Vec::new())?;
+        let sql = get_dialect().explain_sql(&sql, analyze);
 
-        let mut conn = self
-            .conn
-            .acquire()
-            .await
-            .map_err(DatabaseError::ConnectionError)?;
+        let rows = self.fetch_rows(&sql, &params).await?;
 
-        let mut query = sqlx::query(&sql);
-        for v in params {
-            query = bind_value(query, v);
-        }
+        // SQLite returns (id, parent, notused, detail), the others one text column
+        #[cfg(feature = "sqlite")]
+        let column = "detail";
+        #[cfg(not(feature = "sqlite"))]
+        let column = 0;
 
-        let data = query
-            .fetch_all(&mut *conn)
-            .await
+        let lines = rows
+            .iter()
+            .map(|row| sqlx::Row::try_get::<String, _>(row, column))
+            .collect::<Result<Vec<_>, _>>()
             .map_err(|e| DatabaseError::QueryError(e.to_string()))?;
 
-        #[cfg(feature = "mysql")]
-        let rows = Row::from_mysql_row(data, Some(&self.joins));
-
-        #[cfg(feature = "postgres")]
-        let rows = Row::from_postgres_row(data, Some(&self.joins));
-
-        #[cfg(feature = "sqlite")]
-        let rows = Row::from_sqlite_row(data, Some(&self.joins));
-
-        Ok(rows)
+        Ok(lines.join("\n"))
     }
 
     pub(crate) fn select_sql(
@@ -776,31 +2837,156 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
         select: Option<S>,
         table_name: &str,
         joins: &Vec<JoinInfo>,
+        coalesced: &[(String, String, Value)],
+        coalesce: &[String],
     ) -> String {
+        // A coalesced column is selected only through its COALESCE, so the
+        // result doesn't hold two columns of the same name
+        let is_coalesced =
+            |table: &str, column: &str| coalesced.iter().any(|(t, c, _)| t == table && c == column);
+
+        let dialect = get_dialect();
+        let mut columns: Vec<String> = Vec::new();
         if let Some(selection) = select {
-            sql.push_str(&selection.get_selected().join(", "));
+            columns.extend(
+                selection
+                    .get_selected()
+                    .into_iter()
+                    .filter(|column| {
+                        column
+                            .rsplit_once('.')
+                            .is_none_or(|(table, column)| !is_coalesced(table, column))
+                    })
+                    .map(|column| dialect.quote_column_ref(column)),
+            );
+        } else if coalesced.iter().any(|(table, _, _)| table == table_name) {
+            columns.extend(
+                T::get_all_columns()
+                    .into_iter()
+                    .filter(|column| !is_coalesced(table_name, column.name))
+                    .map(|column| {
+                        format!(
+                            "{}.{}",
+                            dialect.quote_qualified(table_name),
+                            dialect.quote_identifier(column.name)
+                        )
+                    }),
+            );
         } else {
             // Default to the base table only (avoid pulling join columns twice)
-            let dialect = get_dialect();
-            sql.push_str(&format!("{}.*", dialect.quote_identifier(table_name)));
+            columns.push(format!("{}.*", dialect.quote_qualified(table_name)));
         }
 
-        if !joins.is_empty() {
-            for join in joins {
-                for column in &join.selected_columns {
-                    sql.push_str(&format!(", {}", column));
+        for join in joins {
+            for (table, column) in &join.selected_columns {
+                if join.alias.is_none() && is_coalesced(table, column) {
+                    continue;
                 }
+
+                let column_ref = format!(
+                    "{}.{}",
+                    dialect.quote_qualified(table),
+                    dialect.quote_identifier(column)
+                );
+
+                columns.push(match &join.alias {
+                    // Name aliased columns `alias.column`, so they don't collide
+                    // with the other side of a self-join
+                    Some(alias) => format!(
+                        "{}.{} AS {}",
+                        dialect.quote_identifier(alias),
+                        dialect.quote_identifier(column),
+                        dialect.quote_identifier(&format!("{}.{}", alias, column))
+                    ),
+                    None if join.prefix_columns => format!(
+                        "{} AS {}",
+                        column_ref,
+                        dialect.quote_identifier(&format!("{}.{}", join.table_name, column))
+                    ),
+                    None => column_ref,
+                });
             }
         }
 
-        sql.push_str(format!(" FROM {}", get_dialect().quote_identifier(table_name)).as_str());
+        columns.extend(coalesce.iter().cloned());
+        sql.push_str(&columns.join(", "));
+
+        sql.push_str(format!(" FROM {}", get_dialect().quote_qualified(table_name)).as_str());
         sql
     }
 
-    pub(crate) fn joins_sql(mut sql: String, joins: &Vec<JoinInfo>) -> String {
-        if joins.is_empty() {
-            return sql;
-        }
+    /// Renders each `select_as` entry as `table.column AS alias`.
+    pub(crate) fn aliases_sql(aliases: &[(String, String, String)]) -> Vec<String> {
+        let dialect = get_dialect();
+        aliases
+            .iter()
+            .map(|(table, column, alias)| {
+                format!(
+                    "{}.{} AS {}",
+                    dialect.quote_qualified(table),
+                    dialect.quote_identifier(column),
+                    dialect.quote_identifier(alias)
+                )
+            })
+            .collect()
+    }
+
+    /// Renders each `select_window` entry as `expression AS alias`.
+    pub(crate) fn windows_sql(windows: &[(String, String)]) -> Vec<String> {
+        let dialect = get_dialect();
+        windows
+            .iter()
+            .map(|(expression, alias)| {
+                format!("{} AS {}", expression, dialect.quote_identifier(alias))
+            })
+            .collect()
+    }
+
+    /// Renders each `select_coalesce` entry as `COALESCE(table.column, ?) AS column`,
+    /// pushing the defaults onto `params` so they bind ahead of any filter values.
+    pub(crate) fn coalesce_sql(
+        coalesce: &[(String, String, Value)],
+        params: &mut Vec<Value>,
+    ) -> Vec<String> {
+        let dialect = get_dialect();
+
+        coalesce
+            .iter()
+            .map(|(table, column, default_value)| {
+                let placeholder = dialect.placeholder(params.len());
+                params.push(default_value.clone());
+                format!(
+                    "COALESCE({}.{}, {}) AS {}",
+                    dialect.quote_qualified(table),
+                    dialect.quote_identifier(column),
+                    placeholder,
+                    dialect.quote_identifier(column)
+                )
+            })
+            .collect()
+    }
+
+    pub(crate) fn raw_select_sql(
+        mut sql: String,
+        expressions: &[String],
+        table_name: &str,
+    ) -> String {
+        sql.push_str(&expressions.join(", "));
+        sql.push_str(format!(" FROM {}", get_dialect().quote_qualified(table_name)).as_str());
+        sql
+    }
+
+    /// Renders the join clauses, pushing any values used in `ON` conditions onto `params`.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if a join condition is malformed,
+    /// e.g. a comparison with nothing on its right-hand side.
+    pub(crate) fn joins_sql(
+        mut sql: String,
+        joins: &Vec<JoinInfo>,
+        with_deleted: bool,
+        params: &mut Vec<Value>,
+    ) -> Result<String, DatabaseError> {
+        let dialect = get_dialect();
 
         for join in joins {
             let join_type = match join.join_type {
@@ -813,41 +2999,265 @@ impl<T: Schema + Debug, S: Select + Debug> Query<T, S> {
                 JoinType::Cross => "CROSS JOIN",
             };
 
-            let join_table = &join.table_name;
-
-            if join_type == "CROSS JOIN" {
-                sql.push_str(&format!(" {} {}", join_type, join_table,));
-            } else {
-                sql.push_str(&format!(
-                    " {} {} ON {}.{} = {}.{}",
-                    join_type,
-                    join_table,
-                    join.condition.column_one.0,
-                    join.condition.column_one.1,
-                    join.condition.column_two.as_ref().unwrap().0,
-                    join.condition.column_two.as_ref().unwrap().1
+            let mut join_table = dialect.quote_qualified(&join.table_name);
+            if let Some(alias) = &join.alias {
+                join_table.push_str(&format!(" AS {}", dialect.quote_identifier(alias)));
+            }
+
+            // Soft-deleted rows are left out in the ON clause, so a left join
+            // still keeps the main table's row
+            let not_deleted = join
+                .soft_delete_column
+                .filter(|_| !with_deleted)
+                .map(|column| {
+                    format!(
+                        "{}.{} IS NULL",
+                        match &join.alias {
+                            Some(alias) => dialect.quote_identifier(alias),
+                            None => dialect.quote_qualified(&join.table_name),
+                        },
+                        dialect.quote_identifier(column)
+                    )
+                });
+
+            match (&join.condition, not_deleted) {
+                (None, None) => sql.push_str(&format!(" {} {}", join_type, join_table)),
+                // A cross join takes no ON clause; filtering it is an inner join
+                (None, Some(not_deleted)) => {
+                    sql.push_str(&format!(" INNER JOIN {} ON {}", join_table, not_deleted))
+                }
+                (Some(condition), not_deleted) => {
+                    validate_join_condition(condition.as_ref(), &join.table_name)?;
+                    let mut on = build_filter_expr(condition.as_ref(), params)?;
+                    if let Some(not_deleted) = not_deleted {
+                        on = format!("{} AND {}", on, not_deleted);
+                    }
+                    sql.push_str(&format!(" {} {} ON {}", join_type, join_table, on));
+                }
+            }
+        }
+
+        Ok(sql)
+    }
+
+    /// Renders the `GROUP BY` clause.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if a column belongs to a table
+    /// that is neither the main table nor joined.
+    pub(crate) fn group_sql(
+        mut sql: String,
+        group_by: &[(String, String)],
+        table_name: &str,
+        joins: &[JoinInfo],
+    ) -> Result<String, DatabaseError> {
+        if group_by.is_empty() {
+            return Ok(sql);
+        }
+
+        let dialect = get_dialect();
+        let mut parts: Vec<String> = Vec::with_capacity(group_by.len());
+
+        for (table, column) in group_by {
+            if table != table_name && !joins.iter().any(|join| join.references(table)) {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "Cannot group by {}.{}: table {} is not part of the query",
+                    table, column, table
+                )));
+            }
+
+            parts.push(format!(
+                "{}.{}",
+                dialect.quote_qualified(table),
+                dialect.quote_identifier(column)
+            ));
+        }
+
+        sql.push_str(" GROUP BY ");
+        sql.push_str(&parts.join(", "));
+        Ok(sql)
+    }
+
+    /// Appends the `having_raw` predicates as a `HAVING` clause, ANDed together,
+    /// pushing their values onto `params` after the filter values.
+    pub(crate) fn having_sql(
+        mut sql: String,
+        having: &[(String, Vec<Value>)],
+        params: &mut Vec<Value>,
+    ) -> Result<String, DatabaseError> {
+        if having.is_empty() {
+            return Ok(sql);
+        }
+
+        let mut predicates: Vec<String> = Vec::with_capacity(having.len());
+        for (predicate, values) in having {
+            predicates.push(format!("({})", bind_raw_sql(predicate, values, params)?));
+        }
+
+        sql.push_str(" HAVING ");
+        sql.push_str(&predicates.join(" AND "));
+        Ok(sql)
+    }
+
+    /// Appends the main table's primary key columns, ascending, to a non-empty
+    /// `order`, skipping any it already sorts by.
+    pub(crate) fn primary_key_tiebreak(order: &mut Vec<(String, String, SortOrder)>) {
+        if order.is_empty() {
+            return;
+        }
+
+        for column in T::get_all_columns() {
+            if !column.constraints.contains(&ColumnConstraint::PrimaryKey) {
+                continue;
+            }
+
+            let ordered = order
+                .iter()
+                .any(|(table, name, _)| table == T::table_name() && name == column.name);
+            if !ordered {
+                order.push((
+                    T::table_name().to_string(),
+                    column.name.to_string(),
+                    SortOrder::Asc,
                 ));
             }
         }
+    }
+
+    /// Renders the `ORDER BY` clause.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if a column belongs to a table
+    /// that is neither the main table nor joined.
+    pub(crate) fn order_sql(
+        mut sql: String,
+        order: &[(String, String, SortOrder)],
+        table_name: &str,
+        joins: &[JoinInfo],
+    ) -> Result<String, DatabaseError> {
+        if order.is_empty() {
+            return Ok(sql);
+        }
+
+        let dialect = get_dialect();
+        let mut parts: Vec<String> = Vec::with_capacity(order.len());
+
+        for (table, column, direction) in order {
+            if table != table_name && !joins.iter().any(|join| join.references(table)) {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "Cannot order by {}.{}: table {} is not part of the query",
+                    table, column, table
+                )));
+            }
+
+            let direction = match direction {
+                SortOrder::Asc => "ASC",
+                SortOrder::Desc => "DESC",
+            };
+            parts.push(format!(
+                "{}.{} {}",
+                dialect.quote_qualified(table),
+                dialect.quote_identifier(column),
+                direction
+            ));
+        }
+
+        sql.push_str(" ORDER BY ");
+        sql.push_str(&parts.join(", "));
+        Ok(sql)
+    }
+
+    /// Appends the `FOR UPDATE` clause, after any `LIMIT`/`OFFSET` as MySQL requires.
+    ///
+    /// SQLite has no row locks, so the clause is dropped with a warning.
+    pub(crate) fn lock_sql(mut sql: String, lock: Option<RowLock>) -> String {
+        let Some(lock) = lock else {
+            return sql;
+        };
+
+        if cfg!(feature = "sqlite") {
+            eprintln!("Warning: SQLite does not support FOR UPDATE. Ignoring the row lock.");
+            return sql;
+        }
+
+        sql.push_str(" FOR UPDATE");
+        match lock {
+            RowLock::Wait => {}
+            RowLock::SkipLocked => sql.push_str(" SKIP LOCKED"),
+            RowLock::NoWait => sql.push_str(" NOWAIT"),
+        }
 
         sql
     }
+
     pub(crate) fn filter_sql(
         mut sql: String,
         filters: Vec<Box<dyn Filtered>>,
         params: &mut Vec<Value>,
-    ) -> String {
+    ) -> Result<String, DatabaseError> {
         if filters.is_empty() {
-            return sql;
+            return Ok(sql);
         }
 
         sql.push_str(" WHERE ");
         let mut parts: Vec<String> = Vec::with_capacity(filters.len());
         for filter in &filters {
-            parts.push(build_filter_expr(filter.as_ref(), params));
+            parts.push(build_filter_expr(filter.as_ref(), params)?);
         }
         sql.push_str(&parts.join(" AND "));
 
-        sql
+        Ok(sql)
+    }
+}
+
+/// Checks that a join condition can be rendered into a meaningful `ON` clause.
+///
+/// Every comparison must name a column and have something to compare it with,
+/// and every `and`/`or`/`not` must wrap the filters it needs.
+fn validate_join_condition(filter: &dyn Filtered, table_name: &str) -> Result<(), DatabaseError> {
+    let malformed = |reason: &str| {
+        Err(DatabaseError::InvalidValue(format!(
+            "Invalid join condition for {}: {}",
+            table_name, reason
+        )))
+    };
+
+    if filter.is_sql().is_some() {
+        return Ok(());
+    }
+
+    if filter.is_or_filter() || filter.is_and_filter() {
+        let (Some(f1), Some(f2)) = (filter.filter1(), filter.filter2()) else {
+            return malformed("composite filter is missing one of its sides");
+        };
+        validate_join_condition(f1, table_name)?;
+        return validate_join_condition(f2, table_name);
+    }
+
+    if filter.is_not().unwrap_or(false) {
+        let Some(f) = filter.filter1() else {
+            return malformed("not filter is missing its inner filter");
+        };
+        return validate_join_condition(f, table_name);
+    }
+
+    if filter.is_group() {
+        let Some(f) = filter.filter1() else {
+            return malformed("group filter is missing its inner filter");
+        };
+        return validate_join_condition(f, table_name);
+    }
+
+    let Some(column) = filter.column_one() else {
+        return malformed("comparison is missing its column");
+    };
+
+    if column.0.is_empty() || column.1.is_empty() {
+        return malformed("comparison is missing its column");
     }
+
+    if filter.value().is_none() && filter.column_two().is_none() && filter.array_values().is_none()
+    {
+        return malformed("comparison has nothing to compare against");
+    }
+
+    Ok(())
 }
@@ -0,0 +1,122 @@
+#![warn(missing_docs)]
+
+//! # Replace Operation
+//!
+//! This module provides the [`Replace`] struct for MySQL's `REPLACE INTO`
+//! statement: if the record's primary or unique key already exists, the old
+//! row is deleted and the new one inserted in its place.
+
+use crate::database::error::DatabaseError;
+use crate::dialects::get_dialect;
+use crate::helpers::{bind_column_value, validate_column_value};
+use crate::operations::insert::select_insertable_columns;
+use crate::schema::{ColumnInfo, Schema, Value};
+
+use sqlx::MySqlPool;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A type-safe `REPLACE INTO` operation (MySQL only).
+///
+/// Unlike [`Upsert`](crate::operations::upsert::Upsert), which updates the
+/// existing row in place, `REPLACE` deletes the conflicting row and inserts a
+/// new one. That means delete triggers fire, columns missing from the record
+/// fall back to their defaults instead of keeping their old values, and an
+/// auto-increment key left unset is assigned a fresh value.
+///
+/// # Example
+///
+/// ```no_run
+/// use lume::database::Database;
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+///
+/// define_schema! {
+///     User {
+///         id: i32 [primary_key()],
+///         name: String [not_null()],
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let db = Database::connect("mysql://...").await.unwrap();
+///     db.replace(User { id: 1, name: "guru".to_string() })
+///         .execute()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+pub struct Replace<T> {
+    /// The record to be written.
+    data: T,
+
+    /// The database connection pool.
+    conn: Arc<MySqlPool>,
+}
+
+impl<T: Schema + Debug> Replace<T> {
+    /// Creates a new [`Replace`] operation for the given data and connection.
+    pub fn new(data: T, conn: Arc<MySqlPool>) -> Self {
+        Self { data, conn }
+    }
+
+    /// Builds the `REPLACE INTO` statement along with each column it writes and
+    /// the value to bind for it, in placeholder order.
+    ///
+    /// Fails with [`DatabaseError::ValidationFailed`] if a value breaks one of
+    /// its column's validators.
+    pub(crate) fn replace_sql(
+        &self,
+    ) -> Result<(String, Vec<(ColumnInfo<'static>, Value)>), DatabaseError> {
+        let values = self.data.values();
+        let selected = select_insertable_columns(T::get_all_columns(), &values);
+
+        let dialect = get_dialect();
+        let sql = format!(
+            "REPLACE INTO {} (",
+            dialect.quote_qualified(T::table_name())
+        );
+        let sql = dialect.insert_sql(sql, &selected);
+
+        let mut params = Vec::with_capacity(selected.len());
+        for col in selected {
+            let value = values.get(col.name);
+            validate_column_value(&col, value).map_err(DatabaseError::ValidationFailed)?;
+            let value = value.cloned().unwrap_or(Value::Null);
+            params.push((col, value));
+        }
+
+        Ok((sql, params))
+    }
+
+    /// Executes the replace operation asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the row was written.
+    /// * `Err(DatabaseError)` if an error occurred.
+    pub async fn execute(self) -> Result<(), DatabaseError> {
+        let (sql, params) = self.replace_sql()?;
+
+        let mut query = sqlx::query(&sql);
+        for (col, value) in params.iter() {
+            query = bind_column_value(query, col, Some(value))?;
+        }
+
+        let mut conn = self
+            .conn
+            .acquire()
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        query
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@
 //! This module provides type-safe query building and execution functionality.
 //! It includes the `Query<T>` struct for building and executing database queries.
 
-use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 
 #[cfg(feature = "mysql")]
 use sqlx::MySqlPool;
@@ -16,9 +16,13 @@ use sqlx::PgPool;
 #[cfg(feature = "sqlite")]
 use sqlx::SqlitePool;
 
+use crate::database::timeout;
+use crate::dialects::get_dialect;
 use crate::filter::Filtered;
-use crate::helpers::{StartingSql, bind_value, build_filter_expr, get_starting_sql};
-use crate::schema::{UpdateTrait, Value};
+use crate::helpers::{
+    StartingSql, bind_column_value, bind_value, build_filter_expr, get_starting_sql,
+};
+use crate::schema::{Column, ColumnConstraint, ColumnInfo, UpdateTrait, Value, native_enum_type};
 use crate::{database::error::DatabaseError, schema::Schema};
 
 #[derive(Debug)]
@@ -88,6 +92,10 @@ pub struct Update<T: Schema + Debug, U: UpdateTrait + Debug> {
 
     /// Vector of (column name, value) pairs to be updated.
     update_data: Vec<(&'static str, Value)>,
+    /// (target, source) pairs of columns set to another column's value.
+    column_copies: Vec<(&'static str, &'static str)>,
+    /// How long the statement may run.
+    timeout: Option<Duration>,
 }
 
 impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
@@ -98,6 +106,8 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
             update_table: PhantomData,
             filters: Vec::new(),
             update_data: Vec::new(),
+            column_copies: Vec::new(),
+            timeout: None,
             conn,
         }
     }
@@ -109,6 +119,8 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
             update_table: PhantomData,
             filters: Vec::new(),
             update_data: Vec::new(),
+            column_copies: Vec::new(),
+            timeout: None,
             conn,
         }
     }
@@ -120,6 +132,8 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
             update_table: PhantomData,
             filters: Vec::new(),
             update_data: Vec::new(),
+            column_copies: Vec::new(),
+            timeout: None,
             conn,
         }
     }
@@ -128,7 +142,8 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
     ///
     /// This method takes an update struct (typically generated by the `define_schema!` macro)
     /// and extracts the fields that should be updated. Only fields set to `Some(value)` will
-    /// be included in the update statement; fields set to `None` are ignored.
+    /// be included in the update statement; fields set to `None` are ignored. Use
+    /// [`set_null`](Self::set_null) to write `NULL` to a column.
     ///
     /// # Arguments
     ///
@@ -165,7 +180,108 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
     /// }
     /// ```
     pub fn set(mut self, data: U) -> Self {
-        self.update_data = data.get_updated();
+        let data = data.get_updated();
+        // Keep columns nulled with `set_null` unless `data` sets them
+        self.update_data.retain(|(name, value)| {
+            matches!(value, Value::Null)
+                && !data
+                    .iter()
+                    .any(|(set, _)| column_name(set) == column_name(name))
+        });
+        self.column_copies
+            .retain(|(target, _)| !data.iter().any(|(set, _)| column_name(set) == *target));
+        self.update_data.extend(data);
+        self
+    }
+
+    /// Sets a column to `NULL`.
+    ///
+    /// Fields left as `None` in the update struct are not touched by [`set`](Self::set),
+    /// so this is the way to clear a nullable column. Can be combined with `set`;
+    /// whichever call comes last decides the column's value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::database::Database;
+    /// use lume::define_schema;
+    /// use lume::filter::eq_value;
+    /// use lume::schema::ColumnInfo;
+    /// use lume::schema::Schema;
+    ///
+    /// define_schema! {
+    ///     Users {
+    ///         id: u64 [primary_key().not_null().auto_increment()],
+    ///         username: String [not_null()],
+    ///         age: u16,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let db = Database::connect("mysql://...").await.unwrap();
+    ///     db.update::<Users, UpdateUsers>()
+    ///         .set_null(Users::age())
+    ///         .filter(eq_value(Users::username(), "alice"))
+    ///         .execute()
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn set_null<C>(mut self, column: &'static Column<C>) -> Self {
+        let name = column.__internal_name();
+        self.update_data.retain(|(set, _)| column_name(set) != name);
+        self.column_copies.retain(|(target, _)| *target != name);
+        self.update_data.push((name, Value::Null));
+        self
+    }
+
+    /// Sets a column to the current value of another column of the same row,
+    /// rendering `SET target = source` rather than binding a value.
+    ///
+    /// The source is read before the update on every backend, even when the same
+    /// statement also sets the source column.
+    ///
+    /// Like [`set_null`](Self::set_null), it can be combined with [`set`](Self::set),
+    /// and whichever call comes last decides the column's value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::database::Database;
+    /// use lume::define_schema;
+    /// use lume::filter::eq_value;
+    /// use lume::schema::ColumnInfo;
+    /// use lume::schema::Schema;
+    ///
+    /// define_schema! {
+    ///     Users {
+    ///         id: u64 [primary_key().not_null().auto_increment()],
+    ///         email: String [not_null()],
+    ///         backup_email: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let db = Database::connect("mysql://...").await.unwrap();
+    ///     db.update::<Users, UpdateUsers>()
+    ///         .set_from_column(Users::backup_email(), Users::email())
+    ///         .filter(eq_value(Users::id(), 1u64))
+    ///         .execute()
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn set_from_column<C>(
+        mut self,
+        target: &'static Column<C>,
+        source: &'static Column<C>,
+    ) -> Self {
+        let name = target.__internal_name();
+        self.update_data.retain(|(set, _)| column_name(set) != name);
+        self.column_copies.retain(|(set, _)| *set != name);
+        self.column_copies.push((name, source.__internal_name()));
         self
     }
 
@@ -220,6 +336,16 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
         self
     }
 
+    /// Fails the update with [`DatabaseError::Timeout`] if it runs longer than `timeout`.
+    /// On MySQL and SQLite only the client stops waiting, so the update may
+    /// still complete on the server.
+    ///
+    /// See [`Query::timeout`](crate::operations::query::Query::timeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Executes the SQL UPDATE operation with the specified update data and filters.
     ///
     /// This method builds the SQL UPDATE statement using the provided update data and filter conditions,
@@ -257,12 +383,52 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
     ///         .unwrap();
     /// }
     /// ```
-    pub async fn execute(self) -> Result<(), DatabaseError> {
-        let sql = get_starting_sql(StartingSql::Update, T::table_name());
-        let sql = Self::update_sql(sql, self.update_data);
+    pub async fn execute(mut self) -> Result<(), DatabaseError> {
+        let columns = T::get_all_columns();
+        let version = columns
+            .iter()
+            .find(|col| col.constraints.contains(&ColumnConstraint::Version))
+            .map(|col| col.name);
+
+        // The version set in the update struct is the one the row must still have
+        let expected_version = version.and_then(|version| {
+            let index = self
+                .update_data
+                .iter()
+                .position(|(name, _)| column_name(name) == version)?;
+            Some(self.update_data.remove(index).1)
+        });
+
+        let set_columns: Vec<Option<&ColumnInfo>> = self
+            .update_data
+            .iter()
+            .map(|(name, _)| {
+                let name = column_name(name);
+                columns.iter().find(|col| col.name == name)
+            })
+            .collect();
 
         let mut params: Vec<Value> = Vec::new();
-        let sql = Self::filter_sql(sql, self.filters, &mut params);
+        let sql = get_starting_sql(StartingSql::Update, T::table_name());
+        let first = set_columns.is_empty() && self.column_copies.is_empty();
+        let sql = Self::update_sql(sql, self.update_data, &self.column_copies, &mut params);
+        let sql = match version {
+            Some(version) => Self::version_sql(sql, version, first),
+            None => sql,
+        };
+        let has_filters = !self.filters.is_empty();
+        let mut sql = Self::filter_sql(sql, self.filters, &mut params)?;
+
+        if let (Some(version), Some(expected)) = (version, &expected_version) {
+            let dialect = get_dialect();
+            sql.push_str(if has_filters { " AND " } else { " WHERE " });
+            sql.push_str(&format!(
+                "{} = {}",
+                dialect.quote_identifier(version),
+                dialect.placeholder(params.len())
+            ));
+            params.push(expected.clone());
+        }
 
         let mut conn = self
             .conn
@@ -270,27 +436,72 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
             .await
             .map_err(DatabaseError::ConnectionError)?;
         let mut query = sqlx::query(&sql);
-        for v in params {
-            query = bind_value(query, v);
+        for (i, v) in params.into_iter().enumerate() {
+            // SET values bind by column, so NULLs get the column's type
+            query = match set_columns.get(i) {
+                Some(Some(col)) => bind_column_value(query, col, Some(&v))?,
+                _ => bind_value(query, v)?,
+            };
         }
 
-        query
-            .execute(conn.as_mut())
-            .await
-            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+        timeout::start(&mut conn, self.timeout).await?;
+        let result = timeout::run(self.timeout, query.execute(conn.as_mut()), |e| {
+            DatabaseError::ExecutionError(e.to_string())
+        })
+        .await;
+        let result = timeout::finish(&mut conn, self.timeout, result).await?;
+
+        if expected_version.is_some() && result.rows_affected() == 0 {
+            return Err(DatabaseError::StaleData(format!(
+                "No row of {} matched the expected version; it was changed or deleted by another writer",
+                T::table_name()
+            )));
+        }
 
         Ok(())
     }
 
-    pub(crate) fn update_sql(mut sql: String, data: Vec<(&'static str, Value)>) -> String {
-        if data.is_empty() {
-            return sql;
+    /// Appends the increment of the `version()` column to the `SET` assignments.
+    pub(crate) fn version_sql(mut sql: String, version: &str, first: bool) -> String {
+        let version = get_dialect().quote_identifier(version);
+        if !first {
+            sql.push_str(", ");
         }
+        sql.push_str(&format!("{} = {} + 1", version, version));
+        sql
+    }
 
-        for column in data {
-            sql.push_str(&format!("{} = {}", column.0, column.1));
-            sql.push_str(" ");
+    /// Renders the column-to-column `copies` followed by the `SET` assignments with
+    /// placeholders, pushing the values onto `params`.
+    pub(crate) fn update_sql(
+        mut sql: String,
+        data: Vec<(&'static str, Value)>,
+        copies: &[(&'static str, &'static str)],
+        params: &mut Vec<Value>,
+    ) -> String {
+        let dialect = get_dialect();
+        let columns = T::get_all_columns();
+
+        let mut assignments: Vec<String> = Vec::with_capacity(data.len() + copies.len());
+        // Copies go first: MySQL applies assignments left to right, so a copy
+        // after a set of its source would read the new value
+        for (target, source) in copies {
+            assignments.push(format!(
+                "{} = {}",
+                dialect.quote_identifier(target),
+                dialect.quote_identifier(source)
+            ));
+        }
+        for (name, value) in data {
+            let name = column_name(name);
+            assignments.push(format!(
+                "{} = {}",
+                dialect.quote_identifier(name),
+                column_placeholder(&columns, name, params.len())
+            ));
+            params.push(value);
         }
+        sql.push_str(&assignments.join(", "));
 
         sql
     }
@@ -299,18 +510,284 @@ impl<T: Schema + Debug, U: UpdateTrait + Debug> Update<T, U> {
         mut sql: String,
         filters: Vec<Box<dyn Filtered>>,
         params: &mut Vec<Value>,
-    ) -> String {
+    ) -> Result<String, DatabaseError> {
         if filters.is_empty() {
-            return sql;
+            return Ok(sql);
         }
 
         sql.push_str(" WHERE ");
         let mut parts: Vec<String> = Vec::with_capacity(filters.len());
         for filter in &filters {
-            parts.push(build_filter_expr(filter.as_ref(), params));
+            parts.push(build_filter_expr(filter.as_ref(), params)?);
         }
         sql.push_str(&parts.join(" AND "));
 
-        sql
+        Ok(sql)
+    }
+}
+
+/// A type-safe update operation applying per-record updates keyed on the primary key.
+///
+/// Each record is an update struct with its primary key field(s) set; every other
+/// `Some` field is written to the row with that key. Executes one UPDATE per record,
+/// all in one transaction, so a failing record leaves none of them applied.
+/// All statements are built before any is executed, so a record missing its key
+/// fails the whole batch without touching the database.
+///
+/// # Example
+///
+/// ```no_run
+/// use lume::database::Database;
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+///
+/// define_schema! {
+///     Users {
+///         id: i32 [primary_key()],
+///         name: String [not_null()],
+///         age: i32,
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+///     let db = Database::connect("mysql://...").await?;
+///     db.update_many::<Users, UpdateUsers, _>(vec![
+///         UpdateUsers { id: Some(1), age: Some(20), ..Default::default() },
+///         UpdateUsers { id: Some(2), age: Some(30), ..Default::default() },
+///     ])
+///     .execute()
+///     .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct UpdateMany<T: Schema + Debug, U: UpdateTrait + Debug> {
+    /// Marker for the schema type `T`.
+    table: PhantomData<T>,
+    /// The update records, each carrying its primary key.
+    records: Vec<U>,
+
+    #[cfg(feature = "mysql")]
+    /// The database connection pool.
+    conn: Arc<MySqlPool>,
+
+    #[cfg(feature = "postgres")]
+    /// The database connection pool.
+    conn: Arc<PgPool>,
+
+    #[cfg(feature = "sqlite")]
+    /// The database connection pool.
+    conn: Arc<SqlitePool>,
+}
+
+impl<T: Schema + Debug, U: UpdateTrait + Debug> UpdateMany<T, U> {
+    #[cfg(feature = "mysql")]
+    pub(crate) fn new(records: Vec<U>, conn: Arc<MySqlPool>) -> Self {
+        Self {
+            table: PhantomData,
+            records,
+            conn,
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub(crate) fn new(records: Vec<U>, conn: Arc<PgPool>) -> Self {
+        Self {
+            table: PhantomData,
+            records,
+            conn,
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn new(records: Vec<U>, conn: Arc<SqlitePool>) -> Self {
+        Self {
+            table: PhantomData,
+            records,
+            conn,
+        }
     }
+
+    /// Builds the `UPDATE ... WHERE <pk> = ...` statement for one record.
+    ///
+    /// `updated` is the output of [`UpdateTrait::get_updated`]. A `version()`
+    /// column is incremented like in [`Update::execute`], and a version set in
+    /// the record is the one the row must still have. Returns the statement and
+    /// whether it checks that expected version, or `Ok(None)` when the record
+    /// sets nothing besides its key and version. Fails with
+    /// [`DatabaseError::InvalidValue`] when the table has no primary key or the
+    /// record leaves one of the key columns unset.
+    pub(crate) fn update_many_sql(
+        updated: Vec<(&'static str, Value)>,
+        params: &mut Vec<Value>,
+    ) -> Result<Option<(String, bool)>, DatabaseError> {
+        let columns = T::get_all_columns();
+        let primary_keys: Vec<&str> = columns
+            .iter()
+            .filter(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+            .map(|col| col.name)
+            .collect();
+        let version = columns
+            .iter()
+            .find(|col| col.constraints.contains(&ColumnConstraint::Version))
+            .map(|col| col.name);
+
+        if primary_keys.is_empty() {
+            return Err(DatabaseError::InvalidValue(format!(
+                "Table {} must have a primary key to update by record",
+                T::table_name()
+            )));
+        }
+
+        let mut keys: Vec<(&str, Value)> = Vec::new();
+        let mut sets: Vec<(&str, Value)> = Vec::new();
+        let mut expected_version: Option<Value> = None;
+        for (name, value) in updated {
+            let column = column_name(name);
+            if primary_keys.contains(&column) {
+                keys.push((column, value));
+            } else if version == Some(column) {
+                expected_version = Some(value);
+            } else {
+                sets.push((column, value));
+            }
+        }
+
+        if let Some(missing) = primary_keys
+            .iter()
+            .find(|pk| !keys.iter().any(|(column, _)| column == *pk))
+        {
+            return Err(DatabaseError::InvalidValue(format!(
+                "Record for table {} is missing primary key column {}",
+                T::table_name(),
+                missing
+            )));
+        }
+
+        if sets.is_empty() {
+            return Ok(None);
+        }
+
+        let dialect = get_dialect();
+        let mut sql = get_starting_sql(StartingSql::Update, T::table_name());
+
+        let mut assignments: Vec<String> = Vec::with_capacity(sets.len());
+        for (column, value) in sets {
+            assignments.push(format!(
+                "{} = {}",
+                dialect.quote_identifier(column),
+                column_placeholder(&columns, column, params.len())
+            ));
+            params.push(value);
+        }
+        sql.push_str(&assignments.join(", "));
+        if let Some(version) = version {
+            sql = Update::<T, U>::version_sql(sql, version, false);
+        }
+
+        let mut conditions: Vec<String> = Vec::with_capacity(keys.len() + 1);
+        for (column, value) in keys {
+            conditions.push(format!(
+                "{} = {}",
+                dialect.quote_identifier(column),
+                column_placeholder(&columns, column, params.len())
+            ));
+            params.push(value);
+        }
+        let versioned = match (version, expected_version) {
+            (Some(version), Some(expected)) => {
+                conditions.push(format!(
+                    "{} = {}",
+                    dialect.quote_identifier(version),
+                    dialect.placeholder(params.len())
+                ));
+                params.push(expected);
+                true
+            }
+            _ => false,
+        };
+        sql.push_str(" WHERE ");
+        sql.push_str(&conditions.join(" AND "));
+
+        Ok(Some((sql, versioned)))
+    }
+
+    /// Executes one UPDATE per record.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if every record was applied.
+    /// * `Err(DatabaseError)` if a record is missing its primary key or a statement failed.
+    /// * `Err(DatabaseError::StaleData)` if a record's expected `version()` no
+    ///   longer matches its row.
+    pub async fn execute(self) -> Result<(), DatabaseError> {
+        let mut statements: Vec<(String, Vec<Value>, bool)> =
+            Vec::with_capacity(self.records.len());
+        for record in self.records {
+            let mut params: Vec<Value> = Vec::new();
+            if let Some((sql, versioned)) =
+                Self::update_many_sql(record.get_updated(), &mut params)?
+            {
+                statements.push((sql, params, versioned));
+            }
+        }
+
+        let mut conn = self
+            .conn
+            .acquire()
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        // A failing record must not leave the earlier ones applied
+        let mut tx = sqlx::Connection::begin(&mut *conn)
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        for (sql, params, versioned) in &statements {
+            let mut query = sqlx::query(sql);
+            for v in params.iter().cloned() {
+                query = bind_value(query, v)?;
+            }
+
+            let result = query
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+            if *versioned && result.rows_affected() == 0 {
+                return Err(DatabaseError::StaleData(format!(
+                    "No row of {} matched the expected version; it was changed or deleted by another writer",
+                    T::table_name()
+                )));
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Renders the placeholder at `index` for `column`, cast to its
+/// `native_enum()` type where the dialect needs it.
+fn column_placeholder(columns: &[ColumnInfo], column: &str, index: usize) -> String {
+    let dialect = get_dialect();
+    let placeholder = dialect.placeholder(index);
+    match columns
+        .iter()
+        .find(|col| col.name == column)
+        .and_then(|col| native_enum_type(col.constraints))
+    {
+        Some(type_name) => dialect.enum_param_sql(placeholder, type_name),
+        None => placeholder,
+    }
+}
+
+/// Strips the table from a [`UpdateTrait::get_updated`] key, which is `Table.column`.
+fn column_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name).trim()
 }
@@ -0,0 +1,170 @@
+#![warn(missing_docs)]
+
+//! # Upsert Operation
+//!
+//! This module provides the [`Upsert`] struct for saving a record by primary key:
+//! the row is inserted if its key is new, otherwise its non-key columns are
+//! overwritten with the record's values.
+
+use crate::database::error::DatabaseError;
+use crate::dialects::get_dialect;
+use crate::helpers::{StartingSql, bind_column_value, get_starting_sql, validate_column_value};
+use crate::operations::insert::select_insertable_columns;
+use crate::schema::{ColumnConstraint, ColumnInfo, Schema};
+
+#[cfg(feature = "mysql")]
+use sqlx::MySqlPool;
+
+#[cfg(feature = "postgres")]
+use sqlx::PgPool;
+
+#[cfg(feature = "sqlite")]
+use sqlx::SqlitePool;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// Builds an `INSERT` of the `selected` columns with a conflict clause on the
+/// primary key, overwriting the other selected columns when `update` is set and
+/// leaving the existing row untouched otherwise.
+///
+/// Fails with [`DatabaseError::InvalidValue`] if the table has no primary key
+/// to use as the conflict target.
+pub(crate) fn conflict_insert_sql<T: Schema>(
+    selected: &[ColumnInfo<'_>],
+    update: bool,
+) -> Result<String, DatabaseError> {
+    let primary_keys: Vec<&str> = T::get_all_columns()
+        .iter()
+        .filter(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+        .map(|col| col.name)
+        .collect();
+
+    if primary_keys.is_empty() {
+        return Err(DatabaseError::InvalidValue(format!(
+            "Table {} must have a primary key to upsert",
+            T::table_name()
+        )));
+    }
+
+    let update: Vec<&str> = selected
+        .iter()
+        .filter(|col| update && !primary_keys.contains(&col.name))
+        .map(|col| col.name)
+        .collect();
+
+    let dialect = get_dialect();
+    let sql = get_starting_sql(StartingSql::Insert, T::table_name());
+    let sql = dialect.insert_sql(sql, &selected.to_vec());
+    Ok(dialect.upsert_sql(sql, &primary_keys, &update))
+}
+
+/// A type-safe insert-or-update operation keyed on the schema's primary key.
+///
+/// Uses `ON DUPLICATE KEY UPDATE` on MySQL and `ON CONFLICT (...) DO UPDATE`
+/// on Postgres and SQLite, updating every non-key column with the new values.
+///
+/// `unique_check()` validators are not run, since the row being updated would
+/// always fail its own pre-check.
+///
+/// # Example
+///
+/// ```no_run
+/// use lume::database::Database;
+/// use lume::define_schema;
+/// use lume::schema::Schema;
+/// use lume::schema::ColumnInfo;
+///
+/// define_schema! {
+///     User {
+///         id: i32 [primary_key()],
+///         name: String [not_null()],
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let db = Database::connect("mysql://...").await.unwrap();
+///     db.upsert(User { id: 1, name: "guru".to_string() })
+///         .execute()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+pub struct Upsert<T> {
+    /// The record to be saved.
+    data: T,
+
+    #[cfg(feature = "mysql")]
+    /// The database connection pool.
+    conn: Arc<MySqlPool>,
+
+    #[cfg(feature = "postgres")]
+    /// The database connection pool.
+    conn: Arc<PgPool>,
+
+    #[cfg(feature = "sqlite")]
+    /// The database connection pool.
+    conn: Arc<SqlitePool>,
+}
+
+impl<T: Schema + Debug> Upsert<T> {
+    #[cfg(feature = "mysql")]
+    /// Creates a new [`Upsert`] operation for the given data and connection.
+    pub fn new(data: T, conn: Arc<MySqlPool>) -> Self {
+        Self { data, conn }
+    }
+
+    #[cfg(feature = "postgres")]
+    /// Creates a new [`Upsert`] operation for the given data and connection.
+    pub fn new(data: T, conn: Arc<PgPool>) -> Self {
+        Self { data, conn }
+    }
+
+    #[cfg(feature = "sqlite")]
+    /// Creates a new [`Upsert`] operation for the given data and connection.
+    pub fn new(data: T, conn: Arc<SqlitePool>) -> Self {
+        Self { data, conn }
+    }
+
+    /// Builds the `INSERT ... ON CONFLICT` statement for the given columns.
+    ///
+    /// Fails with [`DatabaseError::InvalidValue`] if the table has no primary key
+    /// to use as the conflict target.
+    pub(crate) fn upsert_sql(selected: &[ColumnInfo<'_>]) -> Result<String, DatabaseError> {
+        conflict_insert_sql::<T>(selected, true)
+    }
+
+    /// Executes the upsert operation asynchronously.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the row was inserted or updated.
+    /// * `Err(DatabaseError)` if an error occurred.
+    pub async fn execute(self) -> Result<(), DatabaseError> {
+        let values = self.data.values();
+        let selected = select_insertable_columns(T::get_all_columns(), &values);
+
+        let sql = Self::upsert_sql(&selected)?;
+        let mut query = sqlx::query(&sql);
+
+        for col in selected.iter() {
+            let value = values.get(col.name);
+            validate_column_value(col, value).map_err(DatabaseError::ValidationFailed)?;
+            query = bind_column_value(query, col, value)?;
+        }
+
+        let mut conn = self
+            .conn
+            .acquire()
+            .await
+            .map_err(DatabaseError::ConnectionError)?;
+
+        query
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| DatabaseError::ExecutionError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+/// Helper macro: resolves the column accessor for a `from_row!` field, using the
+/// explicit column name when one is given and the field name otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lume_from_row_column {
+    ($schema:ident, $field:ident) => {
+        $schema::$field()
+    };
+    ($schema:ident, $field:ident, $column:ident) => {
+        $schema::$column()
+    };
+}
+
+/// Implements [`FromRow`](crate::row::FromRow) for a plain struct, mapping each
+/// field from the column of the same name on the given schema.
+///
+/// Field types are checked against the schema's column types at compile time.
+/// A field can read from a differently named column with `field: column`.
+///
+/// # Example
+///
+/// ```rust
+/// use lume::define_schema;
+/// use lume::from_row;
+/// use lume::row::FromRow;
+/// use lume::schema::{Schema, ColumnInfo};
+///
+/// define_schema! {
+///     User {
+///         id: i32 [primary_key()],
+///         name: String [not_null()],
+///         age: i32,
+///     }
+/// }
+///
+/// struct UserProfile {
+///     id: i32,
+///     display_name: String,
+/// }
+///
+/// from_row!(User => UserProfile {
+///     id,
+///     display_name: name,
+/// });
+/// ```
+///
+/// Mapping query results is then a single iterator step:
+///
+/// ```ignore
+/// let profiles: Vec<UserProfile> = rows.iter().filter_map(UserProfile::from_row).collect();
+/// ```
+#[macro_export]
+macro_rules! from_row {
+    ($schema:ident => $target:ident { $($field:ident $(: $column:ident)?),* $(,)? }) => {
+        impl $crate::row::FromRow<$schema> for $target {
+            fn from_row(row: &$crate::row::Row<$schema>) -> Option<Self> {
+                Some(Self {
+                    $(
+                        $field: row.get($crate::__lume_from_row_column!($schema, $field $(, $column)?))?,
+                    )*
+                })
+            }
+        }
+    };
+}
@@ -6,6 +6,8 @@
 //! The `Row<S>` struct represents a single row from a database table with
 //! compile-time type safety for column access.
 
+mod macros;
+
 use std::{collections::HashMap, fmt::Debug, marker::PhantomData};
 
 #[cfg(feature = "mysql")]
@@ -17,6 +19,10 @@ use sqlx::postgres::PgRow;
 #[cfg(feature = "sqlite")]
 use sqlx::sqlite::SqliteRow;
 
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+use crate::helpers::json_to_array;
+#[cfg(feature = "postgres")]
+use crate::helpers::{PgNumeric, PgNumericFloat};
 use crate::{
     operations::query::JoinInfo,
     schema::{Column, ColumnInfo, Schema, Value},
@@ -44,12 +50,191 @@ pub struct Row<S: Schema + Debug> {
     _phanton: PhantomData<S>,
 }
 
+/// Trait for mapping a [`Row`] into a user-defined struct.
+///
+/// This is usually implemented with the [`from_row!`](crate::from_row) macro,
+/// which pulls each field from its matching column.
+///
+/// # Returns
+///
+/// - `Some(Self)`: If every mapped column is present and convertible
+/// - `None`: If any mapped column is missing or has an incompatible value
+pub trait FromRow<S: Schema + Debug>: Sized {
+    /// Builds `Self` from the given row.
+    fn from_row(row: &Row<S>) -> Option<Self>;
+}
+
+/// Whether `row` has a column named `column_name` holding SQL `NULL`.
+fn is_null_column<R: sqlx::Row>(row: &R, column_name: &str) -> bool
+where
+    for<'a> &'a str: sqlx::ColumnIndex<R>,
+{
+    use sqlx::ValueRef;
+    row.try_get_raw(column_name)
+        .is_ok_and(|value| value.is_null())
+}
+
+/// Reads the columns of a join that selects them as `table.column`, so they
+/// can't pick up the main table's value of the same name.
+///
+/// `extract` is the backend's typed reader, called with the column's name and
+/// data type.
+fn read_prefixed_columns<R: sqlx::Row>(
+    row: &R,
+    join: &JoinInfo,
+    map: &mut HashMap<String, Value>,
+    extract: impl Fn(&R, &str, &str) -> Option<Value>,
+) where
+    for<'a> &'a str: sqlx::ColumnIndex<R>,
+{
+    for column in &join.columns {
+        let name = format!("{}.{}", join.table_name, column.name);
+        let value = if is_null_column(row, &name) {
+            Some(Value::Null)
+        } else {
+            extract(row, &name, column.data_type)
+        };
+        if let Some(value) = value {
+            map.insert(name, value);
+        }
+    }
+}
+
+/// Extracts a value whose type isn't known from the schema, trying the
+/// widest integer, float, boolean and string types in turn.
+#[cfg(feature = "mysql")]
+pub(crate) fn extract_untyped_value<I>(row: &MySqlRow, column: I) -> Option<Value>
+where
+    I: sqlx::ColumnIndex<MySqlRow> + Copy,
+{
+    use sqlx::Row as _;
+    if let Ok(val) = row.try_get::<i64, _>(column) {
+        Some(Value::Int64(val))
+    } else if let Ok(val) = row.try_get::<u64, _>(column) {
+        Some(Value::UInt64(val))
+    } else if let Ok(val) = row.try_get::<f64, _>(column) {
+        Some(Value::Float64(val))
+    } else if let Ok(val) = row.try_get::<bool, _>(column) {
+        Some(Value::Bool(val))
+    } else if let Ok(val) = row.try_get::<String, _>(column) {
+        Some(Value::String(val))
+    } else if let Some(text) = row
+        .try_get_raw(column)
+        .ok()
+        .filter(|raw| sqlx::TypeInfo::name(&*sqlx::ValueRef::type_info(raw)) == "DECIMAL")
+        .and_then(|_| row.try_get_unchecked::<String, _>(column).ok())
+    {
+        // SUM and AVG return DECIMAL, which sqlx only decodes into decimal crates
+        text.parse::<i64>()
+            .map(Value::Int64)
+            .or_else(|_| text.parse::<f64>().map(Value::Float64))
+            .ok()
+    } else {
+        None
+    }
+}
+
+/// Extracts a value whose type isn't known from the schema, trying the
+/// widest integer, float, boolean and string types in turn.
+#[cfg(feature = "postgres")]
+pub(crate) fn extract_untyped_value<I>(row: &PgRow, column: I) -> Option<Value>
+where
+    I: sqlx::ColumnIndex<PgRow> + Copy,
+{
+    use sqlx::Row as _;
+    if let Ok(val) = row.try_get::<i64, _>(column) {
+        Some(Value::Int64(val))
+    } else if let Ok(val) = row.try_get::<i32, _>(column) {
+        Some(Value::Int32(val))
+    } else if let Ok(val) = row.try_get::<i16, _>(column) {
+        Some(Value::Int16(val))
+    } else if let Ok(val) = row.try_get::<PgNumeric, _>(column) {
+        // SUM over integer columns returns NUMERIC; narrow it when it fits
+        match val.into_value()? {
+            Value::Int128(i) => Some(i64::try_from(i).map_or(Value::Int128(i), Value::Int64)),
+            value => Some(value),
+        }
+    } else if let Ok(val) = row.try_get::<PgNumericFloat, _>(column) {
+        // AVG and fractional NUMERIC values
+        Some(Value::Float64(val.0))
+    } else if let Ok(val) = row.try_get::<f32, _>(column) {
+        Some(Value::Float32(val))
+    } else if let Ok(val) = row.try_get::<f64, _>(column) {
+        Some(Value::Float64(val))
+    } else if let Ok(val) = row.try_get::<bool, _>(column) {
+        Some(Value::Bool(val))
+    } else if let Ok(val) = row.try_get::<String, _>(column) {
+        Some(Value::String(val))
+    } else {
+        None
+    }
+}
+
+/// Extracts a value whose type isn't known from the schema, trying the
+/// widest integer, float, boolean and string types in turn.
+#[cfg(feature = "sqlite")]
+pub(crate) fn extract_untyped_value<I>(row: &SqliteRow, column: I) -> Option<Value>
+where
+    I: sqlx::ColumnIndex<SqliteRow> + Copy,
+{
+    use sqlx::Row as _;
+    if let Ok(val) = row.try_get::<i64, _>(column) {
+        Some(Value::Int64(val))
+    } else if let Ok(val) = row.try_get::<u64, _>(column) {
+        Some(Value::UInt64(val))
+    } else if let Ok(val) = row.try_get::<f64, _>(column) {
+        Some(Value::Float64(val))
+    } else if let Ok(val) = row.try_get::<bool, _>(column) {
+        Some(Value::Bool(val))
+    } else if let Ok(val) = row.try_get::<String, _>(column) {
+        Some(Value::String(val))
+    } else {
+        None
+    }
+}
+
+/// Parses the digits MySQL and SQLite return for a 128-bit integer column.
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+fn parse_int128(text: &str) -> Option<Value> {
+    text.parse::<i128>()
+        .map(Value::Int128)
+        .or_else(|_| text.parse::<u128>().map(Value::UInt128))
+        .ok()
+}
+
+/// Extracts a Postgres array column, converting each element with `into`.
+#[cfg(feature = "postgres")]
+fn extract_array<T>(row: &PgRow, column_name: &str, into: fn(T) -> Value) -> Option<Value>
+where
+    Vec<T>: for<'r> sqlx::Decode<'r, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+{
+    use sqlx::Row as _;
+    let values = row.try_get::<Option<Vec<T>>, _>(column_name).ok()??;
+    Some(Value::Array(values.into_iter().map(into).collect()))
+}
+
 impl<S: Schema + Debug> Debug for Row<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Row").field("data", &self.data).finish()
     }
 }
 
+impl<S: Schema + Debug> Clone for Row<S> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            _phanton: PhantomData,
+        }
+    }
+}
+
+/// Rows are equal when they hold the same columns with the same values.
+impl<S: Schema + Debug> PartialEq for Row<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
 impl<S: Schema + Debug> Row<S> {
     /// Creates a new empty row.
     pub(crate) fn _new() -> Self {
@@ -59,6 +244,24 @@ impl<S: Schema + Debug> Row<S> {
         }
     }
 
+    /// Creates a row holding the given column data.
+    pub(crate) fn from_data(data: HashMap<String, Value>) -> Self {
+        Self {
+            data,
+            _phanton: PhantomData,
+        }
+    }
+
+    /// Returns the column data held by this row.
+    pub(crate) fn data(&self) -> &HashMap<String, Value> {
+        &self.data
+    }
+
+    /// Consumes the row, returning its column data.
+    pub(crate) fn into_data(self) -> HashMap<String, Value> {
+        self.data
+    }
+
     /// Inserts a value into this row for the specified column.
     ///
     /// # Arguments
@@ -95,6 +298,124 @@ impl<S: Schema + Debug> Row<S> {
             .and_then(|v| T::try_from(v.clone()).ok())
     }
 
+    /// Returns `true` if the column was selected and is SQL `NULL`.
+    ///
+    /// Unlike [`get`](Self::get), this tells a `NULL` apart from a column that
+    /// is missing from the row or holds a value of another type.
+    ///
+    /// # Arguments
+    ///
+    /// - `column`: A reference to the column definition
+    pub fn is_null<T>(&self, column: &'static Column<T>) -> bool {
+        matches!(self.data.get(column.name), Some(Value::Null))
+    }
+
+    /// Retrieves a value from this row, or `default` if it's `NULL`, missing
+    /// or can't be converted to `T`.
+    ///
+    /// # Arguments
+    ///
+    /// - `column`: A reference to the column definition
+    /// - `default`: The value to return when [`get`](Self::get) would return `None`
+    pub fn get_or<T>(&self, column: &'static Column<T>, default: T) -> T
+    where
+        T: TryFrom<Value>,
+    {
+        self.get(column).unwrap_or(default)
+    }
+
+    /// Returns the columns of a joined table `J` as their own row.
+    ///
+    /// Returns `None` if none of `J`'s columns were selected, or if they are all
+    /// `NULL`, as they are for an unmatched row of a `LEFT JOIN`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use lume::define_schema;
+    /// use lume::database::Database;
+    /// use lume::filter::eq_column;
+    /// use lume::schema::{Schema, ColumnInfo};
+    ///
+    /// define_schema! {
+    ///     User {
+    ///         id: i32 [primary_key()],
+    ///         name: String [not_null()],
+    ///     }
+    ///
+    ///     Profile {
+    ///         user_id: i32 [primary_key()],
+    ///         bio: String,
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), lume::database::error::DatabaseError> {
+    ///     let db = Database::connect("mysql://...").await?;
+    ///     let users = db.query::<User, SelectUser>()
+    ///         .left_join::<Profile, SelectProfile>(
+    ///             eq_column(User::id(), Profile::user_id()),
+    ///             SelectProfile::selected().all(),
+    ///         )
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     for user in users {
+    ///         let bio = user.joined::<Profile>().and_then(|p| p.get(Profile::bio()));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn joined<J: Schema + Debug>(&self) -> Option<Row<J>> {
+        let main_columns = S::get_all_columns();
+        let mut data = HashMap::new();
+
+        for column in J::get_all_columns() {
+            let qualified = format!("{}.{}", J::table_name(), column.name);
+            // A joined column keeps its bare name unless the main table has one like it
+            let value = self.data.get(&qualified).or_else(|| {
+                if main_columns.iter().any(|main| main.name == column.name) {
+                    None
+                } else {
+                    self.data.get(column.name)
+                }
+            });
+
+            if let Some(value) = value {
+                data.insert(column.name.to_string(), value.clone());
+            }
+        }
+
+        if data.values().all(|value| matches!(value, Value::Null)) {
+            return None;
+        }
+
+        Some(Row::from_data(data))
+    }
+
+    /// Retrieves a value from this row by its column name or alias.
+    ///
+    /// Use this for columns that have no schema definition, such as the
+    /// expressions selected with [`Query::select_raw`](crate::operations::query::Query::select_raw).
+    /// Unlike [`get`](Self::get), the column name is not checked at compile time.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: The column name or alias as returned by the database
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)`: The value if found and convertible
+    /// - `None`: If the column doesn't exist or conversion fails
+    pub fn get_by_name<T>(&self, name: &str) -> Option<T>
+    where
+        T: TryFrom<Value>,
+    {
+        self.data
+            .get(name)
+            .and_then(|v| T::try_from(v.clone()).ok())
+    }
+
     #[cfg(feature = "mysql")]
     /// Converts MySQL rows to type-safe Lume rows.
     ///
@@ -141,27 +462,45 @@ impl<S: Schema + Debug> Row<S> {
     /// let mysql_rows: Vec<MySqlRow> = sqlx::query("SELECT * FROM users").fetch_all(&pool).await?;
     /// let lume_rows: Vec<Row<User>> = Row::from_mysql_row(mysql_rows);
     pub(crate) fn from_mysql_row(rows: Vec<MySqlRow>, joins: Option<&Vec<JoinInfo>>) -> Vec<Self> {
-        let mut rows_: Vec<Self> = Vec::new();
+        use sqlx::{Column as _, Row as _};
+
+        // fetch_all has already buffered every row, so size the output exactly once
+        let mut rows_: Vec<Self> = Vec::with_capacity(rows.len());
 
         for row in rows {
-            let mut map = HashMap::new();
+            let mut map = HashMap::with_capacity(row.len());
 
             // Extract columns from the main table
             let main_columns = S::get_all_columns();
             for column in main_columns {
-                let value = Self::extract_column_value(&row, &column.name, &column.data_type);
+                // Checked first, as some drivers decode NULL into a default value
+                let value = if is_null_column(&row, column.name) {
+                    Some(Value::Null)
+                } else {
+                    Self::extract_column_value(&row, &column.name, &column.data_type)
+                };
                 if let Some(value) = value {
                     map.insert(column.name.to_string(), value);
                 }
             }
 
             if joins.is_some() {
-                for join in joins.unwrap() {
+                // Aliased joins are read below, under their `alias.column` names
+                for join in joins.unwrap().iter().filter(|join| join.alias.is_none()) {
                     let joined_column = &join.columns;
 
+                    if join.prefix_columns {
+                        read_prefixed_columns(&row, join, &mut map, Self::extract_column_value);
+                        continue;
+                    }
+
                     for column in joined_column {
-                        let value =
-                            Self::extract_column_value(&row, &column.name, &column.data_type);
+                        // Unmatched rows of an outer join come back as NULL
+                        let value = if is_null_column(&row, column.name) {
+                            Some(Value::Null)
+                        } else {
+                            Self::extract_column_value(&row, &column.name, &column.data_type)
+                        };
                         if let Some(value) = value {
                             if map.contains_key(column.name) {
                                 let fq_key = format!("{}.{}", join.table_name, column.name);
@@ -174,6 +513,21 @@ impl<S: Schema + Debug> Row<S> {
                 }
             }
 
+            // Keep columns without a schema definition (e.g. raw select expressions)
+            for column in row.columns() {
+                if map.contains_key(column.name()) {
+                    continue;
+                }
+                let value = if is_null_column(&row, column.name()) {
+                    Some(Value::Null)
+                } else {
+                    extract_untyped_value(&row, column.name())
+                };
+                if let Some(value) = value {
+                    map.insert(column.name().to_string(), value);
+                }
+            }
+
             rows_.push(Self {
                 data: map,
                 _phanton: PhantomData,
@@ -230,27 +584,44 @@ impl<S: Schema + Debug> Row<S> {
     /// let pg_rows: Vec<PgRow> = sqlx::query("SELECT * FROM users").fetch_all(&pool).await?;
     /// let lume_rows: Vec<Row<User>> = Row::from_postgres_row(pg_rows);
     pub(crate) fn from_postgres_row(rows: Vec<PgRow>, joins: Option<&Vec<JoinInfo>>) -> Vec<Self> {
-        let mut rows_: Vec<Self> = Vec::new();
+        use sqlx::{Column as _, Row as _};
+
+        let mut rows_: Vec<Self> = Vec::with_capacity(rows.len());
 
         for row in rows {
-            let mut map = HashMap::new();
+            let mut map = HashMap::with_capacity(row.len());
 
             // Extract columns from the main table
             let main_columns = S::get_all_columns();
             for column in main_columns {
-                let value = Self::extract_column_value(&row, &column.name, &column.data_type);
+                // Checked first, as some drivers decode NULL into a default value
+                let value = if is_null_column(&row, column.name) {
+                    Some(Value::Null)
+                } else {
+                    Self::extract_column_value(&row, &column.name, &column.data_type)
+                };
                 if let Some(value) = value {
                     map.insert(column.name.to_string(), value);
                 }
             }
 
             if joins.is_some() {
-                for join in joins.unwrap() {
+                // Aliased joins are read below, under their `alias.column` names
+                for join in joins.unwrap().iter().filter(|join| join.alias.is_none()) {
                     let joined_column = &join.columns;
 
+                    if join.prefix_columns {
+                        read_prefixed_columns(&row, join, &mut map, Self::extract_column_value);
+                        continue;
+                    }
+
                     for column in joined_column {
-                        let value =
-                            Self::extract_column_value(&row, &column.name, &column.data_type);
+                        // Unmatched rows of an outer join come back as NULL
+                        let value = if is_null_column(&row, column.name) {
+                            Some(Value::Null)
+                        } else {
+                            Self::extract_column_value(&row, &column.name, &column.data_type)
+                        };
                         if let Some(value) = value {
                             if map.contains_key(column.name) {
                                 let fq_key = format!("{}.{}", join.table_name, column.name);
@@ -263,6 +634,21 @@ impl<S: Schema + Debug> Row<S> {
                 }
             }
 
+            // Keep columns without a schema definition (e.g. raw select expressions)
+            for column in row.columns() {
+                if map.contains_key(column.name()) {
+                    continue;
+                }
+                let value = if is_null_column(&row, column.name()) {
+                    Some(Value::Null)
+                } else {
+                    extract_untyped_value(&row, column.name())
+                };
+                if let Some(value) = value {
+                    map.insert(column.name().to_string(), value);
+                }
+            }
+
             rows_.push(Self {
                 data: map,
                 _phanton: PhantomData,
@@ -277,27 +663,44 @@ impl<S: Schema + Debug> Row<S> {
         rows: Vec<SqliteRow>,
         joins: Option<&Vec<JoinInfo>>,
     ) -> Vec<Self> {
-        let mut rows_: Vec<Self> = Vec::new();
+        use sqlx::{Column as _, Row as _};
+
+        let mut rows_: Vec<Self> = Vec::with_capacity(rows.len());
 
         for row in rows {
-            let mut map = HashMap::new();
+            let mut map = HashMap::with_capacity(row.len());
 
             // Extract columns from the main table
             let main_columns = S::get_all_columns();
             for column in main_columns {
-                let value = Self::extract_column_value(&row, &column.name, &column.data_type);
+                // Checked first, as some drivers decode NULL into a default value
+                let value = if is_null_column(&row, column.name) {
+                    Some(Value::Null)
+                } else {
+                    Self::extract_column_value(&row, &column.name, &column.data_type)
+                };
                 if let Some(value) = value {
                     map.insert(column.name.to_string(), value);
                 }
             }
 
             if joins.is_some() {
-                for join in joins.unwrap() {
+                // Aliased joins are read below, under their `alias.column` names
+                for join in joins.unwrap().iter().filter(|join| join.alias.is_none()) {
                     let joined_column = &join.columns;
 
+                    if join.prefix_columns {
+                        read_prefixed_columns(&row, join, &mut map, Self::extract_column_value);
+                        continue;
+                    }
+
                     for column in joined_column {
-                        let value =
-                            Self::extract_column_value(&row, &column.name, &column.data_type);
+                        // Unmatched rows of an outer join come back as NULL
+                        let value = if is_null_column(&row, column.name) {
+                            Some(Value::Null)
+                        } else {
+                            Self::extract_column_value(&row, &column.name, &column.data_type)
+                        };
                         if let Some(value) = value {
                             if map.contains_key(column.name) {
                                 let fq_key = format!("{}.{}", join.table_name, column.name);
@@ -310,6 +713,21 @@ impl<S: Schema + Debug> Row<S> {
                 }
             }
 
+            // Keep columns without a schema definition (e.g. raw select expressions)
+            for column in row.columns() {
+                if map.contains_key(column.name()) {
+                    continue;
+                }
+                let value = if is_null_column(&row, column.name()) {
+                    Some(Value::Null)
+                } else {
+                    extract_untyped_value(&row, column.name())
+                };
+                if let Some(value) = value {
+                    map.insert(column.name().to_string(), value);
+                }
+            }
+
             rows_.push(Self {
                 data: map,
                 _phanton: PhantomData,
@@ -334,6 +752,13 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "JSON" => {
+                // sqlx only decodes JSON through serde, so read the raw text
+                let json = row
+                    .try_get_unchecked::<Option<String>, _>(column_name)
+                    .ok()??;
+                Some(json_to_array(&json).map_or(Value::String(json), Value::Array))
+            }
             "TINYINT" => {
                 if let Ok(val) = row.try_get::<i8, _>(column_name) {
                     Some(Value::Int8(val))
@@ -406,6 +831,10 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "DECIMAL(39,0)" => row
+                .try_get_unchecked::<Option<String>, _>(column_name)
+                .ok()?
+                .and_then(|text| parse_int128(&text)),
             "FLOAT" => {
                 if let Ok(val) = row.try_get::<f32, _>(column_name) {
                     Some(Value::Float32(val))
@@ -433,6 +862,17 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "DATETIME" => {
+                // DATETIME has no offset; values are written as UTC wall-clock time
+                if let Ok(val) = row.try_get::<Option<time::PrimitiveDateTime>, _>(column_name) {
+                    val.map(|val| Value::DateTimeTz(val.assume_utc()))
+                } else if let Ok(val) = row.try_get::<Option<time::OffsetDateTime>, _>(column_name)
+                {
+                    val.map(Value::from)
+                } else {
+                    None
+                }
+            }
             _ => {
                 // Fallback: try to get as string
                 if let Ok(val) = row.try_get::<String, _>(column_name) {
@@ -461,6 +901,13 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "TEXT[]" => extract_array::<String>(row, column_name, Value::String),
+            "BOOLEAN[]" => extract_array::<bool>(row, column_name, Value::Bool),
+            "SMALLINT[]" => extract_array::<i16>(row, column_name, Value::Int16),
+            "INT[]" => extract_array::<i32>(row, column_name, Value::Int32),
+            "BIGINT[]" => extract_array::<i64>(row, column_name, Value::Int64),
+            "REAL[]" => extract_array::<f32>(row, column_name, Value::Float32),
+            "DOUBLE PRECISION[]" => extract_array::<f64>(row, column_name, Value::Float64),
             "SMALLINT" => {
                 if let Ok(val) = row.try_get::<i16, _>(column_name) {
                     Some(Value::Int16(val))
@@ -488,6 +935,10 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "DECIMAL(39,0)" => row
+                .try_get::<Option<PgNumeric>, _>(column_name)
+                .ok()??
+                .into_value(),
             "FLOAT" => {
                 if let Ok(val) = row.try_get::<f32, _>(column_name) {
                     Some(Value::Float32(val))
@@ -515,12 +966,22 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "DATETIME" => row
+                .try_get::<Option<time::OffsetDateTime>, _>(column_name)
+                .ok()?
+                .map(Value::from),
             _ => {
                 // Fallback: try to get as string
                 if let Ok(val) = row.try_get::<String, _>(column_name) {
                     Some(Value::String(val))
                 } else if let Ok(val) = row.try_get::<Option<String>, _>(column_name) {
                     val.map(Value::String)
+                } else if Self::is_enum_column(row, column_name) {
+                    // Enum labels are sent as text, but sqlx only decodes String from text types
+                    row.try_get_unchecked::<Option<String>, _>(column_name)
+                        .ok()
+                        .flatten()
+                        .map(Value::String)
                 } else {
                     None
                 }
@@ -528,6 +989,21 @@ impl<S: Schema + Debug> Row<S> {
         }
     }
 
+    #[cfg(feature = "postgres")]
+    /// Whether a column holds a native enum type, as created for `native_enum()` columns.
+    fn is_enum_column(row: &PgRow, column_name: &str) -> bool {
+        use sqlx::{Column as _, Row as _};
+        row.columns()
+            .iter()
+            .find(|column| column.name() == column_name)
+            .is_some_and(|column| {
+                matches!(
+                    column.type_info().kind(),
+                    sqlx::postgres::PgTypeKind::Enum(_)
+                )
+            })
+    }
+
     #[cfg(feature = "sqlite")]
     /// Extracts a column value from a SQLite row based on column name and data type
     fn extract_column_value(row: &SqliteRow, column_name: &str, data_type: &str) -> Option<Value> {
@@ -543,6 +1019,12 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "JSON" => {
+                let json = row
+                    .try_get_unchecked::<Option<String>, _>(column_name)
+                    .ok()??;
+                Some(json_to_array(&json).map_or(Value::String(json), Value::Array))
+            }
             "SMALLINT" => {
                 if let Ok(val) = row.try_get::<i16, _>(column_name) {
                     Some(Value::Int16(val))
@@ -570,6 +1052,10 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "DECIMAL(39,0)" => row
+                .try_get::<Option<String>, _>(column_name)
+                .ok()?
+                .and_then(|text| parse_int128(&text)),
             "FLOAT" => {
                 if let Ok(val) = row.try_get::<f32, _>(column_name) {
                     Some(Value::Float32(val))
@@ -597,6 +1083,17 @@ impl<S: Schema + Debug> Row<S> {
                     None
                 }
             }
+            "DATETIME" => {
+                // Stored as text in UTC; values written elsewhere may carry an offset
+                if let Ok(val) = row.try_get::<Option<time::PrimitiveDateTime>, _>(column_name) {
+                    val.map(|val| Value::DateTimeTz(val.assume_utc()))
+                } else if let Ok(val) = row.try_get::<Option<time::OffsetDateTime>, _>(column_name)
+                {
+                    val.map(Value::from)
+                } else {
+                    None
+                }
+            }
             _ => {
                 // Fallback: try to get as string
                 if let Ok(val) = row.try_get::<String, _>(column_name) {
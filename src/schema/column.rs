@@ -8,7 +8,10 @@
 
 use std::fmt::{Debug, Display};
 
-use crate::schema::{ColumnConstraint, ColumnValidators, DefaultValueEnum, GeneratedColumn};
+use crate::schema::{
+    ColumnConstraint, ColumnValidators, DefaultValueEnum, GeneratedColumn, GeneratedStorage,
+    is_nullable_type,
+};
 
 /// A type-safe column definition with constraints and metadata.
 ///
@@ -83,11 +86,14 @@ impl<T> Column<T> {
 
     /// Sets a default value for this column.
     ///
+    /// The value must have the column's type (or be a `&str` for a string
+    /// column), so `default_value(true)` on an `i32` column doesn't compile.
+    ///
     /// # Arguments
     ///
     /// - `value`: The default value to set
-    pub fn default_value<K: Into<T>>(mut self, value: K) -> Self {
-        self.default_value = Some(DefaultValueEnum::Value(value.into()));
+    pub fn default_value<K: IntoDefault<T>>(mut self, value: K) -> Self {
+        self.default_value = Some(DefaultValueEnum::Value(value.into_default()));
         self
     }
 
@@ -164,6 +170,15 @@ impl<T> Column<T> {
         self
     }
 
+    /// Checks that the value isn't already taken before inserting a row.
+    ///
+    /// Runs a `SELECT EXISTS` against the table on every insert, which costs
+    /// an extra round trip. Pair it with `unique()` to also enforce it in the database.
+    pub fn unique_check(mut self) -> Self {
+        self.validators.push(ColumnValidators::Unique);
+        self
+    }
+
     /// Adds a UNIQUE constraint to this column.
     pub fn unique(mut self) -> Self {
         self.constraints.push(ColumnConstraint::Unique);
@@ -178,12 +193,56 @@ impl<T> Column<T> {
         self
     }
 
+    /// Declares a string column as `VARCHAR(length)` on every backend, instead
+    /// of the default `VARCHAR(255)` (MySQL, SQLite) or `TEXT` (Postgres).
+    ///
+    /// Has no effect on columns of other types. Pair it with
+    /// [`max_len`](Self::max_len) to reject longer values before they reach
+    /// the database.
+    pub fn length(mut self, length: u32) -> Self {
+        self.constraints.push(ColumnConstraint::Length(length));
+        self
+    }
+
     /// Adds an index to this column.
     pub fn indexed(mut self) -> Self {
         self.constraints.push(ColumnConstraint::Indexed);
         self
     }
 
+    /// Adds an index to this column named `name`, instead of the generated
+    /// `idx_{table}_{column}`.
+    ///
+    /// The name is used verbatim, so it must be unique within the database
+    /// and fit the backend's identifier length limit.
+    pub fn indexed_named(mut self, name: &'static str) -> Self {
+        self.constraints.push(ColumnConstraint::IndexedNamed(name));
+        self
+    }
+
+    /// Adds an index on an expression over this column, such as `LOWER(email)`.
+    ///
+    /// Pairs with the `ilike` filter for case-insensitive lookups that can use
+    /// an index. MySQL has no functional indexes before 8.0.13, so it indexes
+    /// the plain column instead; its default collations already compare
+    /// case-insensitively.
+    pub fn indexed_expr(mut self, expression: &'static str) -> Self {
+        self.constraints
+            .push(ColumnConstraint::IndexedExpr(expression));
+        self
+    }
+
+    /// Adds a partial index on this column, covering only the rows matching
+    /// `predicate`, such as `deleted_at IS NULL`.
+    ///
+    /// MySQL has no partial indexes, so it indexes every row instead and
+    /// prints a warning when the DDL is generated.
+    pub fn indexed_where(mut self, predicate: &'static str) -> Self {
+        self.constraints
+            .push(ColumnConstraint::IndexedWhere(predicate));
+        self
+    }
+
     /// Enables AUTO_INCREMENT on this column (MySQL).
     pub fn auto_increment(mut self) -> Self {
         self.constraints.push(ColumnConstraint::AutoIncrement);
@@ -215,34 +274,187 @@ impl<T> Column<T> {
         self
     }
 
-    /// Marks the column as INVISIBLE (MySQL 8).
+    /// Marks this integer column as the table's optimistic-locking version.
+    ///
+    /// Every [`Update`](crate::operations::update::Update) increments it. When the
+    /// update struct sets the version, the update only applies to rows still at
+    /// that version, and fails with
+    /// [`DatabaseError::StaleData`](crate::database::error::DatabaseError::StaleData)
+    /// if another writer got there first.
+    pub fn version(mut self) -> Self {
+        self.constraints.push(ColumnConstraint::Version);
+        self
+    }
+
+    /// Marks the column as INVISIBLE (MySQL 8.0.23+), hiding it from `SELECT *`.
+    ///
+    /// Useful for adding columns without breaking existing queries during a
+    /// migration. Postgres and SQLite have no invisible columns and ignore this.
     pub fn invisible(mut self) -> Self {
         self.constraints.push(ColumnConstraint::Invisible);
         self
     }
 
+    /// Stores the column as a native enum limited to `values`.
+    ///
+    /// MySQL declares it inline as `ENUM(...)`. Postgres creates the type
+    /// `type_name` with `CREATE TYPE ... AS ENUM` before the table, and casts
+    /// values bound for the column in inserts, updates and filters to it. SQLite has no enum types, so it uses `TEXT` with
+    /// a `CHECK` listing the allowed values.
+    pub fn native_enum(mut self, type_name: &'static str, values: &'static [&'static str]) -> Self {
+        self.constraints
+            .push(ColumnConstraint::NativeEnum(type_name, values));
+        self
+    }
+
+    /// Returns the type name given to [`native_enum`](Self::native_enum), if any.
+    pub(crate) fn native_enum_type(&self) -> Option<&'static str> {
+        crate::schema::native_enum_type(&self.constraints)
+    }
+
     /// Adds a CHECK constraint expression (MySQL 8).
     pub fn check(mut self, expression: &'static str) -> Self {
         self.constraints.push(ColumnConstraint::Check(expression));
         self
     }
 
-    /// Defines this column as a VIRTUAL generated column (MySQL) with the given expression.
-    pub fn generated_virtual(mut self, expression: &'static str) -> Self {
+    /// Defines this column as a generated column computed from `expression`.
+    ///
+    /// Emits `GENERATED ALWAYS AS (expression) VIRTUAL|STORED`. Postgres only
+    /// supports stored generated columns, so `storage` is always `STORED` there.
+    /// Generated columns are skipped when inserting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lume::schema::{Column, GeneratedStorage};
+    ///
+    /// let total = Column::<f64>::new("total", "orders")
+    ///     .generated("price * quantity", GeneratedStorage::Stored);
+    /// ```
+    pub fn generated(mut self, expression: &'static str, storage: GeneratedStorage) -> Self {
+        let generated = match storage {
+            GeneratedStorage::Virtual => GeneratedColumn::Virtual(expression),
+            GeneratedStorage::Stored => GeneratedColumn::Stored(expression),
+        };
         self.constraints
-            .push(ColumnConstraint::Generated(GeneratedColumn::Virtual(
-                expression,
-            )));
+            .push(ColumnConstraint::Generated(generated));
         self
     }
 
-    /// Defines this column as a STORED generated column (MySQL) with the given expression.
-    pub fn generated_stored(mut self, expression: &'static str) -> Self {
-        self.constraints
-            .push(ColumnConstraint::Generated(GeneratedColumn::Stored(
-                expression,
-            )));
-        self
+    /// Defines this column as a VIRTUAL generated column with the given expression.
+    pub fn generated_virtual(self, expression: &'static str) -> Self {
+        self.generated(expression, GeneratedStorage::Virtual)
+    }
+
+    /// Defines this column as a STORED generated column with the given expression.
+    pub fn generated_stored(self, expression: &'static str) -> Self {
+        self.generated(expression, GeneratedStorage::Stored)
+    }
+
+    /// Returns whether the column accepts `NULL`, matching the generated DDL.
+    ///
+    /// - `primary_key()` columns are never nullable
+    /// - `Option<T>` columns are always nullable, even with `not_null()`, since
+    ///   the field can hold `None`
+    /// - Other columns are nullable unless marked `not_null()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lume::schema::Column;
+    ///
+    /// assert!(Column::<String>::new("bio", "users").is_nullable());
+    /// assert!(!Column::<String>::new("name", "users").not_null().is_nullable());
+    /// assert!(!Column::<i32>::new("id", "users").primary_key().is_nullable());
+    /// ```
+    pub fn is_nullable(&self) -> bool
+    where
+        T: 'static,
+    {
+        if self.constraints.contains(&ColumnConstraint::PrimaryKey) {
+            return false;
+        }
+
+        is_nullable_type::<T>() || !self.constraints.contains(&ColumnConstraint::NonNullable)
+    }
+
+    /// Returns the column name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the name of the table this column belongs to.
+    pub fn table_name(&self) -> &'static str {
+        self.table_name
+    }
+
+    /// Returns this column qualified by a table alias instead of its table name.
+    ///
+    /// Pass a reference to it to filters, ordering and join conditions to
+    /// target one side of a self-join, whose joined side is named with
+    /// [`Query::join_alias`](crate::operations::query::Query::join_alias).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lume::schema::Column;
+    ///
+    /// static NAME: Column<String> = Column::new("name", "Employee");
+    ///
+    /// let manager_name = NAME.as_alias("mgr");
+    /// assert_eq!(manager_name.to_string(), "mgr.name");
+    /// ```
+    pub fn as_alias(&self, alias: &'static str) -> Column<T> {
+        Column {
+            name: self.name,
+            default_value: None,
+            table_name: alias,
+            comment: self.comment,
+            charset: self.charset,
+            collate: self.collate,
+            validators: self.validators.clone(),
+            constraints: self.constraints.clone(),
+        }
+    }
+
+    /// Returns the column's default value, if one was set.
+    pub fn get_default(&self) -> Option<&DefaultValueEnum<T>> {
+        self.default_value.as_ref()
+    }
+
+    /// Returns the column comment set with [`comment`](Self::comment).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lume::schema::Column;
+    ///
+    /// let bio = Column::<String>::new("bio", "users").comment("Shown on the profile");
+    /// assert_eq!(bio.get_comment(), Some("Shown on the profile"));
+    /// ```
+    pub fn get_comment(&self) -> Option<&'static str> {
+        self.comment
+    }
+
+    /// Returns the character set set with [`charset`](Self::charset).
+    pub fn get_charset(&self) -> Option<&'static str> {
+        self.charset
+    }
+
+    /// Returns the collation set with [`collate`](Self::collate).
+    pub fn get_collate(&self) -> Option<&'static str> {
+        self.collate
+    }
+
+    /// Returns the validators run against this column's values.
+    pub fn get_validators(&self) -> &[ColumnValidators] {
+        &self.validators
+    }
+
+    /// Returns the constraints applied to this column.
+    pub fn get_constraints(&self) -> &[ColumnConstraint] {
+        &self.constraints
     }
 
     #[doc(hidden)]
@@ -285,3 +497,59 @@ impl<T> Column<T> {
         self.collate
     }
 }
+
+/// A value accepted by [`Column::default_value`] for a `Column<T>`.
+///
+/// Only `T` itself converts, plus `&str` for string columns and the inner
+/// type of an `Option<T>` column. Unlike `Into<T>`, this rejects lossless but
+/// surprising conversions such as a `bool` default on an integer column.
+pub trait IntoDefault<T> {
+    /// Converts the value into the column's type.
+    fn into_default(self) -> T;
+}
+
+impl<T> IntoDefault<T> for T {
+    fn into_default(self) -> T {
+        self
+    }
+}
+
+impl<T> IntoDefault<Option<T>> for T {
+    fn into_default(self) -> Option<T> {
+        Some(self)
+    }
+}
+
+impl IntoDefault<String> for &str {
+    fn into_default(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoDefault<Option<String>> for &str {
+    fn into_default(self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// A column of any type, so that columns of different types fit in one list.
+///
+/// Implemented by every [`Column`]; see
+/// [`Query::columns`](crate::operations::query::Query::columns).
+pub trait AnyColumn {
+    /// Returns the column name.
+    fn name(&self) -> &'static str;
+
+    /// Returns the name of the table this column belongs to.
+    fn table_name(&self) -> &'static str;
+}
+
+impl<T> AnyColumn for Column<T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn table_name(&self) -> &'static str {
+        self.table_name
+    }
+}
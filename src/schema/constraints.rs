@@ -14,6 +14,16 @@ pub enum ColumnConstraint {
     PrimaryKey,
     /// Column has an index created for faster lookups.
     Indexed,
+    /// Column has an index with the given name instead of a generated one.
+    IndexedNamed(&'static str),
+    /// String column declared as `VARCHAR(n)` instead of the dialect's default
+    /// string type.
+    Length(u32),
+    /// Column has an index on an expression over it, such as `LOWER(email)`.
+    IndexedExpr(&'static str),
+    /// Column has a partial index covering only rows matching the predicate,
+    /// such as `deleted_at IS NULL`.
+    IndexedWhere(&'static str),
     /// Column value is automatically incremented (AUTO_INCREMENT in MySQL).
     AutoIncrement,
     /// Column is hidden from SELECT * queries (MySQL 8+ INVISIBLE).
@@ -24,9 +34,16 @@ pub enum ColumnConstraint {
     Check(&'static str),
     /// Column is a generated column (VIRTUAL or STORED).
     Generated(GeneratedColumn),
+    /// Column uses a native enum type with the given type name and values.
+    NativeEnum(&'static str, &'static [&'static str]),
+    /// Column is an optimistic-locking version, bumped on every update.
+    Version,
 }
 
-/// MySQL generated column variants
+/// Generated column variants, each carrying the column's expression.
+///
+/// Postgres only supports stored generated columns, so [`Virtual`](Self::Virtual)
+/// columns are emitted as `STORED` there.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GeneratedColumn {
     /// Virtual generated column (not stored)
@@ -43,3 +60,34 @@ impl Display for GeneratedColumn {
         }
     }
 }
+
+/// How a generated column's value is kept, for [`Column::generated`](crate::schema::Column::generated).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeneratedStorage {
+    /// Computed when read (not supported by Postgres, which falls back to `STORED`)
+    Virtual,
+    /// Computed when written and persisted
+    Stored,
+}
+
+/// A table-level `UNIQUE` constraint spanning several columns.
+///
+/// Declared with `#[unique(a, b)]` on a table in [`define_schema!`](crate::define_schema)
+/// and emitted as `CONSTRAINT uq_<table>_<a>_<b> UNIQUE (a, b)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniqueConstraint {
+    /// The constraint name, `uq_<table>_<columns>`.
+    pub name: String,
+    /// The columns that must be unique together, in declaration order.
+    pub columns: Vec<&'static str>,
+}
+
+impl UniqueConstraint {
+    /// Creates a composite unique constraint on `columns` of `table`.
+    pub fn new(table: &str, columns: Vec<&'static str>) -> Self {
+        Self {
+            name: format!("uq_{}_{}", table, columns.join("_")),
+            columns,
+        }
+    }
+}
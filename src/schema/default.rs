@@ -3,6 +3,11 @@ use std::any::TypeId;
 
 use time::macros::format_description;
 
+use crate::dialects::get_dialect;
+#[cfg(not(feature = "postgres"))]
+use crate::helpers::array_to_json;
+#[cfg(not(feature = "postgres"))]
+use crate::schema::Value;
 use crate::schema::{Column, CustomSqlType, Uuid};
 
 /// Trait for converting column default values to SQL representation.
@@ -48,6 +53,15 @@ fn postgres_array_type<T: 'static>() -> &'static str {
     }
 }
 
+/// Renders an array default as the JSON text MySQL and SQLite store arrays as,
+/// like the values bound for them. MySQL only takes a default on a `JSON`
+/// column as an expression, so the literal is parenthesized; SQLite accepts
+/// the same form.
+#[cfg(not(feature = "postgres"))]
+fn json_array_default(values: &[Value]) -> String {
+    format!("({})", get_dialect().string_literal(&array_to_json(values)))
+}
+
 // Macro to implement DefaultToSql for numeric types and their Vec variants
 macro_rules! impl_default_to_sql_numeric {
     ($($t:ty),*) => {
@@ -67,9 +81,7 @@ macro_rules! impl_default_to_sql_numeric {
                 fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
                     self.__internal_get_default().map(|v| match v {
                         DefaultValueEnum::Value(vec) => {
-                            let items = vec.iter()
-                                 .map(|item| item.to_string())
-                                 .collect::<Vec<_>>();
+                            let items = vec.iter().map(|item| item.to_string()).collect::<Vec<_>>();
                             let array_sql = if items.is_empty() {
                                 format!("ARRAY[]::{}[]", postgres_array_type::<$t>())
                             } else {
@@ -88,6 +100,20 @@ macro_rules! impl_default_to_sql_numeric {
                 }
             }
 
+            #[cfg(not(feature = "postgres"))]
+            impl DefaultToSql for Column<Vec<$t>> {
+                fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
+                    self.__internal_get_default().map(|v| match v {
+                        DefaultValueEnum::Value(vec) => {
+                            DefaultValueEnum::Value(json_array_default(
+                                &vec.iter().cloned().map(Value::from).collect::<Vec<_>>(),
+                            ))
+                        }
+                        DefaultValueEnum::CurrentTimestamp => DefaultValueEnum::CurrentTimestamp,
+                        DefaultValueEnum::Random => DefaultValueEnum::Random,
+                    })
+                }
+            }
         )*
     };
 }
@@ -95,6 +121,25 @@ macro_rules! impl_default_to_sql_numeric {
 // Implement for all numeric types
 impl_default_to_sql_numeric!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
 
+// 128-bit integers are quoted, as SQLite reads a bare literal past 64 bits as a lossy REAL
+macro_rules! impl_default_to_sql_int128 {
+    ($($t:ty),*) => {
+        $(
+            impl DefaultToSql for Column<$t> {
+                fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
+                    self.__internal_get_default().map(|v| match v {
+                        DefaultValueEnum::Value(val) => DefaultValueEnum::Value(format!("'{}'", val)),
+                        DefaultValueEnum::CurrentTimestamp => DefaultValueEnum::CurrentTimestamp,
+                        DefaultValueEnum::Random => DefaultValueEnum::Random,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_default_to_sql_int128!(i128, u128);
+
 // Implement for String (needs special escaping)
 impl DefaultToSql for Column<String> {
     fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
@@ -125,25 +170,9 @@ impl DefaultToSql for Column<Uuid> {
 impl DefaultToSql for Column<Vec<String>> {
     fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
         self.__internal_get_default().map(|v| match v {
-            DefaultValueEnum::Value(vec) => {
-                let mut json = String::from("[");
-                for (i, item) in vec.iter().enumerate() {
-                    if i > 0 {
-                        json.push(',');
-                    }
-                    json.push('"');
-                    for ch in item.chars() {
-                        if ch == '"' || ch == '\\' {
-                            json.push('\\');
-                        }
-                        json.push(ch);
-                    }
-                    json.push('"');
-                }
-                json.push(']');
-
-                DefaultValueEnum::Value(format!("'{}'", json))
-            }
+            DefaultValueEnum::Value(vec) => DefaultValueEnum::Value(json_array_default(
+                &vec.iter().cloned().map(Value::from).collect::<Vec<_>>(),
+            )),
             DefaultValueEnum::CurrentTimestamp => DefaultValueEnum::CurrentTimestamp,
             DefaultValueEnum::Random => DefaultValueEnum::Random,
         })
@@ -161,7 +190,10 @@ impl DefaultToSql for Column<time::OffsetDateTime> {
                     "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]"
                 );
                 if let DefaultValueEnum::Value(datetime) = datetime {
-                    let mysql_datetime = datetime.format(&format).unwrap();
+                    let mysql_datetime = datetime
+                        .to_offset(time::UtcOffset::UTC)
+                        .format(&format)
+                        .unwrap();
                     Some(DefaultValueEnum::Value(format!("'{}'", mysql_datetime)))
                 } else {
                     None
@@ -199,12 +231,12 @@ impl DefaultToSql for Column<Vec<String>> {
     }
 }
 
-// Implement for bool (needs TRUE/FALSE)
+// Implement for bool (the literal depends on the dialect)
 impl DefaultToSql for Column<bool> {
     fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
         self.__internal_get_default().map(|v| match v {
             DefaultValueEnum::Value(v) => {
-                DefaultValueEnum::Value(if *v { "TRUE" } else { "FALSE" }.to_string())
+                DefaultValueEnum::Value(get_dialect().bool_literal(*v).to_string())
             }
             DefaultValueEnum::CurrentTimestamp => DefaultValueEnum::CurrentTimestamp,
             DefaultValueEnum::Random => DefaultValueEnum::Random,
@@ -240,6 +272,26 @@ impl DefaultToSql for Column<Vec<bool>> {
     }
 }
 
+// Nullable columns render their default through the inner type. A `None`
+// default is the same as no default, since NULL is already implied.
+impl<T> DefaultToSql for Column<Option<T>>
+where
+    T: Clone,
+    Column<T>: DefaultToSql,
+{
+    fn default_to_sql(&self) -> Option<DefaultValueEnum<String>> {
+        let inner = Column::<T>::new(self.name, "");
+        let inner = match self.__internal_get_default()? {
+            DefaultValueEnum::Value(Some(v)) => inner.default_value(v.clone()),
+            DefaultValueEnum::Value(None) => return None,
+            DefaultValueEnum::CurrentTimestamp => inner.default_now(),
+            DefaultValueEnum::Random => inner.default_random(),
+        };
+
+        inner.default_to_sql()
+    }
+}
+
 // Generic implementation for user-defined types (enums, etc.)
 // Users must implement CustomSqlType for their types to use this
 impl<T> DefaultToSql for Column<T>
@@ -12,6 +12,53 @@ macro_rules! __lume_option_type {
     ($ty:ty, [ $head:tt $($tail:tt)* ]) => { $crate::__lume_option_type!($ty, [ $($tail)* ]) };
 }
 
+/// Helper macro: converts a field to a `Value`, unwrapping the `Option` that
+/// `__lume_option_type!` adds for `default_value(...)` and `auto_increment()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lume_field_value {
+    ($value:expr) => { $crate::schema::convert_to_value($value) };
+    ($value:expr, []) => { $crate::schema::convert_to_value($value) };
+    ($value:expr, [ default_value ( $($inner:tt)* ) $($tail:tt)* ]) => { $crate::schema::convert_option_to_value($value) };
+    ($value:expr, [ auto_increment ( ) $($tail:tt)* ]) => { $crate::schema::convert_option_to_value($value) };
+    ($value:expr, [ $head:tt $($tail:tt)* ]) => { $crate::__lume_field_value!($value, [ $($tail)* ]) };
+}
+
+/// Helper macro: collects the `#[unique(...)]` table attributes of a schema
+/// into its composite `UNIQUE` constraints.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lume_unique_constraints {
+    ($table:ident; [ $($out:expr),* ]) => { vec![ $($out),* ] };
+    ($table:ident; [ $($out:expr),* ] [ unique( $($col:ident),+ $(,)? ) ] $($rest:tt)*) => {
+        $crate::__lume_unique_constraints!($table; [
+            $($out,)*
+            $crate::schema::UniqueConstraint::new(
+                stringify!($table),
+                vec![$($table::$col().__internal_name()),+],
+            )
+        ] $($rest)*)
+    };
+    // Other table attributes are handled by their own helpers
+    ($table:ident; [ $($out:expr),* ] [ soft_delete ] $($rest:tt)*) => {
+        $crate::__lume_unique_constraints!($table; [ $($out),* ] $($rest)*)
+    };
+}
+
+/// Helper macro: returns the `deleted_at` column's name if the table attributes
+/// contain `#[soft_delete]`; otherwise `None`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lume_soft_delete_column {
+    ($table:ident;) => { None };
+    ($table:ident; [ soft_delete ] $($rest:tt)*) => {
+        Some($table::deleted_at().__internal_name())
+    };
+    ($table:ident; [ $($other:tt)* ] $($rest:tt)*) => {
+        $crate::__lume_soft_delete_column!($table; $($rest)*)
+    };
+}
+
 /// Defines a database schema with type-safe columns and constraints.
 ///
 /// This macro creates a schema struct that implements the [`Schema`] trait
@@ -37,8 +84,20 @@ macro_rules! __lume_option_type {
 /// - `not_null()` - Makes the column NOT NULL
 /// - `unique()` - Adds a UNIQUE constraint
 /// - `indexed()` - Creates an index on the column
+/// - `indexed_expr(expr)` - Creates an index on an expression such as `LOWER(email)`
+/// - `indexed_where(predicate)` - Creates a partial index on the rows matching `predicate`
+/// - `version()` - Makes the column an optimistic-locking version, bumped on every update
 /// - `default_value(value)` - Sets a default value
 ///
+/// # Table Constraints
+///
+/// - `#[unique(a, b)]` before a table name - Adds a composite `UNIQUE (a, b)`
+///   constraint; repeat it for several constraints
+/// - `#[soft_delete]` before a table name - Makes deletes set the table's
+///   `deleted_at` column instead of removing rows, and hides those rows from
+///   queries unless [`with_deleted`](crate::operations::query::Query::with_deleted)
+///   is called
+///
 /// # Example
 ///
 /// ```rust
@@ -76,6 +135,7 @@ macro_rules! __lume_option_type {
 macro_rules! define_schema {
     (
         $(
+            $(#[ $($attr:tt)* ])*
             $struct_name:ident {
             $(
                 $name:ident: $type:ty $([ $($args:tt)* ])?
@@ -137,7 +197,7 @@ macro_rules! define_schema {
 
                     $(
                         if self.$name.is_some() {
-                            vec.push((stringify!($struct_name.$name), $crate::schema::convert_to_value(&self.$name)));
+                            vec.push((stringify!($struct_name.$name), $crate::schema::convert_option_to_value(&self.$name)));
                         }
                     )*
 
@@ -157,7 +217,7 @@ macro_rules! define_schema {
                     $(
                         map.insert(
                             stringify!($name).to_string(),
-                            $crate::schema::convert_to_value(&self.$name)
+                            $crate::schema::convert_option_to_value(&self.$name)
                         );
                     )*
                     map
@@ -179,6 +239,7 @@ macro_rules! define_schema {
                                 $crate::schema::ColumnInfo {
                                     name: col.__internal_name(),
                                     data_type: type_to_sql_string::<$type>(),
+                                    nullable: $crate::schema::is_nullable_type::<$type>(),
                                     has_default: col.__internal_get_default().is_some(),
                                     default_sql: col.default_to_sql(),
                                     comment: col.__internal_get_comment(),
@@ -195,7 +256,7 @@ macro_rules! define_schema {
         }
 
         paste::paste! {
-            #[derive(Debug)]
+            #[derive(Debug, Clone)]
             pub struct [<Select $struct_name>] {
                 $(
                     pub $name: bool,
@@ -261,6 +322,17 @@ macro_rules! define_schema {
 
                     vec
                 }
+
+                fn select_column(&mut self, name: &str) -> bool {
+                    $(
+                        if name == stringify!($name) {
+                            self.$name = true;
+                            return true;
+                        }
+                    )*
+
+                    false
+                }
             }
         }
 
@@ -288,7 +360,7 @@ macro_rules! define_schema {
                 $(
                     map.insert(
                         stringify!($name).to_string(),
-                        $crate::schema::convert_to_value(&self.$name)
+                        $crate::__lume_field_value!(&self.$name $(, [ $($args)* ])?)
                     );
                 )*
                 map
@@ -301,6 +373,14 @@ macro_rules! define_schema {
                 });
             }
 
+            fn unique_constraints() -> Vec<$crate::schema::UniqueConstraint> {
+                $crate::__lume_unique_constraints!($struct_name; [] $([ $($attr)* ])*)
+            }
+
+            fn soft_delete_column() -> Option<&'static str> {
+                $crate::__lume_soft_delete_column!($struct_name; $([ $($attr)* ])*)
+            }
+
             fn get_all_columns() -> Vec<$crate::schema::ColumnInfo<'static>> {
                 vec![
                     $(
@@ -310,6 +390,7 @@ macro_rules! define_schema {
                             $crate::schema::ColumnInfo {
                                 name: col.__internal_name(),
                                 data_type: type_to_sql_string::<$type>(),
+                                nullable: $crate::schema::is_nullable_type::<$type>(),
                                 has_default: col.__internal_get_default().is_some(),
                                 default_sql: col.default_to_sql(),
                                 comment: col.__internal_get_comment(),
@@ -330,7 +411,7 @@ macro_rules! define_schema {
 #[macro_export]
 /// Macro to generate SQL string conversions for enums used as custom SQL column types.
 ///
-/// This macro implements the [`ToString`] and [`TryFrom<Value>`] traits for an enum,
+/// This macro implements the [`ToString`], [`TryFrom<Value>`] and `From<Enum> for Value` traits for an enum,
 /// enabling seamless conversion between enum variants and their string representations
 /// in the database. It is intended for use with enums representing custom column types
 /// that need to be stored as strings in SQL databases.
@@ -359,6 +440,7 @@ macro_rules! define_schema {
 ///     UserStatus::try_from(Value::String("banned".to_string())),
 ///     Ok(UserStatus::Banned)
 /// );
+/// assert_eq!(Value::from(UserStatus::Inactive), Value::String("inactive".to_string()));
 /// ```
 ///
 /// # Macro Usage
@@ -374,6 +456,7 @@ macro_rules! define_schema {
 /// - Automatically implements:
 ///     - [`ToString`] for the enum mapping each variant to the specified string.
 ///     - [`TryFrom<Value>`] for the enum (converts a string value to the respective variant; returns `Err(())` if not matched).
+///     - `From<Enum> for Value`, so variants can be passed straight to filters like `eq_value`.
 ///
 /// [`ToString`]: std::string::ToString
 /// [`TryFrom<Value>`]: std::convert::TryFrom
@@ -411,6 +494,12 @@ macro_rules! enum_to_sql {
             }
         }
 
+        impl From<$enum_name> for $crate::schema::Value {
+            fn from(value: $enum_name) -> Self {
+                $crate::schema::Value::String(value.to_string())
+            }
+        }
+
         impl $crate::schema::CustomSqlType for $enum_name {}
     };
 }
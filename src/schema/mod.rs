@@ -36,19 +36,25 @@ mod uuid;
 mod validators;
 mod value;
 
+use std::any::TypeId;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
+use crate::dialects::get_dialect;
 pub use crate::schema::constraints::ColumnConstraint;
 pub use crate::schema::constraints::GeneratedColumn;
+pub use crate::schema::constraints::GeneratedStorage;
+pub use crate::schema::constraints::UniqueConstraint;
 pub use crate::schema::default::DefaultToSql;
 pub use crate::schema::default::DefaultValueEnum;
 pub use crate::schema::validators::ColumnValidators;
+pub use crate::schema::validators::ValidationError;
 use crate::table::TableDefinition;
-pub use column::Column;
+pub use column::{AnyColumn, Column, IntoDefault};
 use std::fmt::Debug;
 pub use uuid::Uuid;
 pub use value::Value;
+pub use value::convert_option_to_value;
 pub use value::convert_to_value;
 
 /// Core trait that all database schemas must implement.
@@ -98,6 +104,21 @@ pub trait Schema {
     /// the associated database value. This is primarily used for insert and update
     /// operations to serialize the struct into a form suitable for database interaction.
     fn values(&self) -> HashMap<String, Value>;
+
+    /// Returns the table-level composite `UNIQUE` constraints for this schema.
+    ///
+    /// Populated from `#[unique(...)]` declarations in `define_schema!`; empty by default.
+    fn unique_constraints() -> Vec<UniqueConstraint> {
+        Vec::new()
+    }
+
+    /// Returns the column soft deletes set, if the table is soft-deleted.
+    ///
+    /// `Some("deleted_at")` when the table is declared with `#[soft_delete]` in
+    /// `define_schema!`; `None` by default.
+    fn soft_delete_column() -> Option<&'static str> {
+        None
+    }
 }
 
 /// Trait for extracting updated column values for SQL UPDATE operations.
@@ -150,6 +171,11 @@ pub trait Select {
     ///
     /// This determines which columns will be included in the SQL SELECT clause.
     fn get_selected(self) -> Vec<&'static str>;
+
+    /// Marks the column named `name` as selected.
+    ///
+    /// Returns `false` if the schema has no such column.
+    fn select_column(&mut self, name: &str) -> bool;
 }
 
 /// Metadata information for a database column.
@@ -173,7 +199,9 @@ pub struct ColumnInfo<'a> {
     pub name: &'static str,
     /// The SQL data type (e.g., "INTEGER", "VARCHAR(255)")
     pub data_type: &'static str,
-    /// Whether the column allows NULL values
+    /// Whether the column allows NULL values (the field is declared as `Option<T>`)
+    pub nullable: bool,
+    /// Whether the column has a default value
     pub has_default: bool,
     /// The SQL representation of the default value
     pub default_sql: Option<DefaultValueEnum<String>>,
@@ -205,9 +233,11 @@ pub struct ColumnInfo<'a> {
 /// - `u16` → `"SMALLINT UNSIGNED"`
 /// - `u32` → `"INTEGER UNSIGNED"`
 /// - `u64` → `"BIGINT UNSIGNED"`
+/// - `i128`, `u128` → `"DECIMAL(39,0)"` (`TEXT` on SQLite)
 /// - `f32` → `"FLOAT"`
 /// - `f64` → `"DOUBLE"`
 /// - `bool` → `"BOOLEAN"`
+/// - `Option<T>` → the SQL type of `T`
 /// - All other types → `"TEXT"` (fallback)
 ///
 /// # Example
@@ -220,12 +250,82 @@ pub struct ColumnInfo<'a> {
 /// assert_eq!(type_to_sql_string::<i64>(), "BIGINT");
 /// assert_eq!(type_to_sql_string::<u64>(), "BIGINT UNSIGNED");
 /// assert_eq!(type_to_sql_string::<bool>(), "BOOLEAN");
+/// assert_eq!(type_to_sql_string::<Option<String>>(), "VARCHAR(255)");
 /// ```
 pub fn type_to_sql_string<T: 'static>() -> &'static str {
-    use std::any::TypeId;
+    let type_id = option_inner_type_id::<T>().unwrap_or(TypeId::of::<T>());
+
+    sql_type_for(type_id)
+}
+
+/// Returns `true` if `T` is `Option<U>` for a supported column type `U`.
+///
+/// Columns declared with an `Option<T>` field are nullable in the generated DDL,
+/// even when `not_null()` is present.
+///
+/// # Example
+///
+/// ```rust
+/// use lume::schema::is_nullable_type;
+///
+/// assert!(is_nullable_type::<Option<String>>());
+/// assert!(!is_nullable_type::<String>());
+/// ```
+pub fn is_nullable_type<T: 'static>() -> bool {
+    option_inner_type_id::<T>().is_some()
+}
 
+macro_rules! match_option_type_id {
+    ($type_id:expr, $($t:ty),* $(,)?) => {
+        $(
+            if $type_id == TypeId::of::<Option<$t>>() {
+                return Some(TypeId::of::<$t>());
+            }
+        )*
+    };
+}
+
+/// Returns the `TypeId` of the inner type when `T` is `Option<U>`.
+fn option_inner_type_id<T: 'static>() -> Option<TypeId> {
     let type_id = TypeId::of::<T>();
 
+    match_option_type_id!(
+        type_id,
+        String,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        f32,
+        f64,
+        bool,
+        crate::schema::Uuid,
+        time::Date,
+        time::OffsetDateTime,
+        Vec<String>,
+        Vec<bool>,
+        Vec<i8>,
+        Vec<i16>,
+        Vec<i32>,
+        Vec<i64>,
+        Vec<u8>,
+        Vec<u16>,
+        Vec<u32>,
+        Vec<u64>,
+        Vec<f32>,
+        Vec<f64>,
+    );
+
+    None
+}
+
+fn sql_type_for(type_id: TypeId) -> &'static str {
     #[cfg(feature = "postgres")]
     {
         if type_id == TypeId::of::<Vec<String>>() {
@@ -294,6 +394,8 @@ pub fn type_to_sql_string<T: 'static>() -> &'static str {
         "INT UNSIGNED"
     } else if type_id == TypeId::of::<u64>() {
         "BIGINT UNSIGNED"
+    } else if type_id == TypeId::of::<i128>() || type_id == TypeId::of::<u128>() {
+        "DECIMAL(39,0)"
     } else if type_id == TypeId::of::<f32>() {
         "FLOAT"
     } else if type_id == TypeId::of::<f64>() {
@@ -353,115 +455,44 @@ impl<T: Schema + Debug + Sync + Send + 'static> TableDefinition for SchemaWrappe
         let table_name = self.table_name();
         let columns = self.get_columns();
 
-        let mut sql = format!("CREATE TABLE IF NOT EXISTS {} (\n", table_name);
-
-        let column_definitions: Vec<String> = columns
+        let mut sql: String = columns
             .iter()
-            .map(|col| {
-                let mut def = format!("    {} {}", col.name, col.data_type);
-                let constraints = col.constraints;
-
-                for constraint in constraints {
-                    match constraint {
-                        ColumnConstraint::NonNullable => {
-                            def.push_str(" NOT NULL");
-                        }
-                        ColumnConstraint::Unique => {
-                            def.push_str(" UNIQUE");
-                        }
-                        ColumnConstraint::PrimaryKey => {
-                            def.push_str(" PRIMARY KEY");
-                        }
-                        ColumnConstraint::Indexed => {}
-                        ColumnConstraint::AutoIncrement => {
-                            if is_mysql_integer_type(col.data_type) {
-                                def.push_str(" AUTO_INCREMENT");
-                            }
-                        }
-                        ColumnConstraint::Invisible => {
-                            def.push_str(" INVISIBLE");
-                        }
-                        ColumnConstraint::OnUpdateCurrentTimestamp => {
-                            def.push_str(" ON UPDATE CURRENT_TIMESTAMP");
-                        }
-                        ColumnConstraint::Check(expression) => {
-                            def.push_str(&format!(" CHECK ({})", expression));
-                        }
-                        ColumnConstraint::Generated(generated) => {
-                            def.push_str(&format!(" GENERATED {}", generated));
-                        }
-                    }
-                }
-
-                if col.comment.is_some() {
-                    let escaped = col.comment.unwrap().replace("'", "''");
-                    def.push_str(&format!(" COMMENT '{}'", escaped));
-                }
-
-                if col.charset.is_some() {
-                    def.push_str(&format!(" CHARACTER SET {}", col.charset.unwrap()));
-                }
-
-                if col.collate.is_some() {
-                    def.push_str(&format!(" COLLATE {}", col.collate.unwrap()));
-                }
+            .flat_map(enum_type_sql)
+            .map(|statement| format!("{};\n\n", statement))
+            .collect();
 
-                if col.has_default {
-                    if let Some(ref default) = col.default_sql {
-                        if let DefaultValueEnum::Value(default) = default {
-                            // Skip empty string defaults for primary keys
-                            let is_empty_string = default == "" || default == "''";
-                            let is_primary_key =
-                                col.constraints.contains(&ColumnConstraint::PrimaryKey);
-
-                            if is_primary_key && is_empty_string {
-                                // Skip default for primary keys with empty string
-                            } else {
-                                // Add quotes for string default values if not already quoted
-                                let needs_quotes = col.data_type == "TEXT"
-                                    || col.data_type.starts_with("VARCHAR")
-                                    || col.data_type == "CHAR"
-                                    || col.data_type == "STRING"
-                                    || col.data_type == "UUID";
-                                if needs_quotes
-                                    && !(default.starts_with('\'') && default.ends_with('\''))
-                                {
-                                    def.push_str(&format!(
-                                        " DEFAULT '{}'",
-                                        default.replace('\'', "''")
-                                    ));
-                                } else {
-                                    def.push_str(&format!(" DEFAULT {}", default));
-                                }
-                            }
-                        } else if &DefaultValueEnum::CurrentTimestamp == default {
-                            def.push_str(" DEFAULT CURRENT_TIMESTAMP");
-                        } else if &DefaultValueEnum::Random == default {
-                            def.push_str(" DEFAULT (UUID())");
-                        }
-                    }
-                }
+        let dialect = get_dialect();
+        sql.push_str(&format!(
+            "CREATE TABLE IF NOT EXISTS {} (\n",
+            dialect.quote_qualified(table_name)
+        ));
 
-                def
-            })
+        let mut column_definitions: Vec<String> = columns
+            .iter()
+            .map(|col| format!("    {}", column_definition_sql(col)))
             .collect();
 
+        for unique in T::unique_constraints() {
+            let unique_columns: Vec<String> = unique
+                .columns
+                .iter()
+                .map(|column| dialect.quote_identifier(column))
+                .collect();
+            column_definitions.push(format!(
+                "    CONSTRAINT {} UNIQUE ({})",
+                dialect.quote_identifier(&unique.name),
+                unique_columns.join(", ")
+            ));
+        }
+
         sql.push_str(&column_definitions.join(",\n"));
         sql.push_str("\n);");
 
         // Add indexes
         let indexes: Vec<String> = columns
             .iter()
-            .filter(|col| {
-                col.constraints.contains(&ColumnConstraint::Indexed)
-                    && !col.constraints.contains(&ColumnConstraint::PrimaryKey)
-            })
-            .map(|col| {
-                format!(
-                    "CREATE INDEX idx_{}_{} ON {} ({});",
-                    table_name, col.name, table_name, col.name
-                )
-            })
+            .flat_map(|col| column_index_sql(table_name, col))
+            .map(|index| format!("{};", index))
             .collect();
 
         if !indexes.is_empty() {
@@ -477,6 +508,265 @@ impl<T: Schema + Debug + Sync + Send + 'static> TableDefinition for SchemaWrappe
     }
 }
 
+/// Renders the statement creating a `native_enum()` column's type, for
+/// backends that declare enum types separately from the table.
+pub(crate) fn enum_type_sql(col: &ColumnInfo<'_>) -> Option<String> {
+    col.constraints
+        .iter()
+        .find_map(|constraint| match constraint {
+            ColumnConstraint::NativeEnum(type_name, values) => {
+                get_dialect().create_enum_type_sql(type_name, values)
+            }
+            _ => None,
+        })
+}
+
+/// Returns the type of a `native_enum()` column, which Postgres parameters
+/// must be cast to.
+pub(crate) fn native_enum_type(constraints: &[ColumnConstraint]) -> Option<&'static str> {
+    constraints.iter().find_map(|constraint| match constraint {
+        ColumnConstraint::NativeEnum(type_name, _) => Some(*type_name),
+        _ => None,
+    })
+}
+
+/// Builds the generated name of a column's index, `idx_{table}_{column}{suffix}`.
+///
+/// A schema-qualified table contributes only its own name, since the index
+/// is created in the table's schema. Names over the backend's identifier
+/// length limit are cut short and end in a hash of the full name, so that
+/// they stay distinct.
+fn auto_index_name(table_name: &str, column: &str, suffix: &str) -> String {
+    let table_name = table_name
+        .rsplit_once('.')
+        .map_or(table_name, |(_, table)| table);
+    let name = format!("idx_{}_{}{}", table_name, column, suffix);
+    match get_dialect().max_identifier_length() {
+        Some(max) if name.len() > max => {
+            // FNV-1a, which unlike `DefaultHasher` is stable across Rust releases
+            let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+                (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+            });
+            let mut end = max - 9;
+            while !name.is_char_boundary(end) {
+                end -= 1;
+            }
+            format!("{}_{:08x}", &name[..end], hash)
+        }
+        _ => name,
+    }
+}
+
+/// Renders the `CREATE INDEX` statements for a column's `indexed()`,
+/// `indexed_named()`, `indexed_expr()` and `indexed_where()` constraints,
+/// without a trailing semicolon.
+///
+/// Primary keys are already indexed, so they get none.
+pub(crate) fn column_index_sql(table_name: &str, col: &ColumnInfo<'_>) -> Vec<String> {
+    if col.constraints.contains(&ColumnConstraint::PrimaryKey) {
+        return Vec::new();
+    }
+
+    let dialect = get_dialect();
+    let table = dialect.quote_qualified(table_name);
+    let column = dialect.quote_identifier(col.name);
+
+    let mut indexes = Vec::new();
+    for constraint in col.constraints {
+        match constraint {
+            ColumnConstraint::Indexed => indexes.push(format!(
+                "CREATE INDEX {} ON {} ({})",
+                dialect.quote_identifier(&auto_index_name(table_name, col.name, "")),
+                table,
+                column
+            )),
+            ColumnConstraint::IndexedNamed(name) => indexes.push(format!(
+                "CREATE INDEX {} ON {} ({})",
+                dialect.quote_identifier(name),
+                table,
+                column
+            )),
+            ColumnConstraint::IndexedExpr(expression) => indexes.push(format!(
+                "CREATE INDEX {} ON {} ({})",
+                dialect.quote_identifier(&auto_index_name(table_name, col.name, "_expr")),
+                table,
+                dialect.expression_index_target(col.name, expression)
+            )),
+            ColumnConstraint::IndexedWhere(predicate) => {
+                let mut index = format!(
+                    "CREATE INDEX {} ON {} ({})",
+                    dialect.quote_identifier(&auto_index_name(table_name, col.name, "_partial")),
+                    table,
+                    column
+                );
+                match dialect.partial_index_where(predicate) {
+                    Some(clause) => index.push_str(&clause),
+                    None => eprintln!(
+                        "Warning: Partial indexes are not supported, indexing all rows of {}.{}",
+                        table_name, col.name
+                    ),
+                }
+                indexes.push(index);
+            }
+            _ => {}
+        }
+    }
+
+    indexes
+}
+
+/// Renders the statements adding a column to an existing table: the
+/// `ALTER TABLE ... ADD COLUMN`, then a `CREATE UNIQUE INDEX` for a `unique()`
+/// column, since SQLite can't add a column with an inline `UNIQUE`, and the
+/// column's other indexes. Postgres enum types are not included.
+pub(crate) fn add_column_sql(table_name: &str, col: &ColumnInfo<'_>) -> Vec<String> {
+    let dialect = get_dialect();
+    let mut statements = vec![dialect.adapt_sql(format!(
+        "ALTER TABLE {} ADD COLUMN {}",
+        dialect.quote_qualified(table_name),
+        render_column_definition(col, false)
+    ))];
+
+    if col.constraints.contains(&ColumnConstraint::Unique) {
+        statements.push(format!(
+            "CREATE UNIQUE INDEX {} ON {} ({})",
+            dialect.quote_identifier(&auto_index_name(table_name, col.name, "_unique")),
+            dialect.quote_qualified(table_name),
+            dialect.quote_identifier(col.name)
+        ));
+    }
+
+    statements.extend(column_index_sql(table_name, col));
+    statements
+}
+
+/// Renders a column's definition as used in `CREATE TABLE`: name, type,
+/// constraints, comment, charset, collation and default.
+pub(crate) fn column_definition_sql(col: &ColumnInfo<'_>) -> String {
+    render_column_definition(col, true)
+}
+
+/// Renders a column's definition, leaving out its `UNIQUE` unless `inline_unique`.
+fn render_column_definition(col: &ColumnInfo<'_>, inline_unique: bool) -> String {
+    let native_enum = col
+        .constraints
+        .iter()
+        .find_map(|constraint| match constraint {
+            ColumnConstraint::NativeEnum(type_name, values) => Some((*type_name, *values)),
+            _ => None,
+        });
+
+    let data_type = if let Some((type_name, values)) = native_enum {
+        get_dialect().native_enum_column_type(col.name, type_name, values)
+    } else if col.constraints.contains(&ColumnConstraint::PrimaryKey)
+        && is_mysql_integer_type(col.data_type)
+    {
+        get_dialect()
+            .integer_primary_key_type(col.data_type)
+            .to_string()
+    } else if col.data_type == "VARCHAR(255)" {
+        // `String` columns; `length()` overrides the dialect's default
+        col.constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                ColumnConstraint::Length(length) => Some(format!("VARCHAR({})", length)),
+                _ => None,
+            })
+            .unwrap_or_else(|| get_dialect().default_string_type().to_string())
+    } else {
+        col.data_type.to_string()
+    };
+    let mut def = format!("{} {}", get_dialect().quote_identifier(col.name), data_type);
+    let constraints = col.constraints;
+
+    for constraint in constraints {
+        match constraint {
+            ColumnConstraint::NonNullable => {
+                if !col.nullable {
+                    def.push_str(" NOT NULL");
+                }
+            }
+            ColumnConstraint::Unique => {
+                if inline_unique {
+                    def.push_str(" UNIQUE");
+                }
+            }
+            ColumnConstraint::PrimaryKey => {
+                def.push_str(" PRIMARY KEY");
+            }
+            ColumnConstraint::Indexed
+            | ColumnConstraint::IndexedNamed(_)
+            | ColumnConstraint::IndexedExpr(_)
+            | ColumnConstraint::IndexedWhere(_) => {}
+            ColumnConstraint::AutoIncrement => {
+                if is_mysql_integer_type(col.data_type) {
+                    def.push_str(" AUTO_INCREMENT");
+                }
+            }
+            ColumnConstraint::Invisible => {
+                def.push_str(get_dialect().invisible_column_sql());
+            }
+            ColumnConstraint::OnUpdateCurrentTimestamp => {
+                def.push_str(" ON UPDATE CURRENT_TIMESTAMP");
+            }
+            ColumnConstraint::Check(expression) => {
+                def.push_str(&format!(" CHECK ({})", expression));
+            }
+            ColumnConstraint::Generated(generated) => {
+                def.push_str(&get_dialect().generated_column_sql(generated));
+            }
+            ColumnConstraint::NativeEnum(..)
+            | ColumnConstraint::Length(_)
+            | ColumnConstraint::Version => {}
+        }
+    }
+
+    if col.comment.is_some() {
+        let escaped = col.comment.unwrap().replace("'", "''");
+        def.push_str(&format!(" COMMENT '{}'", escaped));
+    }
+
+    if col.charset.is_some() {
+        def.push_str(&format!(" CHARACTER SET {}", col.charset.unwrap()));
+    }
+
+    if col.collate.is_some() {
+        def.push_str(&format!(" COLLATE {}", col.collate.unwrap()));
+    }
+
+    if col.has_default {
+        if let Some(ref default) = col.default_sql {
+            if let DefaultValueEnum::Value(default) = default {
+                // Skip empty string defaults for primary keys
+                let is_empty_string = default == "" || default == "''";
+                let is_primary_key = col.constraints.contains(&ColumnConstraint::PrimaryKey);
+
+                if is_primary_key && is_empty_string {
+                    // Skip default for primary keys with empty string
+                } else {
+                    // Add quotes for string default values if not already quoted
+                    let needs_quotes = col.data_type == "TEXT"
+                        || col.data_type.starts_with("VARCHAR")
+                        || col.data_type == "CHAR"
+                        || col.data_type == "STRING"
+                        || col.data_type == "UUID";
+                    if needs_quotes && !(default.starts_with('\'') && default.ends_with('\'')) {
+                        def.push_str(&format!(" DEFAULT '{}'", default.replace('\'', "''")));
+                    } else {
+                        def.push_str(&format!(" DEFAULT {}", default));
+                    }
+                }
+            } else if &DefaultValueEnum::CurrentTimestamp == default {
+                def.push_str(" DEFAULT CURRENT_TIMESTAMP");
+            } else if &DefaultValueEnum::Random == default {
+                def.push_str(" DEFAULT (UUID())");
+            }
+        }
+    }
+
+    def
+}
+
 /// Returns true if a MySQL data type string represents an integer type.
 fn is_mysql_integer_type(data_type: &str) -> bool {
     match data_type {
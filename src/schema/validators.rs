@@ -1,3 +1,5 @@
+use crate::schema::Value;
+
 /// Represents different types of validators that can be applied to a column.
 ///
 /// Validators provide semantic hints for validation and UI generation,
@@ -18,4 +20,57 @@ pub enum ColumnValidators {
     Max(usize),
     /// Validates that the value matches the specified regex pattern.
     Pattern(&'static str),
+    /// Validates that no existing row already has the value (checked against the database).
+    Unique,
 }
+
+/// Describes a value that failed one of its column's validators.
+///
+/// Returned inside [`DatabaseError::ValidationFailed`](crate::database::error::DatabaseError::ValidationFailed)
+/// when an insert is rejected before reaching the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// The name of the column that failed validation
+    pub column: &'static str,
+    /// The validator rule that was not satisfied
+    pub rule: ColumnValidators,
+    /// The offending value
+    pub value: Value,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let is_string = matches!(self.value, Value::String(_));
+
+        write!(
+            f,
+            "Column {} failed {:?} validation: ",
+            self.column, self.rule
+        )?;
+
+        match self.rule {
+            ColumnValidators::Email => write!(f, "'{}' is not a valid email address", self.value),
+            ColumnValidators::Url => write!(f, "'{}' is not a valid URL", self.value),
+            ColumnValidators::Pattern(pattern) => {
+                write!(f, "'{}' does not match pattern {}", self.value, pattern)
+            }
+            ColumnValidators::MinLen(min) => {
+                write!(f, "'{}' is shorter than {} characters", self.value, min)
+            }
+            ColumnValidators::MaxLen(max) => {
+                write!(f, "'{}' is longer than {} characters", self.value, max)
+            }
+            ColumnValidators::Min(min) if is_string => {
+                write!(f, "'{}' is shorter than {} characters", self.value, min)
+            }
+            ColumnValidators::Max(max) if is_string => {
+                write!(f, "'{}' is longer than {} characters", self.value, max)
+            }
+            ColumnValidators::Min(min) => write!(f, "{} is less than {}", self.value, min),
+            ColumnValidators::Max(max) => write!(f, "{} is greater than {}", self.value, max),
+            ColumnValidators::Unique => write!(f, "'{}' is already taken", self.value),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
@@ -16,13 +16,17 @@ use crate::schema::Uuid;
 /// - `Int16(i16)`: 16-bit signed integer
 /// - `Int32(i32)`: 32-bit signed integer
 /// - `Int64(i64)`: 64-bit signed integer
+/// - `Int128(i128)`: 128-bit signed integer
 /// - `UInt8(u8)`: 8-bit unsigned integer
 /// - `UInt16(u16)`: 16-bit unsigned integer
 /// - `UInt32(u32)`: 32-bit unsigned integer
 /// - `UInt64(u64)`: 64-bit unsigned integer
+/// - `UInt128(u128)`: 128-bit unsigned integer
 /// - `Float32(f32)`: 32-bit floating point number
 /// - `Float64(f64)`: 64-bit floating point number
 /// - `Bool(bool)`: Boolean value
+/// - `DateTimeTz(time::OffsetDateTime)`: A point in time, held in UTC (a
+///   `time` type rather than `chrono::DateTime<Utc>`, as Lume uses `time` throughout)
 /// - `Null`: NULL value
 ///
 /// # Type Conversions
@@ -66,6 +70,8 @@ pub enum Value {
     Int32(i32),
     /// 64-bit signed integer value
     Int64(i64),
+    /// 128-bit signed integer value
+    Int128(i128),
     /// 8-bit unsigned integer value
     #[cfg(any(feature = "mysql", feature = "sqlite"))]
     UInt8(u8),
@@ -75,12 +81,17 @@ pub enum Value {
     UInt32(u32),
     /// 64-bit unsigned integer value
     UInt64(u64),
+    /// 128-bit unsigned integer value
+    UInt128(u128),
     /// 32-bit floating point value
     Float32(f32),
     /// 64-bit floating point value
     Float64(f64),
     /// Boolean value
     Bool(bool),
+    /// A point in time, normalized to UTC so the instant survives a round trip
+    /// whatever offset it was created with
+    DateTimeTz(time::OffsetDateTime),
 
     /// Array value containing a vector of `Value` elements.
     Array(Vec<Value>),
@@ -100,14 +111,17 @@ impl Display for Value {
             Value::Int16(i) => write!(f, "{}", i),
             Value::Int32(i) => write!(f, "{}", i),
             Value::Int64(i) => write!(f, "{}", i),
+            Value::Int128(i) => write!(f, "{}", i),
             #[cfg(any(feature = "mysql", feature = "sqlite"))]
             Value::UInt8(u) => write!(f, "{}", u),
             Value::UInt16(u) => write!(f, "{}", u),
             Value::UInt32(u) => write!(f, "{}", u),
             Value::UInt64(u) => write!(f, "{}", u),
+            Value::UInt128(u) => write!(f, "{}", u),
             Value::Float32(val) => write!(f, "{}", val),
             Value::Float64(val) => write!(f, "{}", val),
             Value::Bool(b) => write!(f, "{}", b),
+            Value::DateTimeTz(dt) => write!(f, "{}", dt),
             Value::Array(arr) => write!(f, "{:?}", arr),
             Value::Between(min, max) => write!(f, "BETWEEN {} AND {}", min, max),
             Value::Null => write!(f, "NULL"),
@@ -154,6 +168,12 @@ impl From<i64> for Value {
     }
 }
 
+impl From<i128> for Value {
+    fn from(i: i128) -> Self {
+        Value::Int128(i)
+    }
+}
+
 // Unsigned integer types
 #[cfg(any(feature = "mysql", feature = "sqlite"))]
 impl From<u8> for Value {
@@ -180,6 +200,12 @@ impl From<u64> for Value {
     }
 }
 
+impl From<u128> for Value {
+    fn from(u: u128) -> Self {
+        Value::UInt128(u)
+    }
+}
+
 // Floating point types
 impl From<f32> for Value {
     fn from(f: f32) -> Self {
@@ -199,21 +225,33 @@ impl From<bool> for Value {
     }
 }
 
-impl TryFrom<Value> for Vec<String> {
+impl From<time::OffsetDateTime> for Value {
+    fn from(dt: time::OffsetDateTime) -> Self {
+        Value::DateTimeTz(dt.to_offset(time::UtcOffset::UTC))
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: TryFrom<Value>> TryFrom<Value> for Vec<T> {
     type Error = ();
 
     fn try_from(value: Value) -> Result<Self, Self::Error> {
         match value {
-            Value::Array(arr) => {
-                let mut out = Vec::with_capacity(arr.len());
-                for v in arr {
-                    match v {
-                        Value::String(s) => out.push(s),
-                        _ => return Err(()),
-                    }
-                }
-                Ok(out)
-            }
+            Value::Array(arr) => arr
+                .into_iter()
+                .map(|v| T::try_from(v).map_err(|_| ()))
+                .collect(),
             _ => Err(()),
         }
     }
@@ -385,6 +423,48 @@ impl TryFrom<Value> for u64 {
     }
 }
 
+impl TryFrom<Value> for i128 {
+    type Error = ();
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Int8(i) => Ok(i as i128),
+            Value::Int16(i) => Ok(i as i128),
+            Value::Int32(i) => Ok(i as i128),
+            Value::Int64(i) => Ok(i as i128),
+            Value::Int128(i) => Ok(i),
+            #[cfg(any(feature = "mysql", feature = "sqlite"))]
+            Value::UInt8(u) => Ok(u as i128),
+            Value::UInt16(u) => Ok(u as i128),
+            Value::UInt32(u) => Ok(u as i128),
+            Value::UInt64(u) => Ok(u as i128),
+            Value::UInt128(u) if u <= i128::MAX as u128 => Ok(u as i128),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<Value> for u128 {
+    type Error = ();
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Int8(i) if i >= 0 => Ok(i as u128),
+            Value::Int16(i) if i >= 0 => Ok(i as u128),
+            Value::Int32(i) if i >= 0 => Ok(i as u128),
+            Value::Int64(i) if i >= 0 => Ok(i as u128),
+            Value::Int128(i) if i >= 0 => Ok(i as u128),
+            #[cfg(any(feature = "mysql", feature = "sqlite"))]
+            Value::UInt8(u) => Ok(u as u128),
+            Value::UInt16(u) => Ok(u as u128),
+            Value::UInt32(u) => Ok(u as u128),
+            Value::UInt64(u) => Ok(u as u128),
+            Value::UInt128(u) => Ok(u),
+            _ => Err(()),
+        }
+    }
+}
+
 // Floating point TryFrom implementations
 impl TryFrom<Value> for f32 {
     type Error = ();
@@ -441,11 +521,22 @@ impl TryFrom<Value> for bool {
     }
 }
 
+impl TryFrom<Value> for time::OffsetDateTime {
+    type Error = ();
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::DateTimeTz(dt) => Ok(dt),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Converts a reference to a value of any supported type into a [`Value`] enum.
 ///
 /// This function attempts to downcast the provided reference to a known supported type
-/// (`String`, `i32`, `i64`, `f32`, `f64`, `bool`, or their `Option` variants) and
-/// returns the corresponding [`Value`] variant. If the type is not supported, it
+/// (`String`, `i32`, `i64`, `f32`, `f64`, `bool`, `Vec`s of them, or their `Option`
+/// variants) and returns the corresponding [`Value`] variant. If the type is not supported, it
 /// returns [`Value::Null`] and triggers a debug assertion failure in debug builds.
 ///
 /// # Type Parameters
@@ -496,6 +587,8 @@ pub fn convert_to_value<T: Any + Debug>(value: &T) -> Value {
         Value::Int32(*i)
     } else if let Some(i) = <dyn Any>::downcast_ref::<i64>(value) {
         Value::Int64(*i)
+    } else if let Some(i) = <dyn Any>::downcast_ref::<i128>(value) {
+        Value::Int128(*i)
     } else if let Some(u) = <dyn Any>::downcast_ref::<u8>(value) {
         #[cfg(any(feature = "mysql", feature = "sqlite"))]
         return Value::UInt8(*u);
@@ -508,12 +601,20 @@ pub fn convert_to_value<T: Any + Debug>(value: &T) -> Value {
         Value::UInt32(*u)
     } else if let Some(u) = <dyn Any>::downcast_ref::<u64>(value) {
         Value::UInt64(*u)
+    } else if let Some(u) = <dyn Any>::downcast_ref::<u128>(value) {
+        Value::UInt128(*u)
     } else if let Some(f) = <dyn Any>::downcast_ref::<f32>(value) {
         Value::Float32(*f)
     } else if let Some(f) = <dyn Any>::downcast_ref::<f64>(value) {
         Value::Float64(*f)
     } else if let Some(b) = <dyn Any>::downcast_ref::<bool>(value) {
         Value::Bool(*b)
+    } else if let Some(dt) = <dyn Any>::downcast_ref::<time::OffsetDateTime>(value) {
+        Value::from(*dt)
+    } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<crate::schema::Uuid>>(value) {
+        opt.as_ref()
+            .map(|uuid| Value::String(uuid.as_str().to_string()))
+            .unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<&str>>(value) {
         opt.map(|s| Value::String(s.to_string()))
             .unwrap_or(Value::Null)
@@ -534,6 +635,8 @@ pub fn convert_to_value<T: Any + Debug>(value: &T) -> Value {
         opt.map(Value::Int32).unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<i64>>(value) {
         opt.map(Value::Int64).unwrap_or(Value::Null)
+    } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<i128>>(value) {
+        opt.map(Value::Int128).unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<u8>>(value) {
         #[cfg(any(feature = "mysql", feature = "sqlite"))]
         return opt.map(Value::UInt8).unwrap_or(Value::Null);
@@ -546,12 +649,18 @@ pub fn convert_to_value<T: Any + Debug>(value: &T) -> Value {
         opt.map(Value::UInt32).unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<u64>>(value) {
         opt.map(Value::UInt64).unwrap_or(Value::Null)
+    } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<u128>>(value) {
+        opt.map(Value::UInt128).unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<f32>>(value) {
         opt.map(Value::Float32).unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<f64>>(value) {
         opt.map(Value::Float64).unwrap_or(Value::Null)
     } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<bool>>(value) {
         opt.map(Value::Bool).unwrap_or(Value::Null)
+    } else if let Some(opt) = <dyn Any>::downcast_ref::<Option<time::OffsetDateTime>>(value) {
+        opt.map(Value::from).unwrap_or(Value::Null)
+    } else if let Some(array) = convert_array_to_value(value) {
+        array
     } else {
         // Fallback to Debug
         let dbg = value as &dyn std::fmt::Debug;
@@ -563,3 +672,48 @@ pub fn convert_to_value<T: Any + Debug>(value: &T) -> Value {
         }
     }
 }
+
+/// Converts an `Option<T>` into a [`Value`], converting `Some` with
+/// [`convert_to_value`] and mapping `None` to [`Value::Null`].
+///
+/// [`define_schema!`](crate::define_schema) uses this for fields it wraps in
+/// `Option` (those with `default_value(...)` or `auto_increment()`), so a
+/// custom type such as an [`enum_to_sql!`](crate::enum_to_sql) enum is stored
+/// the same whether or not it is optional.
+///
+/// # Examples
+///
+/// ```
+/// use lume::schema::{convert_option_to_value, Value};
+///
+/// assert_eq!(convert_option_to_value(&Some(5i32)), Value::Int32(5));
+/// assert_eq!(convert_option_to_value::<i32>(&None), Value::Null);
+/// ```
+pub fn convert_option_to_value<T: Any + Debug>(value: &Option<T>) -> Value {
+    value.as_ref().map_or(Value::Null, convert_to_value)
+}
+
+macro_rules! downcast_array {
+    ($value:expr, $($t:ty),* $(,)?) => {
+        $(
+            if let Some(values) = <dyn Any>::downcast_ref::<Vec<$t>>($value) {
+                return Some(Value::Array(values.iter().map(convert_to_value).collect()));
+            }
+            if let Some(opt) = <dyn Any>::downcast_ref::<Option<Vec<$t>>>($value) {
+                return Some(opt.as_ref().map_or(Value::Null, |values| {
+                    Value::Array(values.iter().map(convert_to_value).collect())
+                }));
+            }
+        )*
+    };
+}
+
+/// Converts a `Vec<T>` (or `Option<Vec<T>>`) of a supported element type into
+/// a [`Value::Array`], converting each element with [`convert_to_value`].
+fn convert_array_to_value<T: Any>(value: &T) -> Option<Value> {
+    downcast_array!(
+        value, String, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, bool,
+    );
+
+    None
+}
@@ -10,7 +10,8 @@ use std::{
     sync::{Mutex, OnceLock},
 };
 
-use crate::schema::{ColumnInfo, Schema, SchemaWrapper};
+use crate::dialects::get_dialect;
+use crate::schema::{ColumnConstraint, ColumnInfo, DefaultValueEnum, Schema, SchemaWrapper};
 
 /// Global table registry for storing all registered tables
 static TABLE_REGISTRY: OnceLock<Mutex<Vec<Box<dyn TableDefinition>>>> = OnceLock::new();
@@ -96,3 +97,186 @@ pub(crate) fn get_all_tables() -> Vec<Box<dyn TableDefinition>> {
     let tables = registry.lock().unwrap();
     tables.iter().map(|t| t.clone_box()).collect()
 }
+
+/// A problem in a table definition that would make its DDL fail or misbehave.
+///
+/// Returned by [`Database::validate_schema`](crate::database::Database::validate_schema).
+/// Foreign keys aren't part of the schema model, so references between tables
+/// aren't checked; new checks may add variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SchemaError {
+    /// The table has no `primary_key()` column
+    MissingPrimaryKey {
+        /// The table name
+        table: &'static str,
+    },
+    /// More than one column is marked `primary_key()`. Each is rendered as its
+    /// own inline `PRIMARY KEY`, which databases reject.
+    MultiplePrimaryKeys {
+        /// The table name
+        table: &'static str,
+        /// The columns marked `primary_key()`, in declaration order
+        columns: Vec<&'static str>,
+    },
+    /// A `default_now()` or `default_random()` default can't be stored in the column's type
+    MismatchedDefault {
+        /// The table name
+        table: &'static str,
+        /// The column name
+        column: &'static str,
+        /// The column's SQL type
+        data_type: &'static str,
+        /// The offending default
+        default: DefaultValueEnum<String>,
+    },
+    /// A `default_value` string is longer than the column's `VARCHAR(n)`
+    DefaultTooLong {
+        /// The table name
+        table: &'static str,
+        /// The column name
+        column: &'static str,
+        /// The column's maximum length in characters
+        length: usize,
+        /// The offending default, as its SQL literal
+        default: String,
+    },
+}
+
+impl std::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaError::MissingPrimaryKey { table } => {
+                write!(f, "Table {} has no primary key", table)
+            }
+            SchemaError::MultiplePrimaryKeys { table, columns } => write!(
+                f,
+                "Table {} marks more than one column as primary key: {}",
+                table,
+                columns.join(", ")
+            ),
+            SchemaError::MismatchedDefault {
+                table,
+                column,
+                data_type,
+                default,
+            } => {
+                let default = match default {
+                    DefaultValueEnum::CurrentTimestamp => "default_now()".to_string(),
+                    DefaultValueEnum::Random => "default_random()".to_string(),
+                    DefaultValueEnum::Value(value) => value.clone(),
+                };
+                write!(
+                    f,
+                    "Column {}.{} of type {} can't have the default {}",
+                    table, column, data_type, default
+                )
+            }
+            SchemaError::DefaultTooLong {
+                table,
+                column,
+                length,
+                default,
+            } => write!(
+                f,
+                "Column {}.{} holds at most {} characters, but its default is {}",
+                table, column, length, default
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/// Checks a table definition for problems that would only show up when its DDL runs.
+pub(crate) fn validate_table(table: &dyn TableDefinition) -> Vec<SchemaError> {
+    let table_name = table.table_name();
+    let columns = table.get_columns();
+    let mut errors = Vec::new();
+
+    let primary_keys: Vec<&'static str> = columns
+        .iter()
+        .filter(|col| col.constraints.contains(&ColumnConstraint::PrimaryKey))
+        .map(|col| col.name)
+        .collect();
+
+    match primary_keys.len() {
+        0 => errors.push(SchemaError::MissingPrimaryKey { table: table_name }),
+        1 => {}
+        _ => errors.push(SchemaError::MultiplePrimaryKeys {
+            table: table_name,
+            columns: primary_keys,
+        }),
+    }
+
+    for col in &columns {
+        let fits = match &col.default_sql {
+            Some(DefaultValueEnum::CurrentTimestamp) => is_temporal_type(col.data_type),
+            Some(DefaultValueEnum::Random) => is_text_type(col.data_type),
+            _ => true,
+        };
+
+        if !fits {
+            errors.push(SchemaError::MismatchedDefault {
+                table: table_name,
+                column: col.name,
+                data_type: col.data_type,
+                default: col.default_sql.clone().unwrap(),
+            });
+        }
+
+        if let Some(DefaultValueEnum::Value(literal)) = &col.default_sql
+            && let Some(length) = string_length(col)
+            && let Some(text) = literal
+                .strip_prefix('\'')
+                .and_then(|l| l.strip_suffix('\''))
+            && text.replace("''", "'").chars().count() > length
+        {
+            errors.push(SchemaError::DefaultTooLong {
+                table: table_name,
+                column: col.name,
+                length,
+                default: literal.clone(),
+            });
+        }
+    }
+
+    errors
+}
+
+/// Returns the maximum length of a string column, from `length()` or the
+/// dialect's `VARCHAR(n)` string type; `None` for unbounded types like `TEXT`.
+fn string_length(col: &ColumnInfo) -> Option<usize> {
+    if col.data_type != "VARCHAR(255)" {
+        return None;
+    }
+
+    let declared = col
+        .constraints
+        .iter()
+        .find_map(|constraint| match constraint {
+            ColumnConstraint::Length(length) => Some(*length as usize),
+            _ => None,
+        });
+    declared.or_else(|| {
+        get_dialect()
+            .default_string_type()
+            .strip_prefix("VARCHAR(")?
+            .strip_suffix(')')?
+            .parse()
+            .ok()
+    })
+}
+
+/// Returns true for the date and time types `CURRENT_TIMESTAMP` can be stored in.
+fn is_temporal_type(data_type: &str) -> bool {
+    matches!(data_type, "DATE" | "DATETIME" | "TIMESTAMP" | "TIME")
+}
+
+/// Returns true for the string and UUID types a generated UUID can be stored in.
+fn is_text_type(data_type: &str) -> bool {
+    data_type == "TEXT"
+        || data_type == "UUID"
+        || data_type.starts_with("VARCHAR")
+        || data_type.starts_with("CHAR")
+}
@@ -1,5 +1,7 @@
 pub mod database;
+pub mod delete;
 pub mod query;
+pub mod row;
 
 #[cfg(test)]
 #[allow(dead_code)]
@@ -12,6 +14,11 @@ mod tests {
     use crate::schema::{ColumnInfo, Schema};
     use crate::table::TableDefinition;
 
+    /// Quotes `name` the way the DDL does.
+    fn quoted(name: &str) -> String {
+        crate::dialects::get_dialect().quote_identifier(name)
+    }
+
     // Test schema definition
     define_schema! {
         TestUser {
@@ -126,6 +133,7 @@ mod tests {
             ColumnInfo {
                 name: "id",
                 data_type: "INTEGER",
+                nullable: false,
                 has_default: false,
                 default_sql: None,
                 comment: None,
@@ -141,6 +149,7 @@ mod tests {
             ColumnInfo {
                 name: "username",
                 data_type: "VARCHAR(255)",
+                nullable: false,
                 has_default: false,
                 default_sql: None,
                 comment: None,
@@ -156,6 +165,7 @@ mod tests {
             ColumnInfo {
                 name: "email",
                 data_type: "VARCHAR(255)",
+                nullable: false,
                 has_default: false,
                 default_sql: None,
                 comment: None,
@@ -171,6 +181,7 @@ mod tests {
             ColumnInfo {
                 name: "age",
                 data_type: "INTEGER",
+                nullable: false,
                 has_default: false,
                 default_sql: None,
                 comment: None,
@@ -186,6 +197,7 @@ mod tests {
             ColumnInfo {
                 name: "is_active",
                 data_type: "BOOLEAN",
+                nullable: false,
                 has_default: false,
                 default_sql: None,
                 comment: None,
@@ -292,10 +304,424 @@ mod tests {
             score_info.default_sql,
             Some(DefaultValueEnum::Value("100".to_string()))
         );
+        #[cfg(feature = "postgres")]
         assert_eq!(
             active_info.default_sql,
             Some(DefaultValueEnum::Value("TRUE".to_string()))
         );
+        #[cfg(not(feature = "postgres"))]
+        assert_eq!(
+            active_info.default_sql,
+            Some(DefaultValueEnum::Value("1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_default_value_type_mismatch_fails_to_compile() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/default_value_*.rs");
+    }
+
+    #[test]
+    fn test_bool_default_ddl() {
+        define_schema! {
+            TestFeatureFlags {
+                id: i32 [primary_key()],
+                enabled: bool [default_value(true)],
+                archived: bool [default_value(false)],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestFeatureFlags>::new().to_create_sql();
+
+        #[cfg(feature = "postgres")]
+        {
+            assert!(create_sql.contains(&format!("{} BOOLEAN DEFAULT TRUE", quoted("enabled"))));
+            assert!(create_sql.contains(&format!("{} BOOLEAN DEFAULT FALSE", quoted("archived"))));
+        }
+
+        #[cfg(not(feature = "postgres"))]
+        {
+            assert!(create_sql.contains(&format!("{} BOOLEAN DEFAULT 1", quoted("enabled"))));
+            assert!(create_sql.contains(&format!("{} BOOLEAN DEFAULT 0", quoted("archived"))));
+        }
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    #[test]
+    fn test_vec_default_ddl() {
+        define_schema! {
+            TestVecDefaults {
+                id: i32 [primary_key()],
+                scores: Vec<i32> [default_value(vec![1, 2])],
+                tags: Vec<String> [default_value(vec!["it's".to_string(), "a\\b".to_string()])],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestVecDefaults>::new().to_create_sql();
+
+        // JSON text as an expression default, which MySQL requires on JSON columns
+        assert!(create_sql.contains(&format!("{} JSON DEFAULT ('[1,2]')", quoted("scores"))));
+        // MySQL string literals also escape the JSON's backslash
+        #[cfg(feature = "mysql")]
+        assert!(create_sql.contains(&format!(
+            r#"{} JSON DEFAULT ('["it''s","a\\\\b"]')"#,
+            quoted("tags")
+        )));
+        #[cfg(feature = "sqlite")]
+        assert!(create_sql.contains(&format!(
+            r#"{} JSON DEFAULT ('["it''s","a\\b"]')"#,
+            quoted("tags")
+        )));
+    }
+
+    #[test]
+    fn test_option_columns_are_nullable() {
+        define_schema! {
+            TestNullable {
+                id: i32 [primary_key().not_null()],
+                email: Option<String>,
+                username: String [not_null()],
+            }
+        }
+
+        let columns = TestNullable::get_all_columns();
+        let email_info = columns.iter().find(|c| c.name == "email").unwrap();
+        let username_info = columns.iter().find(|c| c.name == "username").unwrap();
+
+        assert!(email_info.nullable);
+        assert_eq!(email_info.data_type, "VARCHAR(255)");
+        assert!(!username_info.nullable);
+
+        let create_sql = crate::schema::SchemaWrapper::<TestNullable>::new().to_create_sql();
+        let string_type = crate::dialects::get_dialect().default_string_type();
+        assert!(create_sql.contains(&format!("{} {},", quoted("email"), string_type)));
+        assert!(!create_sql.contains(&format!("{} {} NOT NULL", quoted("email"), string_type)));
+        assert!(create_sql.contains(&format!("{} {} NOT NULL", quoted("username"), string_type)));
+    }
+
+    #[test]
+    fn test_generated_columns_ddl() {
+        use crate::schema::GeneratedStorage;
+
+        define_schema! {
+            TestOrder {
+                id: i32 [primary_key().not_null()],
+                price: i32 [not_null()],
+                quantity: i32 [not_null()],
+                total: i32 [generated("price * quantity", GeneratedStorage::Stored)],
+                doubled: i32 [generated("price * 2", GeneratedStorage::Virtual)],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestOrder>::new().to_create_sql();
+
+        #[cfg(any(feature = "mysql", feature = "sqlite"))]
+        {
+            assert!(create_sql.contains(&format!(
+                "{} INT GENERATED ALWAYS AS (price * quantity) STORED",
+                quoted("total")
+            )));
+            assert!(create_sql.contains(&format!(
+                "{} INT GENERATED ALWAYS AS (price * 2) VIRTUAL",
+                quoted("doubled")
+            )));
+        }
+
+        // Postgres has no virtual generated columns, so STORED is forced
+        #[cfg(feature = "postgres")]
+        {
+            assert!(create_sql.contains(&format!(
+                "{} INT GENERATED ALWAYS AS (price * quantity) STORED",
+                quoted("total")
+            )));
+            assert!(create_sql.contains(&format!(
+                "{} INT GENERATED ALWAYS AS (price * 2) STORED",
+                quoted("doubled")
+            )));
+            assert!(!create_sql.contains("VIRTUAL"));
+        }
+    }
+
+    #[test]
+    fn test_invisible_column_ddl() {
+        define_schema! {
+            TestInvisible {
+                id: i32 [primary_key().not_null()],
+                legacy_code: String [invisible()],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestInvisible>::new().to_create_sql();
+
+        #[cfg(feature = "mysql")]
+        assert!(create_sql.contains(&format!("{} VARCHAR(255) INVISIBLE", quoted("legacy_code"))));
+
+        #[cfg(feature = "postgres")]
+        assert!(create_sql.contains(&format!("{} TEXT", quoted("legacy_code"))));
+
+        #[cfg(feature = "sqlite")]
+        assert!(create_sql.contains(&format!("{} VARCHAR(255)", quoted("legacy_code"))));
+
+        #[cfg(not(feature = "mysql"))]
+        assert!(!create_sql.contains("INVISIBLE"));
+    }
+
+    #[test]
+    fn test_default_string_type_ddl() {
+        define_schema! {
+            TestArticleBody {
+                id: i32 [primary_key()],
+                title: String [not_null()],
+                slug: String [length(64)],
+                summary: String [length(255)],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestArticleBody>::new().to_create_sql();
+
+        #[cfg(feature = "mysql")]
+        assert!(create_sql.contains(&format!("{} VARCHAR(255) NOT NULL", quoted("title"))));
+        #[cfg(feature = "postgres")]
+        assert!(create_sql.contains(&format!("{} TEXT NOT NULL", quoted("title"))));
+        #[cfg(feature = "sqlite")]
+        assert!(create_sql.contains(&format!("{} VARCHAR(255) NOT NULL", quoted("title"))));
+
+        // An explicit length wins on every backend, even where it matches the default
+        assert!(create_sql.contains(&format!("{} VARCHAR(64)", quoted("slug"))));
+        assert!(create_sql.contains(&format!("{} VARCHAR(255)", quoted("summary"))));
+    }
+
+    #[test]
+    fn test_indexed_expr_ddl() {
+        define_schema! {
+            TestAccount {
+                id: i32 [primary_key().not_null()],
+                email: String [not_null().indexed_expr("LOWER(email)")],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestAccount>::new().to_create_sql();
+
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        assert!(create_sql.contains(
+            "CREATE INDEX \"idx_TestAccount_email_expr\" ON \"TestAccount\" (LOWER(email));"
+        ));
+
+        #[cfg(feature = "mysql")]
+        assert!(
+            create_sql
+                .contains("CREATE INDEX `idx_TestAccount_email_expr` ON `TestAccount` (`email`);")
+        );
+    }
+
+    #[test]
+    fn test_index_names() {
+        define_schema! {
+            TestSubscriberPreferences {
+                id: i32 [primary_key().not_null()],
+                email: String [indexed_named("subscriber_email_lookup")],
+                preferred_notification_delivery_channel: String [indexed()],
+            }
+        }
+
+        let create_sql =
+            crate::schema::SchemaWrapper::<TestSubscriberPreferences>::new().to_create_sql();
+
+        #[cfg(feature = "mysql")]
+        assert!(create_sql.contains(
+            "CREATE INDEX `subscriber_email_lookup` ON `TestSubscriberPreferences` (`email`);"
+        ));
+        #[cfg(not(feature = "mysql"))]
+        assert!(create_sql.contains(
+            "CREATE INDEX \"subscriber_email_lookup\" ON \"TestSubscriberPreferences\" (\"email\");"
+        ));
+
+        let full_name = "idx_TestSubscriberPreferences_preferred_notification_delivery_channel";
+        let index = create_sql
+            .lines()
+            .find(|line| {
+                line.contains("preferred_notification_delivery_channel`)")
+                    || line.contains("preferred_notification_delivery_channel\")")
+            })
+            .unwrap();
+        let name = index
+            .split_whitespace()
+            .nth(2)
+            .unwrap()
+            .trim_matches(['"', '`']);
+
+        #[cfg(feature = "sqlite")]
+        assert_eq!(name, full_name);
+
+        #[cfg(not(feature = "sqlite"))]
+        {
+            #[cfg(feature = "mysql")]
+            assert_eq!(name.len(), 64);
+            #[cfg(feature = "postgres")]
+            assert_eq!(name.len(), 63);
+
+            // Cut short and suffixed with a hash, the same on every run
+            let (prefix, hash) = name.rsplit_once('_').unwrap();
+            assert!(full_name.starts_with(prefix));
+            assert_eq!(hash.len(), 8);
+            assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+            let again =
+                crate::schema::SchemaWrapper::<TestSubscriberPreferences>::new().to_create_sql();
+            assert!(again.contains(name));
+        }
+    }
+
+    #[test]
+    fn test_indexed_where_ddl() {
+        define_schema! {
+            TestMember {
+                id: i32 [primary_key().not_null()],
+                email: String [not_null().indexed_where("deleted_at IS NULL")],
+                deleted_at: time::OffsetDateTime,
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestMember>::new().to_create_sql();
+
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        assert!(create_sql.contains(
+            "CREATE INDEX \"idx_TestMember_email_partial\" ON \"TestMember\" (\"email\") WHERE deleted_at IS NULL;"
+        ));
+
+        #[cfg(feature = "mysql")]
+        assert!(
+            create_sql
+                .contains("CREATE INDEX `idx_TestMember_email_partial` ON `TestMember` (`email`);")
+        );
+    }
+
+    #[test]
+    fn test_native_enum_ddl() {
+        define_schema! {
+            TestTicket {
+                id: i32 [primary_key().not_null()],
+                status: String [not_null().native_enum("ticket_status", &["open", "closed"])],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestTicket>::new().to_create_sql();
+
+        #[cfg(feature = "mysql")]
+        assert!(create_sql.contains(&format!(
+            "{} ENUM('open', 'closed') NOT NULL",
+            quoted("status")
+        )));
+
+        #[cfg(feature = "postgres")]
+        {
+            assert!(create_sql.starts_with(
+                "DO $$ BEGIN CREATE TYPE ticket_status AS ENUM ('open', 'closed'); EXCEPTION WHEN duplicate_object THEN NULL; END $$;"
+            ));
+            assert!(create_sql.contains(&format!("{} ticket_status NOT NULL", quoted("status"))));
+        }
+
+        #[cfg(feature = "sqlite")]
+        assert!(
+            create_sql.contains(r#""status" TEXT CHECK ("status" IN ('open', 'closed')) NOT NULL"#)
+        );
+    }
+
+    #[test]
+    fn test_split_statements_keeps_quoted_semicolons() {
+        use crate::helpers::split_statements;
+
+        let statements = split_statements(
+            "DO $$ BEGIN CREATE TYPE t AS ENUM ('a;b'); END $$;\n\nCREATE TABLE x (c TEXT DEFAULT ';');\n",
+        );
+        assert_eq!(
+            statements,
+            vec![
+                "DO $$ BEGIN CREATE TYPE t AS ENUM ('a;b'); END $$",
+                "CREATE TABLE x (c TEXT DEFAULT ';')",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_composite_unique_ddl() {
+        define_schema! {
+            #[unique(tenant_id, email)]
+            #[unique(tenant_id, handle)]
+            TestTenantUser {
+                id: i32 [primary_key().not_null()],
+                tenant_id: i32 [not_null()],
+                email: String [not_null()],
+                handle: String [not_null()],
+            }
+        }
+
+        let create_sql = crate::schema::SchemaWrapper::<TestTenantUser>::new().to_create_sql();
+
+        #[cfg(feature = "mysql")]
+        {
+            assert!(create_sql.contains(
+                "CONSTRAINT `uq_TestTenantUser_tenant_id_email` UNIQUE (`tenant_id`, `email`)"
+            ));
+            assert!(create_sql.contains(
+                "CONSTRAINT `uq_TestTenantUser_tenant_id_handle` UNIQUE (`tenant_id`, `handle`)"
+            ));
+        }
+        #[cfg(not(feature = "mysql"))]
+        {
+            assert!(create_sql.contains(
+                r#"CONSTRAINT "uq_TestTenantUser_tenant_id_email" UNIQUE ("tenant_id", "email")"#
+            ));
+            assert!(create_sql.contains(
+                r#"CONSTRAINT "uq_TestTenantUser_tenant_id_handle" UNIQUE ("tenant_id", "handle")"#
+            ));
+        }
+        // Only the two table-level constraints; no per-column UNIQUE for their fields
+        assert_eq!(create_sql.matches("UNIQUE").count(), 2);
+    }
+
+    #[test]
+    fn test_validation_errors_name_the_failed_rule() {
+        use crate::helpers::validate_column_value;
+        use crate::schema::{ColumnValidators, ValidationError};
+
+        define_schema! {
+            TestValidated {
+                email: String [email()],
+                age: i32 [min(10)],
+            }
+        }
+
+        let columns = TestValidated::get_all_columns();
+        let email_info = columns.iter().find(|c| c.name == "email").unwrap();
+        let age_info = columns.iter().find(|c| c.name == "age").unwrap();
+
+        let email = Value::String("not-an-email".to_string());
+        let email_err = validate_column_value(email_info, Some(&email)).unwrap_err();
+        assert_eq!(
+            email_err,
+            ValidationError {
+                column: "email",
+                rule: ColumnValidators::Email,
+                value: email,
+            }
+        );
+        assert_eq!(
+            email_err.to_string(),
+            "Column email failed Email validation: 'not-an-email' is not a valid email address"
+        );
+
+        let age = Value::Int32(5);
+        let age_err = validate_column_value(age_info, Some(&age)).unwrap_err();
+        assert_eq!(age_err.rule, ColumnValidators::Min(10));
+        assert_eq!(age_err.value, age);
+        assert_eq!(
+            age_err.to_string(),
+            "Column age failed Min(10) validation: 5 is less than 10"
+        );
+
+        assert_ne!(email_err.to_string(), age_err.to_string());
+        assert!(validate_column_value(age_info, Some(&Value::Int32(10))).is_ok());
     }
 
     #[test]
@@ -323,6 +749,63 @@ mod tests {
         assert_eq!(type_to_sql_string::<f32>(), "FLOAT");
         assert_eq!(type_to_sql_string::<f64>(), "DOUBLE");
         assert_eq!(type_to_sql_string::<bool>(), "BOOLEAN");
+        assert_eq!(type_to_sql_string::<i128>(), "DECIMAL(39,0)");
+        assert_eq!(type_to_sql_string::<Option<u128>>(), "DECIMAL(39,0)");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_pg_numeric_round_trip() {
+        use crate::helpers::PgNumeric;
+
+        for value in [0, 7, 10_000, -10_000, 123_456_789, i128::MIN, i128::MAX] {
+            let bytes = PgNumeric::from(value).to_binary();
+            let decoded = PgNumeric::from_binary(&bytes).unwrap().into_value();
+            assert_eq!(decoded, Some(Value::Int128(value)), "{}", value);
+        }
+
+        let bytes = PgNumeric::from(u128::MAX).to_binary();
+        let decoded = PgNumeric::from_binary(&bytes).unwrap().into_value();
+        assert_eq!(decoded, Some(Value::UInt128(u128::MAX)));
+
+        // 10000 is a single digit with weight 1: ndigits, weight, sign, dscale, digits
+        assert_eq!(
+            PgNumeric::from(10_000i128).to_binary(),
+            vec![0, 1, 0, 1, 0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(PgNumeric::from(0i128).to_binary(), vec![0; 8]);
+
+        assert_eq!(
+            PgNumeric::from_text("-42.000").unwrap().into_value(),
+            Some(Value::Int128(-42))
+        );
+        assert!(PgNumeric::from_text("1.5").is_err());
+        // 1.5: one integer digit and a fractional 5000
+        assert!(PgNumeric::from_binary(&[0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0x13, 0x88]).is_err());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_pg_numeric_float_from_binary() {
+        use crate::helpers::PgNumericFloat;
+
+        // 1.5: one integer digit and a fractional 5000
+        let bytes = [0, 2, 0, 0, 0, 0, 0, 1, 0, 1, 0x13, 0x88];
+        assert_eq!(PgNumericFloat::from_binary(&bytes), Ok(PgNumericFloat(1.5)));
+
+        // -20000.25: digits 2, 0 and 2500 with weight 1
+        let bytes = [0, 3, 0, 1, 0x40, 0, 0, 2, 0, 2, 0, 0, 0x09, 0xC4];
+        assert_eq!(
+            PgNumericFloat::from_binary(&bytes),
+            Ok(PgNumericFloat(-20_000.25))
+        );
+
+        assert!(
+            PgNumericFloat::from_binary(&[0, 0, 0, 0, 0xC0, 0, 0, 0])
+                .unwrap()
+                .0
+                .is_nan()
+        );
     }
 
     #[test]
@@ -336,12 +819,19 @@ mod tests {
         assert_eq!(columns.len(), 5);
 
         let create_sql = wrapper.to_create_sql();
-        assert!(create_sql.contains("CREATE TABLE IF NOT EXISTS TestUser"));
-        assert!(create_sql.contains("id INT PRIMARY KEY"));
-        assert!(create_sql.contains("username VARCHAR(255) NOT NULL"));
-        assert!(create_sql.contains("email VARCHAR(255)"));
-        assert!(create_sql.contains("age INT"));
-        assert!(create_sql.contains("is_active BOOLEAN NOT NULL"));
+        assert!(create_sql.contains(&format!(
+            "CREATE TABLE IF NOT EXISTS {}",
+            quoted("TestUser")
+        )));
+        #[cfg(not(feature = "sqlite"))]
+        assert!(create_sql.contains(&format!("{} INT PRIMARY KEY", quoted("id"))));
+        #[cfg(feature = "sqlite")]
+        assert!(create_sql.contains(&format!("{} INTEGER PRIMARY KEY", quoted("id"))));
+        let string_type = crate::dialects::get_dialect().default_string_type();
+        assert!(create_sql.contains(&format!("{} {} NOT NULL", quoted("username"), string_type)));
+        assert!(create_sql.contains(&format!("{} {}", quoted("email"), string_type)));
+        assert!(create_sql.contains(&format!("{} INT", quoted("age"))));
+        assert!(create_sql.contains(&format!("{} BOOLEAN NOT NULL", quoted("is_active"))));
     }
 
     #[cfg(feature = "postgres")]
@@ -381,24 +871,268 @@ mod tests {
             "INSERT INTO `TestUser` (".to_string()
         );
         assert_eq!(
-            get_starting_sql(StartingSql::Delete, "TestUser"),
-            "DELETE FROM `TestUser` ".to_string()
+            get_starting_sql(StartingSql::Delete, "TestUser"),
+            "DELETE FROM `TestUser` ".to_string()
+        );
+        assert_eq!(
+            get_starting_sql(StartingSql::Update, "TestUser"),
+            "UPDATE `TestUser` SET ".to_string()
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_qualified_table_name_postgres() {
+        use crate::dialects::get_dialect;
+        use crate::helpers::StartingSql;
+
+        let dialect = get_dialect();
+        assert_eq!(
+            dialect.quote_qualified("analytics.events"),
+            "\"analytics\".\"events\""
+        );
+        assert_eq!(dialect.quote_qualified("events"), "\"events\"");
+        assert_eq!(
+            get_starting_sql(StartingSql::Delete, "analytics.events"),
+            "DELETE FROM \"analytics\".\"events\" ".to_string()
+        );
+
+        define_schema! {
+            TestEvent {
+                id: i32 [primary_key()],
+                kind: String [indexed()],
+            }
+        }
+
+        // The index lives in the table's schema, so its name skips the schema part
+        let columns = TestEvent::get_all_columns();
+        assert_eq!(
+            crate::schema::column_index_sql("analytics.events", &columns[1]),
+            vec![
+                "CREATE INDEX \"idx_events_kind\" ON \"analytics\".\"events\" (\"kind\")"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn test_qualified_table_name_mysql() {
+        use crate::dialects::get_dialect;
+        use crate::helpers::StartingSql;
+
+        let dialect = get_dialect();
+        assert_eq!(
+            dialect.quote_qualified("analytics.events"),
+            "`analytics`.`events`"
+        );
+        assert_eq!(dialect.quote_qualified("events"), "`events`");
+        assert_eq!(
+            get_starting_sql(StartingSql::Delete, "analytics.events"),
+            "DELETE FROM `analytics`.`events` ".to_string()
+        );
+
+        define_schema! {
+            TestEvent {
+                id: i32 [primary_key()],
+                kind: String [indexed()],
+            }
+        }
+
+        let columns = TestEvent::get_all_columns();
+        assert_eq!(
+            crate::schema::column_index_sql("analytics.events", &columns[1]),
+            vec!["CREATE INDEX `idx_events_kind` ON `analytics`.`events` (`kind`)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_column_metadata_getters() {
+        use crate::schema::{Column, ColumnConstraint, ColumnValidators};
+
+        let column = Column::<String>::new("bio", "profiles")
+            .not_null()
+            .max_len(200)
+            .comment("Shown on the profile")
+            .charset("utf8mb4")
+            .collate("utf8mb4_unicode_ci");
+
+        assert_eq!(column.name(), "bio");
+        assert_eq!(column.table_name(), "profiles");
+        assert_eq!(column.get_comment(), Some("Shown on the profile"));
+        assert_eq!(column.get_charset(), Some("utf8mb4"));
+        assert_eq!(column.get_collate(), Some("utf8mb4_unicode_ci"));
+        assert!(column.get_default().is_none());
+        assert_eq!(column.get_constraints(), [ColumnConstraint::NonNullable]);
+        assert!(matches!(
+            column.get_validators(),
+            [ColumnValidators::MaxLen(200)]
+        ));
+
+        let plain = Column::<i32>::new("id", "profiles");
+        assert_eq!(plain.get_comment(), None);
+        assert_eq!(plain.get_charset(), None);
+        assert_eq!(plain.get_collate(), None);
+    }
+
+    #[test]
+    fn test_column_is_nullable() {
+        use crate::schema::Column;
+
+        assert!(Column::<String>::new("bio", "t").is_nullable());
+        assert!(!Column::<String>::new("name", "t").not_null().is_nullable());
+        assert!(!Column::<i32>::new("id", "t").primary_key().is_nullable());
+        assert!(Column::<Option<String>>::new("nick", "t").is_nullable());
+        // Option fields can hold None, so not_null() doesn't apply to them
+        assert!(
+            Column::<Option<String>>::new("nick", "t")
+                .not_null()
+                .is_nullable()
+        );
+        assert!(
+            !Column::<Option<i32>>::new("id", "t")
+                .primary_key()
+                .is_nullable()
+        );
+
+        // Generated schema columns agree with the DDL
+        let create_sql = crate::schema::SchemaWrapper::<TestUser>::new().to_create_sql();
+        let string_type = crate::dialects::get_dialect().default_string_type();
+        assert!(!TestUser::username().is_nullable());
+        assert!(create_sql.contains(&format!("{} {} NOT NULL", quoted("username"), string_type)));
+        assert!(TestUser::email().is_nullable());
+        assert!(create_sql.contains(&format!("{} {},", quoted("email"), string_type)));
+    }
+
+    #[test]
+    fn test_validate_table_accepts_valid_schema() {
+        use crate::schema::SchemaWrapper;
+        use crate::table::validate_table;
+
+        define_schema! {
+            TestValidEvent {
+                id: i32 [primary_key().not_null()],
+                created_at: time::OffsetDateTime [default_now()],
+                token: String [default_random()],
+            }
+        }
+
+        assert!(validate_table(&SchemaWrapper::<TestValidEvent>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_table_missing_primary_key() {
+        use crate::schema::SchemaWrapper;
+        use crate::table::{SchemaError, validate_table};
+
+        define_schema! {
+            TestNoKey {
+                name: String [not_null()],
+            }
+        }
+
+        assert_eq!(
+            validate_table(&SchemaWrapper::<TestNoKey>::new()),
+            vec![SchemaError::MissingPrimaryKey { table: "TestNoKey" }]
+        );
+    }
+
+    #[test]
+    fn test_validate_table_multiple_primary_keys() {
+        use crate::schema::SchemaWrapper;
+        use crate::table::{SchemaError, validate_table};
+
+        define_schema! {
+            TestTwoKeys {
+                order_id: i32 [primary_key()],
+                line: i32 [primary_key()],
+            }
+        }
+
+        assert_eq!(
+            validate_table(&SchemaWrapper::<TestTwoKeys>::new()),
+            vec![SchemaError::MultiplePrimaryKeys {
+                table: "TestTwoKeys",
+                columns: vec!["order_id", "line"],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_table_mismatched_default() {
+        use crate::schema::SchemaWrapper;
+        use crate::table::{SchemaError, validate_table};
+
+        define_schema! {
+            TestBadDefault {
+                id: i32 [primary_key()],
+                seen: i64 [default_now()],
+                code: i32 [default_random()],
+            }
+        }
+
+        let errors = validate_table(&SchemaWrapper::<TestBadDefault>::new());
+        assert_eq!(
+            errors,
+            vec![
+                SchemaError::MismatchedDefault {
+                    table: "TestBadDefault",
+                    column: "seen",
+                    data_type: "BIGINT",
+                    default: DefaultValueEnum::CurrentTimestamp,
+                },
+                SchemaError::MismatchedDefault {
+                    table: "TestBadDefault",
+                    column: "code",
+                    data_type: "INT",
+                    default: DefaultValueEnum::Random,
+                },
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "Column TestBadDefault.seen of type BIGINT can't have the default default_now()"
+        );
+    }
+
+    #[test]
+    fn test_validate_table_default_too_long() {
+        use crate::schema::SchemaWrapper;
+        use crate::table::{SchemaError, validate_table};
+
+        define_schema! {
+            TestLongDefault {
+                id: i32 [primary_key()],
+                code: String [length(4).default_value("ab'cd".to_string())],
+                fits: String [length(4).default_value("ab'c".to_string())],
+            }
+        }
+
+        let errors = validate_table(&SchemaWrapper::<TestLongDefault>::new());
+        assert_eq!(
+            errors,
+            vec![SchemaError::DefaultTooLong {
+                table: "TestLongDefault",
+                column: "code",
+                length: 4,
+                default: "'ab''cd'".to_string(),
+            }]
         );
         assert_eq!(
-            get_starting_sql(StartingSql::Update, "TestUser"),
-            "UPDATE `TestUser` SET ".to_string()
+            errors[0].to_string(),
+            "Column TestLongDefault.code holds at most 4 characters, but its default is 'ab''cd'"
         );
     }
 }
 
 #[cfg(test)]
 mod build_filter_expr_tests {
-    use crate::filter::{FilterType, Filtered};
+    use crate::filter::{DatePart, Extract, FilterType, Filtered, JsonPathFilter};
     use crate::helpers::build_filter_expr;
     use crate::schema::Value;
     use std::sync::Arc;
 
-    #[derive(Debug)]
+    #[derive(Debug, Clone)]
     struct DummyFilter {
         // For composite filters
         or: bool,
@@ -467,6 +1201,10 @@ mod build_filter_expr_tests {
         fn array_values(&self) -> Option<&Vec<Value>> {
             self.array_values.as_ref()
         }
+
+        fn clone_box(&self) -> Box<dyn Filtered> {
+            Box::new(self.clone())
+        }
     }
 
     #[test]
@@ -492,12 +1230,12 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&and_filter, &mut params);
+        let sql = build_filter_expr(&and_filter, &mut params).unwrap();
         {
             #[cfg(feature = "mysql")]
-            assert_eq!(sql, "(t.a = ? AND t.b = ?)");
+            assert_eq!(sql, "(`t`.`a` = ? AND `t`.`b` = ?)");
             #[cfg(feature = "postgres")]
-            assert_eq!(sql, "(t.a = $1 AND t.b = $2)");
+            assert_eq!(sql, "(\"t\".\"a\" = $1 AND \"t\".\"b\" = $2)");
         }
         assert_eq!(params, vec![Value::Int32(1), Value::Int32(2)]);
 
@@ -510,11 +1248,11 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&or_filter, &mut params);
+        let sql = build_filter_expr(&or_filter, &mut params).unwrap();
         #[cfg(feature = "mysql")]
-        assert_eq!(sql, "(t.a = ? OR t.b = ?)");
+        assert_eq!(sql, "(`t`.`a` = ? OR `t`.`b` = ?)");
         #[cfg(feature = "postgres")]
-        assert_eq!(sql, "(t.a = $1 OR t.b = $2)");
+        assert_eq!(sql, "(\"t\".\"a\" = $1 OR \"t\".\"b\" = $2)");
         assert_eq!(params, vec![Value::Int32(1), Value::Int32(2)]);
     }
 
@@ -534,12 +1272,12 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&not_filter, &mut params);
+        let sql = build_filter_expr(&not_filter, &mut params).unwrap();
         {
             #[cfg(feature = "mysql")]
-            assert_eq!(sql, "NOT (t.a = ?)");
+            assert_eq!(sql, "NOT (`t`.`a` = ?)");
             #[cfg(feature = "postgres")]
-            assert_eq!(sql, "NOT (t.a = $1)");
+            assert_eq!(sql, "NOT (\"t\".\"a\" = $1)");
         }
         assert_eq!(params, vec![Value::Int32(1)]);
     }
@@ -554,7 +1292,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&and_filter, &mut params);
+        let sql = build_filter_expr(&and_filter, &mut params).unwrap();
         assert_eq!(sql, "1=1");
 
         let not_filter = DummyFilter {
@@ -563,7 +1301,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&not_filter, &mut params);
+        let sql = build_filter_expr(&not_filter, &mut params).unwrap();
         assert_eq!(sql, "1=1");
     }
 
@@ -574,10 +1312,76 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         assert_eq!(sql, "1=1");
     }
 
+    #[test]
+    fn test_large_in_list_stays_under_placeholder_limit() {
+        use crate::helpers::LARGE_IN_LIST;
+
+        let values: Vec<Value> = (0..70_000).map(Value::Int32).collect();
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            in_array: Some(true),
+            array_values: Some(values.clone()),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+
+        #[cfg(feature = "postgres")]
+        {
+            // One array parameter, well under Postgres's 65535 placeholders
+            assert_eq!(sql, "\"t\".\"a\" = ANY($1)");
+            assert_eq!(params, vec![Value::Array(values.clone())]);
+
+            let filter = DummyFilter {
+                col1: Some(("t".to_owned(), "a".to_owned())),
+                in_array: Some(false),
+                array_values: Some(values.clone()),
+                ..DummyFilter::new()
+            };
+            let mut params = vec![Value::Int32(0)];
+            let sql = build_filter_expr(&filter, &mut params).unwrap();
+            assert_eq!(sql, "\"t\".\"a\" <> ALL($2)");
+            assert_eq!(params.len(), 2);
+
+            // Lists with mixed types can't be one array, so they keep a placeholder each
+            let mut mixed = values[..LARGE_IN_LIST + 1].to_vec();
+            mixed.push(Value::Int64(1));
+            let filter = DummyFilter {
+                col1: Some(("t".to_owned(), "a".to_owned())),
+                in_array: Some(true),
+                array_values: Some(mixed),
+                ..DummyFilter::new()
+            };
+            let mut params = vec![];
+            let sql = build_filter_expr(&filter, &mut params).unwrap();
+            assert!(sql.starts_with("\"t\".\"a\" IN ($1, $2,"));
+            assert_eq!(params.len(), LARGE_IN_LIST + 2);
+        }
+
+        // MySQL and SQLite can't bind arrays, so each value keeps its placeholder
+        #[cfg(not(feature = "postgres"))]
+        {
+            assert!(sql.ends_with(", ?)"));
+            assert_eq!(params.len(), 70_000);
+        }
+
+        // Short lists are unchanged on every backend
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            in_array: Some(true),
+            array_values: Some(values[..LARGE_IN_LIST].to_vec()),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        assert!(sql.contains(" IN ("));
+        assert_eq!(params.len(), LARGE_IN_LIST);
+    }
+
     #[test]
     fn test_in_and_not_in_array() {
         let filter = DummyFilter {
@@ -588,7 +1392,7 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         #[cfg(feature = "mysql")]
         assert_eq!(sql, "`t`.`a` IN (?, ?)");
         #[cfg(feature = "postgres")]
@@ -603,7 +1407,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         assert_eq!(sql, "1=0");
 
         // NOT IN with values
@@ -615,7 +1419,7 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         #[cfg(feature = "mysql")]
         assert_eq!(sql, "`t`.`a` NOT IN (?)");
         #[cfg(feature = "postgres")]
@@ -630,10 +1434,63 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         assert_eq!(sql, "1=1");
     }
 
+    #[test]
+    fn test_in_and_not_in_array_with_null() {
+        let values = vec![Value::Int32(1), Value::Null, Value::Int32(3)];
+
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            in_array: Some(false),
+            array_values: Some(values.clone()),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        #[allow(unused)]
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "(`t`.`a` NOT IN (?, ?) AND `t`.`a` IS NOT NULL)");
+        #[cfg(feature = "postgres")]
+        assert_eq!(
+            sql,
+            "(\"t\".\"a\" NOT IN ($1, $2) AND \"t\".\"a\" IS NOT NULL)"
+        );
+        assert_eq!(params, vec![Value::Int32(1), Value::Int32(3)]);
+
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            in_array: Some(true),
+            array_values: Some(values),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        #[allow(unused)]
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "(`t`.`a` IN (?, ?) OR `t`.`a` IS NULL)");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "(\"t\".\"a\" IN ($1, $2) OR \"t\".\"a\" IS NULL)");
+
+        // Only NULLs
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            in_array: Some(false),
+            array_values: Some(vec![Value::Null]),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        #[allow(unused)]
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` IS NOT NULL");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"t\".\"a\" IS NOT NULL");
+        assert!(params.is_empty());
+    }
+
     #[test]
     fn test_in_and_not_in_column() {
         // IN column: t1.a IN (SELECT t2.b FROM t2)
@@ -645,7 +1502,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         #[cfg(feature = "mysql")]
         assert_eq!(sql, "`t1`.`a` IN (SELECT `b` FROM `t2`)");
         #[cfg(feature = "postgres")]
@@ -663,7 +1520,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         #[cfg(feature = "mysql")]
         assert_eq!(sql, "`t1`.`a` NOT IN (SELECT `b` FROM `t2`)");
         #[cfg(feature = "postgres")]
@@ -681,7 +1538,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         assert_eq!(sql, "1=0");
         assert!(params.is_empty());
 
@@ -694,7 +1551,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         assert_eq!(sql, "1=1");
         assert!(params.is_empty());
     }
@@ -709,8 +1566,11 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
-        assert_eq!(sql, "t.a IS NULL");
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` IS NULL");
+        #[cfg(not(feature = "mysql"))]
+        assert_eq!(sql, "\"t\".\"a\" IS NULL");
         assert!(params.is_empty());
 
         // IS NOT NULL
@@ -721,8 +1581,11 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
-        assert_eq!(sql, "t.a IS NOT NULL");
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` IS NOT NULL");
+        #[cfg(not(feature = "mysql"))]
+        assert_eq!(sql, "\"t\".\"a\" IS NOT NULL");
         assert!(params.is_empty());
 
         // NULL with unsupported op
@@ -733,10 +1596,346 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         assert_eq!(sql, "1=0");
     }
 
+    #[cfg(any(feature = "postgres", feature = "sqlite"))]
+    #[test]
+    fn test_bind_value_rejects_u64_above_bigint() {
+        use crate::database::error::DatabaseError;
+        use crate::helpers::bind_value;
+
+        let result = bind_value(sqlx::query("SELECT 1"), Value::UInt64(u64::MAX));
+        assert!(matches!(result, Err(DatabaseError::ValueOutOfRange(_))));
+
+        let result = bind_value(sqlx::query("SELECT 1"), Value::UInt64(i64::MAX as u64));
+        assert!(result.is_ok());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_eq_any_binds_single_array() {
+        use crate::database::error::DatabaseError;
+        use crate::helpers::bind_value;
+        use sqlx::{Arguments, Execute};
+
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            filter_type: FilterType::EqAny,
+            value: Some(Value::Array(vec![
+                Value::Int32(1),
+                Value::Int32(2),
+                Value::Int32(3),
+            ])),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![Value::Int32(0)];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        assert_eq!(sql, "\"t\".\"a\" = ANY($2)");
+        assert_eq!(
+            params[1],
+            Value::Array(vec![Value::Int32(1), Value::Int32(2), Value::Int32(3)])
+        );
+
+        // The whole list is one bind parameter
+        let mut query = bind_value(sqlx::query("SELECT 1"), params.remove(1)).unwrap();
+        let args = query.take_arguments().unwrap().unwrap();
+        assert_eq!(args.len(), 1);
+
+        // Mixed element types can't form a Postgres array
+        let result = bind_value(
+            sqlx::query("SELECT 1"),
+            Value::Array(vec![Value::Int32(1), Value::String("x".to_string())]),
+        );
+        assert!(matches!(result, Err(DatabaseError::InvalidValue(_))));
+
+        // e.g. eq_any(col, vec![Some(1), None])
+        let result = bind_value(
+            sqlx::query("SELECT 1"),
+            Value::Array(vec![Value::Int32(1), Value::Null]),
+        );
+        assert!(matches!(result, Err(DatabaseError::InvalidValue(_))));
+
+        let result = bind_value(sqlx::query("SELECT 1"), Value::Array(vec![Value::Null]));
+        assert!(matches!(result, Err(DatabaseError::InvalidValue(_))));
+
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            filter_type: FilterType::EqAny,
+            value: Some(Value::Array(vec![])),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        assert_eq!(build_filter_expr(&filter, &mut params).unwrap(), "1=0");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_lone_in_filter_type_is_parenthesized() {
+        // A single value with `In` but no array marker
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            filter_type: FilterType::In,
+            value: Some(Value::Int32(1)),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` IN (?)");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"t\".\"a\" IN ($1)");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(sql, "\"t\".\"a\" IN (?)");
+        assert!(!sql.contains("IN ?") && !sql.contains("IN $"));
+        assert_eq!(params, vec![Value::Int32(1)]);
+
+        // Column-to-column with `In`
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            col2: Some(("u".to_owned(), "b".to_owned())),
+            filter_type: FilterType::In,
+            ..DummyFilter::new()
+        };
+        let sql = build_filter_expr(&filter, &mut vec![]).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` IN (`u`.`b`)");
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        assert_eq!(sql, "\"t\".\"a\" IN (\"u\".\"b\")");
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn test_eq_value_accepts_enum_variant() {
+        use crate::filter::eq_value;
+        use crate::{define_schema, enum_to_sql};
+
+        #[derive(Clone, PartialEq, Default)]
+        pub enum AccountStatus {
+            #[default]
+            Active,
+            Banned,
+        }
+
+        enum_to_sql!(AccountStatus {
+            Active => "active",
+            Banned => "banned",
+        });
+
+        define_schema! {
+            Account {
+                id: i32 [primary_key()],
+                status: AccountStatus,
+            }
+        }
+
+        let mut params = vec![];
+        let sql = build_filter_expr(
+            &eq_value(Account::status(), AccountStatus::Banned),
+            &mut params,
+        )
+        .unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`Account`.`status` = ?");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"Account\".\"status\" = $1");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(sql, "\"Account\".\"status\" = ?");
+        assert_eq!(params, vec![Value::String("banned".to_string())]);
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn test_like_escaped_renders_escape_clause() {
+        use crate::define_schema;
+        use crate::filter::like_escaped;
+
+        define_schema! {
+            Product {
+                id: i32 [primary_key()],
+                name: String,
+            }
+        }
+
+        let mut params = vec![];
+        let sql =
+            build_filter_expr(&like_escaped(Product::name(), "100!%", '!'), &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`Product`.`name` LIKE ? ESCAPE '!'");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"Product\".\"name\" LIKE $1 ESCAPE '!'");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(sql, "\"Product\".\"name\" LIKE ? ESCAPE '!'");
+        assert_eq!(params, vec![Value::String("100!%".to_string())]);
+
+        // Backslash needs escaping inside MySQL string literals
+        let mut params = vec![];
+        let sql =
+            build_filter_expr(&like_escaped(Product::name(), "a", '\\'), &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert!(sql.ends_with(" ESCAPE '\\\\'"));
+        #[cfg(not(feature = "mysql"))]
+        assert!(sql.ends_with(" ESCAPE '\\'"));
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn test_range_bounds_pick_comparisons() {
+        use crate::define_schema;
+        use crate::filter::{Bounds, range};
+
+        define_schema! {
+            TestShift {
+                id: i32 [primary_key()],
+                starts_at: i64 [not_null()],
+            }
+        }
+
+        let mut params = vec![];
+        let sql = build_filter_expr(
+            &range(
+                TestShift::starts_at(),
+                100_i64,
+                200_i64,
+                Bounds::InclusiveExclusive,
+            ),
+            &mut params,
+        )
+        .unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(
+            sql,
+            "(`TestShift`.`starts_at` >= ? AND `TestShift`.`starts_at` < ?)"
+        );
+        #[cfg(feature = "postgres")]
+        assert_eq!(
+            sql,
+            "(\"TestShift\".\"starts_at\" >= $1 AND \"TestShift\".\"starts_at\" < $2)"
+        );
+        #[cfg(feature = "sqlite")]
+        assert_eq!(
+            sql,
+            "(\"TestShift\".\"starts_at\" >= ? AND \"TestShift\".\"starts_at\" < ?)"
+        );
+        assert_eq!(params, vec![Value::Int64(100), Value::Int64(200)]);
+
+        for (bounds, low_op, high_op) in [
+            (Bounds::Inclusive, ">=", "<="),
+            (Bounds::ExclusiveInclusive, ">", "<="),
+            (Bounds::Exclusive, ">", "<"),
+        ] {
+            let sql = build_filter_expr(
+                &range(TestShift::starts_at(), 100_i64, 200_i64, bounds),
+                &mut vec![],
+            )
+            .unwrap();
+            assert!(sql.contains(&format!(" {} ", low_op)));
+            assert!(sql.contains(&format!(" {} ", high_op)));
+            assert!(!sql.contains("BETWEEN"));
+        }
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn test_on_date_expands_to_half_open_range() {
+        use crate::define_schema;
+        use crate::filter::{after, before, on_date};
+        use time::macros::{date, datetime};
+
+        define_schema! {
+            TestEvent {
+                id: i32 [primary_key()],
+                created_at: i64 [not_null()],
+            }
+        }
+
+        let mut params = vec![];
+        let sql = build_filter_expr(
+            &on_date(TestEvent::created_at(), date!(2025 - 01 - 31)),
+            &mut params,
+        )
+        .unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(
+            sql,
+            "(`TestEvent`.`created_at` >= ? AND `TestEvent`.`created_at` < ?)"
+        );
+        #[cfg(feature = "postgres")]
+        assert_eq!(
+            sql,
+            "(\"TestEvent\".\"created_at\" >= $1 AND \"TestEvent\".\"created_at\" < $2)"
+        );
+        #[cfg(feature = "sqlite")]
+        assert_eq!(
+            sql,
+            "(\"TestEvent\".\"created_at\" >= ? AND \"TestEvent\".\"created_at\" < ?)"
+        );
+        // 2025-01-31T00:00:00Z and 2025-02-01T00:00:00Z
+        assert_eq!(
+            params,
+            vec![Value::Int64(1_738_281_600), Value::Int64(1_738_368_000)]
+        );
+
+        let mut params = vec![];
+        let sql = build_filter_expr(
+            &before(TestEvent::created_at(), datetime!(2025-01-31 00:00 UTC)),
+            &mut params,
+        )
+        .unwrap();
+        #[cfg(feature = "mysql")]
+        assert!(sql.starts_with("`TestEvent`.`created_at` < "));
+        #[cfg(not(feature = "mysql"))]
+        assert!(sql.starts_with("\"TestEvent\".\"created_at\" < "));
+        assert_eq!(params, vec![Value::Int64(1_738_281_600)]);
+
+        let mut params = vec![];
+        let sql = build_filter_expr(
+            &after(TestEvent::created_at(), datetime!(2025-01-31 00:00 UTC)),
+            &mut params,
+        )
+        .unwrap();
+        #[cfg(feature = "mysql")]
+        assert!(sql.starts_with("`TestEvent`.`created_at` > "));
+        #[cfg(not(feature = "mysql"))]
+        assert!(sql.starts_with("\"TestEvent\".\"created_at\" > "));
+        assert_eq!(params, vec![Value::Int64(1_738_281_600)]);
+    }
+
+    #[test]
+    fn test_null_safe_eq() {
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            filter_type: FilterType::NullSafeEq,
+            value: Some(Value::Int32(1)),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` <=> ?");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"t\".\"a\" IS NOT DISTINCT FROM $1");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(sql, "\"t\".\"a\" IS NOT DISTINCT FROM ?");
+        assert_eq!(params, vec![Value::Int32(1)]);
+
+        // NULL is never bound, so it falls back to IS NULL
+        let filter = DummyFilter {
+            col1: Some(("t".to_owned(), "a".to_owned())),
+            filter_type: FilterType::NullSafeEq,
+            value: Some(Value::Null),
+            ..DummyFilter::new()
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "`t`.`a` IS NULL");
+        #[cfg(not(feature = "mysql"))]
+        assert_eq!(sql, "\"t\".\"a\" IS NULL");
+        assert!(params.is_empty());
+    }
+
     #[test]
     fn test_between() {
         let filter = DummyFilter {
@@ -750,7 +1949,7 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         {
             #[cfg(feature = "mysql")]
             assert_eq!(sql, "`t`.`a` BETWEEN ? AND ?");
@@ -770,12 +1969,12 @@ mod build_filter_expr_tests {
         };
         let mut params = vec![];
         #[allow(unused)]
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         {
             #[cfg(feature = "mysql")]
-            assert_eq!(sql, "t.a > ?");
+            assert_eq!(sql, "`t`.`a` > ?");
             #[cfg(feature = "postgres")]
-            assert_eq!(sql, "t.a > $1");
+            assert_eq!(sql, "\"t\".\"a\" > $1");
         }
         assert_eq!(params, vec![Value::Int32(10)]);
     }
@@ -789,7 +1988,7 @@ mod build_filter_expr_tests {
             ..DummyFilter::new()
         };
         let mut params = vec![];
-        let sql = build_filter_expr(&filter, &mut params);
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
         #[cfg(feature = "mysql")]
         assert_eq!(sql, "`t`.`a` = `t`.`b`");
         #[cfg(feature = "postgres")]
@@ -798,4 +1997,92 @@ mod build_filter_expr_tests {
         assert_eq!(sql, "\"t\".\"a\" = \"t\".\"b\"");
         assert!(params.is_empty());
     }
+
+    #[test]
+    fn test_json_path_filter_nested_path() {
+        let filter = JsonPathFilter {
+            column: ("t".to_owned(), "meta".to_owned()),
+            path: "$.address.city".to_owned(),
+            value: Value::String("Pune".to_owned()),
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "JSON_EXTRACT(`t`.`meta`, '$.address.city') = ?");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"t\".\"meta\"->'address'->>'city' = CAST($1 AS TEXT)");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(sql, "JSON_EXTRACT(\"t\".\"meta\", '$.address.city') = ?");
+        assert_eq!(params, vec![Value::String("Pune".to_owned())]);
+    }
+
+    #[test]
+    fn test_json_path_filter_array_index() {
+        let filter = JsonPathFilter {
+            column: ("t".to_owned(), "meta".to_owned()),
+            path: "$.tags[0]".to_owned(),
+            value: Value::Int32(7),
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "\"t\".\"meta\"->'tags'->>0 = CAST($1 AS TEXT)");
+        #[cfg(not(feature = "postgres"))]
+        assert!(sql.starts_with("JSON_EXTRACT("));
+        assert_eq!(params, vec![Value::Int32(7)]);
+    }
+
+    #[test]
+    fn test_json_path_filter_invalid_path() {
+        let filter = JsonPathFilter {
+            column: ("t".to_owned(), "meta".to_owned()),
+            path: "status".to_owned(),
+            value: Value::Int32(1),
+        };
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        assert_eq!(sql, "1=0");
+        assert!(params.is_empty());
+    }
+    #[test]
+    fn test_extract_year_filter() {
+        let part = Extract {
+            column: ("t".to_owned(), "created_at".to_owned()),
+            part: DatePart::Year,
+        };
+        let filter = part.eq_value(2024);
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "YEAR(`t`.`created_at`) = ?");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "EXTRACT(YEAR FROM \"t\".\"created_at\") = $1");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(
+            sql,
+            "CAST(strftime('%Y', \"t\".\"created_at\") AS INTEGER) = ?"
+        );
+        assert_eq!(params, vec![Value::Int32(2024)]);
+    }
+
+    #[test]
+    fn test_extract_month_filter_comparison() {
+        let part = Extract {
+            column: ("t".to_owned(), "created_at".to_owned()),
+            part: DatePart::Month,
+        };
+        let filter = part.gte(6);
+        let mut params = vec![];
+        let sql = build_filter_expr(&filter, &mut params).unwrap();
+        #[cfg(feature = "mysql")]
+        assert_eq!(sql, "MONTH(`t`.`created_at`) >= ?");
+        #[cfg(feature = "postgres")]
+        assert_eq!(sql, "EXTRACT(MONTH FROM \"t\".\"created_at\") >= $1");
+        #[cfg(feature = "sqlite")]
+        assert_eq!(
+            sql,
+            "CAST(strftime('%m', \"t\".\"created_at\") AS INTEGER) >= ?"
+        );
+        assert_eq!(params, vec![Value::Int32(6)]);
+    }
 }
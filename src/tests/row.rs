@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        define_schema, from_row,
+        row::{FromRow, Row},
+        schema::Schema,
+    };
+
+    use schemas::*;
+
+    #[allow(dead_code)]
+    mod schemas {
+        use super::*;
+
+        define_schema! {
+            Member {
+                id: i32 [primary_key().not_null()],
+                name: String [not_null()],
+                age: i32,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct MemberProfile {
+        id: i32,
+        display_name: String,
+    }
+
+    from_row!(Member => MemberProfile {
+        id,
+        display_name: name,
+    });
+
+    fn insert_column<V: Into<crate::schema::Value>>(row: &mut Row<Member>, name: &str, value: V) {
+        let column = Member::get_all_columns()
+            .into_iter()
+            .find(|c| c.name == name)
+            .unwrap();
+        row._insert(column, value);
+    }
+
+    #[test]
+    fn test_from_row_maps_columns_into_struct() {
+        let mut row = Row::<Member>::_new();
+        insert_column(&mut row, "id", 7);
+        insert_column(&mut row, "name", "alice".to_string());
+        insert_column(&mut row, "age", 30);
+
+        let rows = [row];
+        let profiles: Vec<MemberProfile> =
+            rows.iter().filter_map(MemberProfile::from_row).collect();
+
+        assert_eq!(
+            profiles,
+            vec![MemberProfile {
+                id: 7,
+                display_name: "alice".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_from_row_missing_column_returns_none() {
+        let mut row = Row::<Member>::_new();
+        insert_column(&mut row, "id", 7);
+
+        assert_eq!(MemberProfile::from_row(&row), None);
+    }
+
+    #[test]
+    fn test_rows_compare_by_data_and_clone() {
+        let mut row = Row::<Member>::_new();
+        insert_column(&mut row, "id", 7);
+        insert_column(&mut row, "name", "Ana");
+
+        let mut same = Row::<Member>::_new();
+        insert_column(&mut same, "name", "Ana");
+        insert_column(&mut same, "id", 7);
+        assert_eq!(row, same);
+
+        let copy = row.clone();
+        assert_eq!(copy, row);
+        assert_eq!(copy.get(Member::id()), Some(7));
+        assert_eq!(copy.get(Member::name()), Some("Ana".to_string()));
+
+        insert_column(&mut same, "age", 30);
+        assert_ne!(row, same);
+    }
+
+    #[test]
+    fn test_is_null_distinguishes_null_from_zero() {
+        let mut row = Row::<Member>::_new();
+        insert_column(&mut row, "id", 0);
+        insert_column(&mut row, "age", crate::schema::Value::Null);
+
+        assert!(!row.is_null(Member::id()));
+        assert_eq!(row.get(Member::id()), Some(0));
+        assert_eq!(row.get_or(Member::id(), 42), 0);
+
+        assert!(row.is_null(Member::age()));
+        assert_eq!(row.get(Member::age()), None);
+        assert_eq!(row.get_or(Member::age(), 42), 42);
+
+        // Missing columns aren't NULL, but still fall back to the default
+        assert!(!row.is_null(Member::name()));
+        assert_eq!(row.get_or(Member::name(), "none".to_string()), "none");
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_null_column_is_null_sqlite() {
+        let db = crate::database::Database::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE Member (id INTEGER PRIMARY KEY, name TEXT NOT NULL, age INTEGER)",
+        )
+        .execute(&*db.connection)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO Member (id, name, age) VALUES (1, 'zero', 0), (2, 'null', NULL)")
+            .execute(&*db.connection)
+            .await
+            .unwrap();
+
+        let rows = db
+            .sql::<Member>("SELECT * FROM Member ORDER BY id")
+            .await
+            .unwrap();
+
+        assert!(!rows[0].is_null(Member::age()));
+        assert_eq!(rows[0].get(Member::age()), Some(0));
+        assert!(rows[1].is_null(Member::age()));
+        assert_eq!(rows[1].get_or(Member::age(), -1), -1);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    #[allow(dead_code)]
+    async fn test_joined_is_none_for_unmatched_left_join_sqlite() {
+        use crate::filter::eq_column;
+
+        define_schema! {
+            Badge {
+                member_id: i32 [primary_key()],
+                label: String,
+                level: i32,
+            }
+        }
+
+        let db = crate::database::Database::connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE Member (id INTEGER PRIMARY KEY, name TEXT NOT NULL, age INTEGER)",
+        )
+        .execute(&*db.connection)
+        .await
+        .unwrap();
+        sqlx::query(
+            "CREATE TABLE Badge (member_id INTEGER PRIMARY KEY, label TEXT, level INTEGER)",
+        )
+        .execute(&*db.connection)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO Member (id, name, age) VALUES (1, 'with', 20), (2, 'without', 30)",
+        )
+        .execute(&*db.connection)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO Badge (member_id, label, level) VALUES (1, 'gold', 3)")
+            .execute(&*db.connection)
+            .await
+            .unwrap();
+
+        let rows = db
+            .query::<Member, SelectMember>()
+            .left_join::<Badge, SelectBadge>(
+                eq_column(Member::id(), Badge::member_id()),
+                SelectBadge::selected().all(),
+            )
+            .order_by(Member::id())
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+
+        let badge = rows[0].joined::<Badge>().unwrap();
+        assert_eq!(badge.get(Badge::member_id()), Some(1));
+        assert_eq!(badge.get(Badge::label()), Some("gold".to_string()));
+        assert_eq!(badge.get(Badge::level()), Some(3));
+
+        assert!(rows[1].joined::<Badge>().is_none());
+        assert_eq!(rows[1].get(Member::name()), Some("without".to_string()));
+    }
+}
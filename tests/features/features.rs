@@ -0,0 +1,16 @@
+//! Builds `src/features.rs` with the backend features this package is tested
+//! with, since lume itself can't compile far enough to run these cases.
+
+#[test]
+#[cfg(not(any(feature = "mysql", feature = "postgres", feature = "sqlite")))]
+fn test_no_backend_fails_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("../ui/no_backend.rs");
+}
+
+#[test]
+#[cfg(all(feature = "mysql", feature = "postgres"))]
+fn test_several_backends_fail_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("../ui/several_backends.rs");
+}
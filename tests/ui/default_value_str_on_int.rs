@@ -0,0 +1,10 @@
+use lume::define_schema;
+
+define_schema! {
+    Player {
+        id: i32 [primary_key()],
+        score: i32 [default_value("x")],
+    }
+}
+
+fn main() {}
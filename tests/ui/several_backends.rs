@@ -0,0 +1,4 @@
+#[path = "../../src/features.rs"]
+mod features;
+
+fn main() {}